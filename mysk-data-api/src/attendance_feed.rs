@@ -0,0 +1,92 @@
+use crate::background::BackgroundTasks;
+use mysk_lib::models::enums::CheerPracticeAttendanceType;
+use serde::{Deserialize, Serialize};
+use sqlx::{PgPool, postgres::PgListener};
+use std::{sync::Arc, time::Duration};
+use tokio::sync::broadcast::{self, Receiver, Sender};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+/// The channel `notify_cheer_practice_attendance` announces checked attendances on.
+const CHANNEL: &str = "cheer_practice_attendances";
+/// The number of events a slow subscriber can fall behind by before it misses some.
+const CHANNEL_CAPACITY: usize = 256;
+/// How long to wait before listening again after losing the connection.
+const RETRY_DELAY: Duration = Duration::from_secs(5);
+
+/// A cheer practice attendance as it was checked.
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
+pub struct CheerPracticeAttendanceEvent {
+    pub id: Uuid,
+    pub practice_period_id: Uuid,
+    /// The classroom the student practices with in the period.
+    pub classroom_id: Option<Uuid>,
+    pub student_id: Uuid,
+    /// The user who checked the attendance.
+    pub checker_id: Option<Uuid>,
+    pub presence: Option<CheerPracticeAttendanceType>,
+    pub presence_at_end: Option<CheerPracticeAttendanceType>,
+    pub absence_reason: Option<String>,
+}
+
+/// A handle to the background task listening for checked cheer practice attendances. Attendances
+/// are announced through Postgres on commit, so every instance sees those checked by the others.
+#[derive(Clone)]
+pub struct AttendanceFeed {
+    sender: Sender<Arc<CheerPracticeAttendanceEvent>>,
+}
+
+impl AttendanceFeed {
+    pub fn spawn(pool: PgPool, tasks: &mut BackgroundTasks) -> Self {
+        let (sender, _) = broadcast::channel(CHANNEL_CAPACITY);
+        let task_sender = sender.clone();
+        tasks.spawn(|mut stop| async move {
+            tokio::select! {
+                () = Self::run(pool, task_sender) => (),
+                () = stop.stopped() => (),
+            }
+        });
+
+        Self { sender }
+    }
+
+    /// Receives every attendance checked from now on.
+    pub fn subscribe(&self) -> Receiver<Arc<CheerPracticeAttendanceEvent>> {
+        self.sender.subscribe()
+    }
+
+    async fn run(pool: PgPool, sender: Sender<Arc<CheerPracticeAttendanceEvent>>) {
+        loop {
+            let mut listener = match PgListener::connect_with(&pool).await {
+                Ok(listener) => listener,
+                Err(err) => {
+                    tracing::error!("Failed to connect the attendance feed: {err}");
+                    tokio::time::sleep(RETRY_DELAY).await;
+                    continue;
+                }
+            };
+            if let Err(err) = listener.listen(CHANNEL).await {
+                tracing::error!("Failed to listen for attendances: {err}");
+                tokio::time::sleep(RETRY_DELAY).await;
+                continue;
+            }
+
+            // `recv` reconnects by itself, though attendances checked meanwhile are missed
+            loop {
+                match listener.recv().await {
+                    Ok(notification) => {
+                        match serde_json::from_str(notification.payload()) {
+                            // Nobody subscribing isn't an error
+                            Ok(event) => _ = sender.send(Arc::new(event)),
+                            Err(err) => tracing::error!("Malformed attendance notification: {err}"),
+                        }
+                    }
+                    Err(err) => {
+                        tracing::error!("Lost the attendance feed: {err}");
+                        tokio::time::sleep(RETRY_DELAY).await;
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,74 @@
+use futures::future;
+use std::{future::Future, time::Duration};
+use tokio::{
+    sync::watch::{self, Receiver, Sender},
+    task::JoinHandle,
+};
+
+/// The background tasks of the application, such as the [`ErrorLogSink`] and the
+/// [`WebhookDispatcher`], which must stop before the database connections are closed.
+///
+/// Each task is handed a [`StopSignal`], and is expected to finish the work it has started, e.g.
+/// flush buffered error logs or complete an in-flight delivery, before returning once it fires.
+///
+/// [`ErrorLogSink`]: crate::middlewares::error_log::ErrorLogSink
+/// [`WebhookDispatcher`]: crate::webhook_dispatcher::WebhookDispatcher
+pub struct BackgroundTasks {
+    stop: Sender<bool>,
+    handles: Vec<JoinHandle<()>>,
+}
+
+impl BackgroundTasks {
+    pub fn new() -> Self {
+        let (stop, _) = watch::channel(false);
+
+        Self {
+            stop,
+            handles: Vec::new(),
+        }
+    }
+
+    pub fn spawn<F>(&mut self, task: impl FnOnce(StopSignal) -> F)
+    where
+        F: Future<Output = ()> + Send + 'static,
+    {
+        let signal = StopSignal(self.stop.subscribe());
+        self.handles.push(tokio::spawn(task(signal)));
+    }
+
+    /// Signals every task to stop, and waits up to `timeout` for them to finish before aborting
+    /// those still running.
+    pub async fn stop(self, timeout: Duration) {
+        self.stop.send_replace(true);
+
+        let abort_handles = self
+            .handles
+            .iter()
+            .map(JoinHandle::abort_handle)
+            .collect::<Vec<_>>();
+        if tokio::time::timeout(timeout, future::join_all(self.handles))
+            .await
+            .is_err()
+        {
+            tracing::warn!("Background tasks didn't stop in time, aborting them");
+            for handle in abort_handles {
+                handle.abort();
+            }
+        }
+    }
+}
+
+/// Fires once the [`BackgroundTasks`] of a task are stopped or dropped.
+pub struct StopSignal(Receiver<bool>);
+
+impl StopSignal {
+    pub fn is_stopped(&self) -> bool {
+        *self.0.borrow() || self.0.has_changed().is_err()
+    }
+
+    /// Waits until the task is signalled to stop.
+    pub async fn stopped(&mut self) {
+        // An error means the tasks were dropped without being stopped, which is just as final
+        _ = self.0.wait_for(|stopped| *stopped).await;
+    }
+}
@@ -0,0 +1,32 @@
+use crate::extractors::logged_in::LoggedIn;
+use actix_web::{FromRequest, HttpRequest, dev::Payload};
+use futures::{FutureExt as _, future::LocalBoxFuture};
+use mysk_lib::{models::user::User, prelude::*};
+use serde::Serialize;
+
+/// Extractor to allow only clients that are logged in as admins.
+#[derive(Serialize)]
+pub struct LoggedInAdmin(pub User);
+
+impl FromRequest for LoggedInAdmin {
+    type Error = Error;
+    type Future = LocalBoxFuture<'static, Result<Self>>;
+
+    fn from_request(req: &HttpRequest, payload: &mut Payload) -> Self::Future {
+        let source = req.path().to_string();
+        let user = LoggedIn::from_request(req, payload);
+
+        async move {
+            let user = user.await?.0;
+            if !user.is_admin {
+                return Err(Error::InvalidPermission(
+                    "User is not an admin".to_string(),
+                    source,
+                ));
+            }
+
+            Ok(LoggedInAdmin(user))
+        }
+        .boxed_local()
+    }
+}
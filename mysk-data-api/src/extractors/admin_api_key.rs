@@ -0,0 +1,40 @@
+use crate::{AppState, extractors::api_key::ApiKeyHeader};
+use actix_web::{FromRequest, HttpRequest, dev::Payload, web::Data};
+use futures::{FutureExt as _, future::LocalBoxFuture};
+use mysk_lib::prelude::*;
+use sqlx::query_scalar;
+
+/// Extractor to allow only clients with a valid API key belonging to an admin.
+pub struct AdminApiKeyHeader;
+
+impl FromRequest for AdminApiKeyHeader {
+    type Error = Error;
+    type Future = LocalBoxFuture<'static, Result<Self>>;
+
+    fn from_request(req: &HttpRequest, payload: &mut Payload) -> Self::Future {
+        let app_state = req
+            .app_data::<Data<AppState>>()
+            .expect("Irrecoverable error, AppState is None");
+        let pool = app_state.db.clone();
+        let source = req.path().to_string();
+        let api_key = ApiKeyHeader::from_request(req, payload);
+
+        async move {
+            let ApiKeyHeader(api_key) = api_key.await?;
+            let is_admin =
+                query_scalar!("SELECT is_admin FROM users WHERE id = $1", api_key.user_id)
+                    .fetch_optional(&pool)
+                    .await?
+                    .unwrap_or(false);
+            if !is_admin {
+                return Err(Error::InvalidPermission(
+                    "API key does not belong to an admin".to_string(),
+                    source,
+                ));
+            }
+
+            Ok(AdminApiKeyHeader)
+        }
+        .boxed_local()
+    }
+}
@@ -1,5 +1,5 @@
-use crate::AppState;
-use actix_web::{FromRequest, HttpRequest, dev::Payload, web::Data};
+use crate::{AppState, extractors::SharedCredentials};
+use actix_web::{FromRequest, HttpMessage as _, HttpRequest, dev::Payload, web::Data};
 use futures::{
     FutureExt as _,
     future::{self, LocalBoxFuture},
@@ -11,20 +11,30 @@ use mysk_lib::{
 use serde::Serialize;
 use sha2::{Digest, Sha256};
 use sqlx::query_as;
+use uuid::Uuid;
 
 /// Extractor to allow only clients with a valid API key.
 #[derive(Serialize)]
-pub struct ApiKeyHeader(ApiKey);
+pub struct ApiKeyHeader(pub ApiKey);
+
+/// The ID of the API key used for the request, stored in the request extensions once validated.
+#[derive(Clone, Copy)]
+pub struct ApiKeyId(pub Uuid);
 
 impl FromRequest for ApiKeyHeader {
     type Error = Error;
     type Future = LocalBoxFuture<'static, Result<Self>>;
 
     fn from_request(req: &HttpRequest, _payload: &mut Payload) -> Self::Future {
+        if let Some(credentials) = req.extensions().get::<SharedCredentials>() {
+            return future::ok(ApiKeyHeader(credentials.api_key.clone())).boxed();
+        }
+
         let app_state = req
             .app_data::<Data<AppState>>()
             .expect("Irrecoverable error, AppState is None");
         let pool = app_state.db.clone();
+        let req = req.clone();
         let source = req.path().to_string();
         let token = if let Some(token) = req.headers().get("X-Api-Key") {
             let Ok(token) = token.to_str() else {
@@ -60,8 +70,11 @@ impl FromRequest for ApiKeyHeader {
                 return Err(Error::MissingApiKey("Missing API Key".to_string(), source));
             };
 
+            tracing::Span::current().record("api_key_id", tracing::field::display(api_key.id));
+            req.extensions_mut().insert(ApiKeyId(api_key.id));
+
             Ok(ApiKeyHeader(api_key))
         }
-        .boxed()
+        .boxed_local()
     }
 }
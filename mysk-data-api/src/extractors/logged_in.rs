@@ -1,5 +1,7 @@
-use crate::AppState;
-use actix_web::{FromRequest, HttpRequest, dev::Payload, http::header, web::Data};
+use crate::{AppState, extractors::SharedCredentials};
+use actix_web::{
+    FromRequest, HttpMessage as _, HttpRequest, dev::Payload, http::header, web::Data,
+};
 use futures::{
     FutureExt as _,
     future::{self, LocalBoxFuture},
@@ -7,16 +9,29 @@ use futures::{
 use jsonwebtoken::{DecodingKey, Validation, decode};
 use mysk_lib::{auth::oauth::TokenClaims, models::user::User, prelude::*};
 use serde::Serialize;
+use uuid::Uuid;
 
 /// Extractor to allow only clients that are logged in.
 #[derive(Serialize)]
 pub struct LoggedIn(pub User);
 
+/// The ID of the logged in user, stored in the request extensions once authenticated.
+#[derive(Clone, Copy)]
+pub struct UserId(pub Uuid);
+
 impl FromRequest for LoggedIn {
     type Error = Error;
     type Future = LocalBoxFuture<'static, Result<Self>>;
 
     fn from_request(req: &HttpRequest, _payload: &mut Payload) -> Self::Future {
+        if let Some(user) = req
+            .extensions()
+            .get::<SharedCredentials>()
+            .and_then(|credentials| credentials.user.clone())
+        {
+            return future::ok(LoggedIn(user)).boxed();
+        }
+
         let app_state = req
             .app_data::<Data<AppState>>()
             .expect("Irrecoverable error, AppState is None");
@@ -72,16 +87,21 @@ impl FromRequest for LoggedIn {
             .boxed();
         };
 
+        let req = req.clone();
+
         async move {
-            Ok(LoggedIn(
-                User::get_by_id(
-                    &mut *(conn.await?),
-                    decoded_token.claims.sub,
-                    decoded_token.claims.mta,
-                )
-                .await?,
-            ))
+            let mut user = User::get_by_id(
+                &mut *(conn.await?),
+                decoded_token.claims.sub,
+                decoded_token.claims.mta,
+            )
+            .await?;
+            user.impersonator_id = decoded_token.claims.imp;
+            tracing::Span::current().record("user_id", tracing::field::display(user.id));
+            req.extensions_mut().insert(UserId(user.id));
+
+            Ok(LoggedIn(user))
         }
-        .boxed()
+        .boxed_local()
     }
 }
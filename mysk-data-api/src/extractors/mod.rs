@@ -1,6 +1,18 @@
 //! Custom extractors that sometimes also functions as middlewares.
 
+use mysk_lib::{auth::key::ApiKey, models::user::User};
+
+pub mod admin;
+pub mod admin_api_key;
 pub mod api_key;
 pub mod logged_in;
 pub mod student;
 pub mod teacher;
+
+/// Credentials already checked by the batch route, stored in the extensions of each of its
+/// operations so [`api_key::ApiKeyHeader`] and [`logged_in::LoggedIn`] don't check them again.
+#[derive(Clone)]
+pub struct SharedCredentials {
+    pub api_key: ApiKey,
+    pub user: Option<User>,
+}
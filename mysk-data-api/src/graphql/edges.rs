@@ -0,0 +1,261 @@
+use crate::graphql::loaders::Edge;
+use mysk_lib::{
+    helpers::date::get_current_academic_year, models::enums::SubmissionStatus, prelude::*,
+};
+use sqlx::{PgPool, query};
+use std::collections::HashMap;
+use uuid::Uuid;
+
+/// Groups related IDs by the row they are related to.
+fn group<V>(pairs: impl IntoIterator<Item = (Uuid, V)>) -> HashMap<Uuid, Vec<V>> {
+    let mut groups = HashMap::<_, Vec<_>>::new();
+    for (id, value) in pairs {
+        groups.entry(id).or_default().push(value);
+    }
+
+    groups
+}
+
+/// The teachers advising each classroom this academic year.
+pub struct ClassroomAdvisors;
+
+impl Edge for ClassroomAdvisors {
+    type Value = Vec<Uuid>;
+
+    async fn load(pool: &PgPool, ids: &[Uuid]) -> Result<HashMap<Uuid, Vec<Uuid>>> {
+        let res = query!(
+            "\
+            SELECT classroom_id, teacher_id FROM classroom_advisors \
+            JOIN classrooms AS c ON c.id = classroom_id \
+            WHERE classroom_id = ANY($1) AND year = $2\
+            ",
+            ids,
+            get_current_academic_year(None),
+        )
+        .fetch_all(pool)
+        .await?;
+
+        Ok(group(
+            res.into_iter().map(|r| (r.classroom_id, r.teacher_id)),
+        ))
+    }
+}
+
+/// The contacts of each classroom.
+pub struct ClassroomContacts;
+
+impl Edge for ClassroomContacts {
+    type Value = Vec<Uuid>;
+
+    async fn load(pool: &PgPool, ids: &[Uuid]) -> Result<HashMap<Uuid, Vec<Uuid>>> {
+        let res = query!(
+            "SELECT classroom_id, contact_id FROM classroom_contacts WHERE classroom_id = ANY($1)",
+            ids,
+        )
+        .fetch_all(pool)
+        .await?;
+
+        Ok(group(
+            res.into_iter().map(|r| (r.classroom_id, r.contact_id)),
+        ))
+    }
+}
+
+/// The students of each classroom.
+pub struct ClassroomStudents;
+
+impl Edge for ClassroomStudents {
+    type Value = Vec<Uuid>;
+
+    async fn load(pool: &PgPool, ids: &[Uuid]) -> Result<HashMap<Uuid, Vec<Uuid>>> {
+        let res = query!(
+            "\
+            SELECT classroom_id, student_id FROM classroom_students \
+            WHERE classroom_id = ANY($1) ORDER BY class_no\
+            ",
+            ids,
+        )
+        .fetch_all(pool)
+        .await?;
+
+        Ok(group(
+            res.into_iter().map(|r| (r.classroom_id, r.student_id)),
+        ))
+    }
+}
+
+/// The contacts of each club.
+pub struct ClubContacts;
+
+impl Edge for ClubContacts {
+    type Value = Vec<Uuid>;
+
+    async fn load(pool: &PgPool, ids: &[Uuid]) -> Result<HashMap<Uuid, Vec<Uuid>>> {
+        let res = query!(
+            "SELECT club_id, contact_id FROM club_contacts WHERE club_id = ANY($1)",
+            ids,
+        )
+        .fetch_all(pool)
+        .await?;
+
+        Ok(group(res.into_iter().map(|r| (r.club_id, r.contact_id))))
+    }
+}
+
+/// The approved members of each club this academic year.
+pub struct ClubMembers;
+
+impl Edge for ClubMembers {
+    type Value = Vec<Uuid>;
+
+    async fn load(pool: &PgPool, ids: &[Uuid]) -> Result<HashMap<Uuid, Vec<Uuid>>> {
+        let res = query!(
+            "\
+            SELECT club_id, student_id FROM club_members \
+            WHERE club_id = ANY($1) AND year = $2 AND membership_status = $3\
+            ",
+            ids,
+            get_current_academic_year(None),
+            SubmissionStatus::Approved as SubmissionStatus,
+        )
+        .fetch_all(pool)
+        .await?;
+
+        Ok(group(res.into_iter().map(|r| (r.club_id, r.student_id))))
+    }
+}
+
+/// The staff of each club this academic year.
+pub struct ClubStaffs;
+
+impl Edge for ClubStaffs {
+    type Value = Vec<Uuid>;
+
+    async fn load(pool: &PgPool, ids: &[Uuid]) -> Result<HashMap<Uuid, Vec<Uuid>>> {
+        let res = query!(
+            "SELECT club_id, student_id FROM club_staffs WHERE club_id = ANY($1) AND year = $2",
+            ids,
+            get_current_academic_year(None),
+        )
+        .fetch_all(pool)
+        .await?;
+
+        Ok(group(res.into_iter().map(|r| (r.club_id, r.student_id))))
+    }
+}
+
+/// The allergies of each person.
+pub struct PersonAllergies;
+
+impl Edge for PersonAllergies {
+    type Value = Vec<String>;
+
+    async fn load(pool: &PgPool, ids: &[Uuid]) -> Result<HashMap<Uuid, Vec<String>>> {
+        let res = query!(
+            "SELECT person_id, allergy_name FROM person_allergies WHERE person_id = ANY($1)",
+            ids,
+        )
+        .fetch_all(pool)
+        .await?;
+
+        Ok(group(
+            res.into_iter().map(|r| (r.person_id, r.allergy_name)),
+        ))
+    }
+}
+
+/// The classroom of each student this academic year, along with their class number in it.
+pub struct StudentClassroom;
+
+impl Edge for StudentClassroom {
+    type Value = (Uuid, i64);
+
+    async fn load(pool: &PgPool, ids: &[Uuid]) -> Result<HashMap<Uuid, (Uuid, i64)>> {
+        let res = query!(
+            "\
+            SELECT student_id, classroom_id, class_no FROM classroom_students \
+            JOIN classrooms ON classrooms.id = classroom_id \
+            WHERE student_id = ANY($1) AND year = $2\
+            ",
+            ids,
+            get_current_academic_year(None),
+        )
+        .fetch_all(pool)
+        .await?;
+
+        Ok(res
+            .into_iter()
+            .map(|r| (r.student_id, (r.classroom_id, r.class_no)))
+            .collect())
+    }
+}
+
+/// The contacts of each student.
+pub struct StudentContacts;
+
+impl Edge for StudentContacts {
+    type Value = Vec<Uuid>;
+
+    async fn load(pool: &PgPool, ids: &[Uuid]) -> Result<HashMap<Uuid, Vec<Uuid>>> {
+        let res = query!(
+            "\
+            SELECT students.id AS student_id, person_contacts.contact_id FROM students \
+            INNER JOIN person_contacts ON person_contacts.person_id = students.person_id \
+            WHERE students.id = ANY($1)\
+            ",
+            ids,
+        )
+        .fetch_all(pool)
+        .await?;
+
+        Ok(group(res.into_iter().map(|r| (r.student_id, r.contact_id))))
+    }
+}
+
+/// The classroom each teacher advises this academic year.
+pub struct TeacherAdvisorAt;
+
+impl Edge for TeacherAdvisorAt {
+    type Value = Uuid;
+
+    async fn load(pool: &PgPool, ids: &[Uuid]) -> Result<HashMap<Uuid, Uuid>> {
+        let res = query!(
+            "\
+            SELECT teacher_id, classroom_id FROM classroom_advisors \
+            INNER JOIN classrooms ON classrooms.id = classroom_id \
+            WHERE teacher_id = ANY($1) AND classrooms.year = $2\
+            ",
+            ids,
+            get_current_academic_year(None),
+        )
+        .fetch_all(pool)
+        .await?;
+
+        Ok(res
+            .into_iter()
+            .map(|r| (r.teacher_id, r.classroom_id))
+            .collect())
+    }
+}
+
+/// The contacts of each teacher.
+pub struct TeacherContacts;
+
+impl Edge for TeacherContacts {
+    type Value = Vec<Uuid>;
+
+    async fn load(pool: &PgPool, ids: &[Uuid]) -> Result<HashMap<Uuid, Vec<Uuid>>> {
+        let res = query!(
+            "\
+            SELECT teachers.id AS teacher_id, person_contacts.contact_id FROM teachers \
+            INNER JOIN person_contacts ON person_contacts.person_id = teachers.person_id \
+            WHERE teachers.id = ANY($1)\
+            ",
+            ids,
+        )
+        .fetch_all(pool)
+        .await?;
+
+        Ok(group(res.into_iter().map(|r| (r.teacher_id, r.contact_id))))
+    }
+}
@@ -0,0 +1,77 @@
+use crate::graphql::to_graphql_error;
+use async_graphql::dataloader::Loader;
+use mysk_lib::{models::traits::GetById, prelude::*};
+use sqlx::PgPool;
+use std::{collections::HashMap, hash::Hash, marker::PhantomData};
+use uuid::Uuid;
+
+/// A relation whose rows can be loaded in batches by their IDs.
+pub trait Row: GetById<Id: Copy + Eq + Hash + Send + Sync> + Clone + Sync {
+    fn id(&self) -> Self::Id;
+}
+
+/// Loads the rows of a relation by their IDs, one query per batch.
+pub struct RowLoader<R> {
+    pool: PgPool,
+    relation: PhantomData<fn() -> R>,
+}
+
+impl<R> RowLoader<R> {
+    pub fn new(pool: PgPool) -> Self {
+        Self {
+            pool,
+            relation: PhantomData,
+        }
+    }
+}
+
+impl<R: Row> Loader<R::Id> for RowLoader<R> {
+    type Value = R;
+    type Error = async_graphql::Error;
+
+    async fn load(&self, ids: &[R::Id]) -> Result<HashMap<R::Id, R>, Self::Error> {
+        let rows = R::get_by_ids(&self.pool, ids)
+            .await
+            .map_err(|err| to_graphql_error(&err.into()))?;
+
+        Ok(rows.into_iter().map(|row| (row.id(), row)).collect())
+    }
+}
+
+/// A relationship from rows of one relation to something related to each of them, such as the
+/// contacts of each student.
+pub trait Edge: 'static {
+    type Value: Clone + Send + Sync + 'static;
+
+    /// Gets what is related to each of the rows, leaving out rows without anything related.
+    fn load(
+        pool: &PgPool,
+        ids: &[Uuid],
+    ) -> impl Future<Output = Result<HashMap<Uuid, Self::Value>>> + Send;
+}
+
+/// Loads an [`Edge`] for many rows at once, one query per batch.
+pub struct EdgeLoader<E> {
+    pool: PgPool,
+    edge: PhantomData<fn() -> E>,
+}
+
+impl<E> EdgeLoader<E> {
+    pub fn new(pool: PgPool) -> Self {
+        Self {
+            pool,
+            edge: PhantomData,
+        }
+    }
+}
+
+impl<E: Edge> Loader<Uuid> for EdgeLoader<E> {
+    type Value = E::Value;
+    type Error = async_graphql::Error;
+
+    async fn load(&self, ids: &[Uuid]) -> Result<HashMap<Uuid, E::Value>, Self::Error> {
+        E::load(&self.pool, ids)
+            .await
+            .map_err(|err| to_graphql_error(&err))
+    }
+}
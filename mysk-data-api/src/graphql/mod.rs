@@ -0,0 +1,115 @@
+//! A read-only GraphQL schema over the models, letting clients pick nested fields precisely rather
+//! than one fetch level for a whole tree.
+//!
+//! Each field belongs to the fetch level it first appears at in the model's fetch variants, and is
+//! only resolved once the requesting user is authorized to read its parent at that level. Rows and
+//! the relations between them are loaded in batches per request through [`DataLoader`]s.
+
+use async_graphql::{
+    EmptyMutation, EmptySubscription, ErrorExtensions as _, Request, Schema, dataloader::DataLoader,
+};
+use mysk_lib::{
+    common::response::ErrorType,
+    models::{
+        classroom::db::DbClassroom, club::db::DbClub, contact::db::DbContact, person::db::DbPerson,
+        student::db::DbStudent, subject_group::db::DbSubjectGroup, teacher::db::DbTeacher,
+    },
+    permissions::Authorizer,
+    prelude::*,
+};
+use sqlx::{PgPool, Postgres, pool::PoolConnection};
+use std::sync::LazyLock;
+use tokio::sync::Mutex;
+
+mod edges;
+mod loaders;
+mod node;
+mod objects;
+mod query;
+
+use loaders::{EdgeLoader, RowLoader};
+use query::Query;
+
+/// The deepest a query can nest its selections.
+const MAX_DEPTH: usize = 12;
+
+/// The most fields a single query can select, counting each field of every nested selection, once
+/// for each row a list can return.
+const MAX_COMPLEXITY: usize = 2000;
+
+pub type MyskSchema = Schema<Query, EmptyMutation, EmptySubscription>;
+
+pub static SCHEMA: LazyLock<MyskSchema> = LazyLock::new(|| {
+    Schema::build(Query, EmptyMutation, EmptySubscription)
+        .limit_depth(MAX_DEPTH)
+        .limit_complexity(MAX_COMPLEXITY)
+        .finish()
+});
+
+/// The state shared by the resolvers of a single request.
+pub struct GraphQlContext {
+    pub pool: PgPool,
+    pub authorizer: Authorizer,
+    /// The connection the rows are authorized on, acquired by the first of them and reused by the
+    /// rest, so however many rows a query reads it holds at most one connection for them.
+    authorization_conn: Mutex<Option<PoolConnection<Postgres>>>,
+}
+
+/// Attaches the state and the loaders for a single request, so batches never mix rows read by
+/// different users.
+pub fn prepare_request(request: Request, pool: &PgPool, authorizer: Authorizer) -> Request {
+    request
+        .data(GraphQlContext {
+            pool: pool.clone(),
+            authorizer,
+            authorization_conn: Mutex::new(None),
+        })
+        .data(row_loader::<DbClassroom>(pool))
+        .data(row_loader::<DbClub>(pool))
+        .data(row_loader::<DbContact>(pool))
+        .data(row_loader::<DbPerson>(pool))
+        .data(row_loader::<DbStudent>(pool))
+        .data(row_loader::<DbSubjectGroup>(pool))
+        .data(row_loader::<DbTeacher>(pool))
+        .data(edge_loader::<edges::ClassroomAdvisors>(pool))
+        .data(edge_loader::<edges::ClassroomContacts>(pool))
+        .data(edge_loader::<edges::ClassroomStudents>(pool))
+        .data(edge_loader::<edges::ClubContacts>(pool))
+        .data(edge_loader::<edges::ClubMembers>(pool))
+        .data(edge_loader::<edges::ClubStaffs>(pool))
+        .data(edge_loader::<edges::PersonAllergies>(pool))
+        .data(edge_loader::<edges::StudentClassroom>(pool))
+        .data(edge_loader::<edges::StudentContacts>(pool))
+        .data(edge_loader::<edges::TeacherAdvisorAt>(pool))
+        .data(edge_loader::<edges::TeacherContacts>(pool))
+}
+
+fn row_loader<R: loaders::Row>(pool: &PgPool) -> DataLoader<RowLoader<R>> {
+    DataLoader::new(RowLoader::new(pool.clone()), tokio::spawn)
+}
+
+fn edge_loader<E: loaders::Edge>(pool: &PgPool) -> DataLoader<EdgeLoader<E>> {
+    DataLoader::new(EdgeLoader::new(pool.clone()), tokio::spawn)
+}
+
+/// Converts an error into a GraphQL error, keeping the fields of the REST error response as
+/// extensions.
+pub fn to_graphql_error(error: &Error) -> async_graphql::Error {
+    let ErrorType {
+        code,
+        error_type,
+        detail,
+        source,
+        error_code,
+        ..
+    } = error.into();
+
+    async_graphql::Error::new(detail).extend_with(|_, extensions| {
+        extensions.set("code", code);
+        extensions.set("error_type", error_type);
+        extensions.set("source", source);
+        if let Some(error_code) = error_code {
+            extensions.set("error_code", error_code.to_string());
+        }
+    })
+}
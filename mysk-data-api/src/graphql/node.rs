@@ -0,0 +1,109 @@
+use crate::graphql::{
+    GraphQlContext,
+    loaders::{Edge, EdgeLoader, Row, RowLoader},
+    to_graphql_error,
+};
+use async_graphql::{Context, Result, dataloader::DataLoader};
+use mysk_lib::{
+    common::{requests::FetchLevel, string::FlexibleMultiLangString},
+    permissions::{ActionType, Authorizer},
+};
+use sqlx::PgConnection;
+use tokio::sync::OnceCell;
+use uuid::Uuid;
+
+/// A row which is only read once the user is authorized to read it at a fetch level.
+pub trait Node: Row + Send {
+    fn authorize(
+        &self,
+        authorizer: &Authorizer,
+        conn: &mut PgConnection,
+        action: ActionType,
+    ) -> impl Future<Output = mysk_lib::prelude::Result<()>> + Send;
+}
+
+/// A row along with whether the user is authorized to read it at each fetch level, so each level
+/// is checked at most once however many of its fields are selected.
+pub struct Guarded<R> {
+    row: R,
+    access: [OnceCell<Result<()>>; 4],
+}
+
+impl<R: Node> Guarded<R> {
+    pub fn new(row: R) -> Self {
+        Self {
+            row,
+            access: Default::default(),
+        }
+    }
+
+    /// Gets the row if the user is authorized to read it at the fetch level.
+    pub async fn read(&self, ctx: &Context<'_>, level: FetchLevel) -> Result<&R> {
+        let (index, action) = match level {
+            FetchLevel::IdOnly => (0, ActionType::ReadIdOnly),
+            FetchLevel::Compact => (1, ActionType::ReadCompact),
+            FetchLevel::Default => (2, ActionType::ReadDefault),
+            FetchLevel::Detailed => (3, ActionType::ReadDetailed),
+        };
+
+        self.access[index]
+            .get_or_init(|| async {
+                let GraphQlContext {
+                    pool,
+                    authorizer,
+                    authorization_conn,
+                } = ctx.data_unchecked();
+                let mut shared_conn = authorization_conn.lock().await;
+                let mut conn = match shared_conn.take() {
+                    Some(conn) => conn,
+                    None => pool
+                        .acquire()
+                        .await
+                        .map_err(|err| to_graphql_error(&err.into()))?,
+                };
+
+                let result = self.row.authorize(authorizer, &mut conn, action).await;
+                *shared_conn = Some(conn);
+
+                result.map_err(|err| to_graphql_error(&err))
+            })
+            .await
+            .clone()?;
+
+        Ok(&self.row)
+    }
+}
+
+/// Loads a row by its ID through the request's batches.
+pub async fn load_row<R: Node>(ctx: &Context<'_>, id: R::Id) -> Result<Option<Guarded<R>>> {
+    let loader = ctx.data_unchecked::<DataLoader<RowLoader<R>>>();
+
+    Ok(loader.load_one(id).await?.map(Guarded::new))
+}
+
+/// Loads rows by their IDs through the request's batches, in the order of the IDs.
+pub async fn load_rows<R: Node>(ctx: &Context<'_>, ids: &[R::Id]) -> Result<Vec<Guarded<R>>> {
+    let loader = ctx.data_unchecked::<DataLoader<RowLoader<R>>>();
+    let mut rows = loader.load_many(ids.iter().copied()).await?;
+
+    Ok(ids
+        .iter()
+        .filter_map(|id| rows.remove(id))
+        .map(Guarded::new)
+        .collect())
+}
+
+/// Loads what is related to a row through the request's batches.
+pub async fn load_edge<E: Edge>(ctx: &Context<'_>, id: Uuid) -> Result<Option<E::Value>> {
+    let loader = ctx.data_unchecked::<DataLoader<EdgeLoader<E>>>();
+
+    loader.load_one(id).await
+}
+
+/// Combines the Thai and English versions of an optional string, if either is present.
+pub fn flexible_string(th: Option<String>, en: Option<String>) -> Option<FlexibleMultiLangString> {
+    match (th, en) {
+        (None, None) => None,
+        (th, en) => Some(FlexibleMultiLangString::new(th, en)),
+    }
+}
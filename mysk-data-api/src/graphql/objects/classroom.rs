@@ -0,0 +1,101 @@
+use crate::graphql::{
+    edges::{ClassroomAdvisors, ClassroomContacts, ClassroomStudents},
+    loaders::Row,
+    node::{Guarded, Node, load_edge, load_rows},
+    objects::{contact::Contact, student::Student, teacher::Teacher},
+};
+use async_graphql::{Context, Object, Result};
+use mysk_lib::{
+    common::requests::FetchLevel,
+    models::{
+        classroom::db::DbClassroom, contact::db::DbContact, student::db::DbStudent,
+        teacher::db::DbTeacher,
+    },
+    permissions::{ActionType, Authorizable as _, Authorizer},
+};
+use sqlx::PgConnection;
+use uuid::Uuid;
+
+impl Row for DbClassroom {
+    fn id(&self) -> Uuid {
+        self.id
+    }
+}
+
+impl Node for DbClassroom {
+    async fn authorize(
+        &self,
+        authorizer: &Authorizer,
+        conn: &mut PgConnection,
+        action: ActionType,
+    ) -> mysk_lib::prelude::Result<()> {
+        authorizer.authorize_classroom(self, conn, action).await
+    }
+}
+
+pub struct Classroom(pub Guarded<DbClassroom>);
+
+#[Object]
+impl Classroom {
+    async fn id(&self, ctx: &Context<'_>) -> Result<Uuid> {
+        Ok(self.0.read(ctx, FetchLevel::IdOnly).await?.id)
+    }
+
+    async fn number(&self, ctx: &Context<'_>) -> Result<i64> {
+        Ok(self.0.read(ctx, FetchLevel::Compact).await?.number)
+    }
+
+    async fn room(&self, ctx: &Context<'_>) -> Result<Option<String>> {
+        Ok(self
+            .0
+            .read(ctx, FetchLevel::Compact)
+            .await?
+            .main_room
+            .clone())
+    }
+
+    async fn year(&self, ctx: &Context<'_>) -> Result<i64> {
+        Ok(self.0.read(ctx, FetchLevel::Default).await?.year)
+    }
+
+    /// The teachers advising the classroom this academic year.
+    async fn class_advisors(&self, ctx: &Context<'_>) -> Result<Vec<Teacher>> {
+        let classroom = self.0.read(ctx, FetchLevel::Default).await?;
+        let teacher_ids = load_edge::<ClassroomAdvisors>(ctx, classroom.id)
+            .await?
+            .unwrap_or_default();
+
+        Ok(load_rows::<DbTeacher>(ctx, &teacher_ids)
+            .await?
+            .into_iter()
+            .map(Teacher)
+            .collect())
+    }
+
+    /// The students of the classroom, in order of their class numbers.
+    async fn students(&self, ctx: &Context<'_>) -> Result<Vec<Student>> {
+        let classroom = self.0.read(ctx, FetchLevel::Default).await?;
+        let student_ids = load_edge::<ClassroomStudents>(ctx, classroom.id)
+            .await?
+            .unwrap_or_default();
+
+        Ok(load_rows::<DbStudent>(ctx, &student_ids)
+            .await?
+            .into_iter()
+            .map(Student)
+            .collect())
+    }
+
+    async fn contacts(&self, ctx: &Context<'_>) -> Result<Vec<Contact>> {
+        let classroom = self.0.read(ctx, FetchLevel::Default).await?;
+        let contact_ids = load_edge::<ClassroomContacts>(ctx, classroom.id)
+            .await?
+            .unwrap_or_default();
+
+        Ok(load_rows::<DbContact>(ctx, &contact_ids)
+            .await?
+            .into_iter()
+            .map(Contact)
+            .collect())
+    }
+}
@@ -0,0 +1,136 @@
+use crate::graphql::{
+    edges::{ClubContacts, ClubMembers, ClubStaffs},
+    loaders::Row,
+    node::{Guarded, Node, flexible_string, load_edge, load_rows},
+    objects::{contact::Contact, student::Student},
+};
+use async_graphql::{Context, Object, Result};
+use mysk_lib::{
+    common::{
+        requests::FetchLevel,
+        string::{FlexibleMultiLangString, MultiLangString},
+    },
+    models::{club::db::DbClub, contact::db::DbContact, student::db::DbStudent},
+    permissions::{ActionType, Authorizable as _, Authorizer},
+};
+use sqlx::PgConnection;
+use uuid::Uuid;
+
+impl Row for DbClub {
+    fn id(&self) -> Uuid {
+        self.id
+    }
+}
+
+impl Node for DbClub {
+    async fn authorize(
+        &self,
+        authorizer: &Authorizer,
+        conn: &mut PgConnection,
+        action: ActionType,
+    ) -> mysk_lib::prelude::Result<()> {
+        authorizer.authorize_club(self, conn, action).await
+    }
+}
+
+pub struct Club(pub Guarded<DbClub>);
+
+impl Club {
+    async fn students<E>(&self, ctx: &Context<'_>, level: FetchLevel) -> Result<Vec<Student>>
+    where
+        E: crate::graphql::loaders::Edge<Value = Vec<Uuid>>,
+    {
+        let club = self.0.read(ctx, level).await?;
+        let student_ids = load_edge::<E>(ctx, club.id).await?.unwrap_or_default();
+
+        Ok(load_rows::<DbStudent>(ctx, &student_ids)
+            .await?
+            .into_iter()
+            .map(Student)
+            .collect())
+    }
+}
+
+#[Object]
+impl Club {
+    async fn id(&self, ctx: &Context<'_>) -> Result<Uuid> {
+        Ok(self.0.read(ctx, FetchLevel::IdOnly).await?.id)
+    }
+
+    async fn name(&self, ctx: &Context<'_>) -> Result<MultiLangString> {
+        let club = self.0.read(ctx, FetchLevel::Compact).await?;
+
+        Ok(MultiLangString::new(
+            club.name_th.clone(),
+            club.name_en.clone(),
+        ))
+    }
+
+    async fn description(&self, ctx: &Context<'_>) -> Result<Option<FlexibleMultiLangString>> {
+        let club = self.0.read(ctx, FetchLevel::Compact).await?;
+
+        Ok(flexible_string(
+            club.description_th.clone(),
+            club.description_en.clone(),
+        ))
+    }
+
+    async fn logo_url(&self, ctx: &Context<'_>) -> Result<Option<String>> {
+        Ok(self
+            .0
+            .read(ctx, FetchLevel::Compact)
+            .await?
+            .logo_url
+            .clone())
+    }
+
+    async fn background_color(&self, ctx: &Context<'_>) -> Result<Option<String>> {
+        Ok(self
+            .0
+            .read(ctx, FetchLevel::Compact)
+            .await?
+            .background_color
+            .clone())
+    }
+
+    async fn member_count(&self, ctx: &Context<'_>) -> Result<i64> {
+        Ok(self.0.read(ctx, FetchLevel::Compact).await?.member_count)
+    }
+
+    async fn accent_color(&self, ctx: &Context<'_>) -> Result<Option<String>> {
+        Ok(self
+            .0
+            .read(ctx, FetchLevel::Default)
+            .await?
+            .accent_color
+            .clone())
+    }
+
+    async fn staff_count(&self, ctx: &Context<'_>) -> Result<i64> {
+        Ok(self.0.read(ctx, FetchLevel::Default).await?.staff_count)
+    }
+
+    async fn contacts(&self, ctx: &Context<'_>) -> Result<Vec<Contact>> {
+        let club = self.0.read(ctx, FetchLevel::Default).await?;
+        let contact_ids = load_edge::<ClubContacts>(ctx, club.id)
+            .await?
+            .unwrap_or_default();
+
+        Ok(load_rows::<DbContact>(ctx, &contact_ids)
+            .await?
+            .into_iter()
+            .map(Contact)
+            .collect())
+    }
+
+    /// The staff of the club this academic year.
+    async fn staffs(&self, ctx: &Context<'_>) -> Result<Vec<Student>> {
+        self.students::<ClubStaffs>(ctx, FetchLevel::Detailed).await
+    }
+
+    /// The approved members of the club this academic year.
+    async fn members(&self, ctx: &Context<'_>) -> Result<Vec<Student>> {
+        self.students::<ClubMembers>(ctx, FetchLevel::Detailed)
+            .await
+    }
+}
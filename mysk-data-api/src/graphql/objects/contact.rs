@@ -0,0 +1,55 @@
+use crate::graphql::{
+    loaders::Row,
+    node::{Guarded, Node, flexible_string},
+};
+use async_graphql::{Context, Object, Result};
+use mysk_lib::{
+    common::{requests::FetchLevel, string::FlexibleMultiLangString},
+    models::{contact::db::DbContact, enums::ContactType},
+    permissions::{ActionType, Authorizable as _, Authorizer},
+};
+use sqlx::PgConnection;
+use uuid::Uuid;
+
+impl Row for DbContact {
+    fn id(&self) -> Uuid {
+        self.id
+    }
+}
+
+impl Node for DbContact {
+    async fn authorize(
+        &self,
+        authorizer: &Authorizer,
+        conn: &mut PgConnection,
+        action: ActionType,
+    ) -> mysk_lib::prelude::Result<()> {
+        authorizer.authorize_contact(self, conn, action).await
+    }
+}
+
+pub struct Contact(pub Guarded<DbContact>);
+
+#[Object]
+impl Contact {
+    async fn id(&self, ctx: &Context<'_>) -> Result<Uuid> {
+        Ok(self.0.read(ctx, FetchLevel::IdOnly).await?.id)
+    }
+
+    async fn name(&self, ctx: &Context<'_>) -> Result<Option<FlexibleMultiLangString>> {
+        let contact = self.0.read(ctx, FetchLevel::Default).await?;
+
+        Ok(flexible_string(
+            contact.name_th.clone(),
+            contact.name_en.clone(),
+        ))
+    }
+
+    async fn r#type(&self, ctx: &Context<'_>) -> Result<ContactType> {
+        Ok(self.0.read(ctx, FetchLevel::Default).await?.r#type)
+    }
+
+    async fn value(&self, ctx: &Context<'_>) -> Result<String> {
+        Ok(self.0.read(ctx, FetchLevel::Default).await?.value.clone())
+    }
+}
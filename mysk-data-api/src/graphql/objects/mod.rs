@@ -0,0 +1,7 @@
+pub mod classroom;
+pub mod club;
+pub mod contact;
+pub mod person;
+pub mod student;
+pub mod subject_group;
+pub mod teacher;
@@ -0,0 +1,117 @@
+use crate::graphql::{
+    edges::PersonAllergies,
+    loaders::Row,
+    node::{Guarded, Node, load_edge},
+};
+use async_graphql::{Context, Object, Result};
+use chrono::NaiveDate;
+use mysk_lib::{
+    common::{requests::FetchLevel, string::MultiLangString},
+    models::{
+        enums::{Sex, ShirtSize},
+        person::db::DbPerson,
+    },
+    permissions::{ActionType, Authorizable as _, Authorizer},
+};
+use sqlx::PgConnection;
+use uuid::Uuid;
+
+impl Row for DbPerson {
+    fn id(&self) -> Uuid {
+        self.id
+    }
+}
+
+impl Node for DbPerson {
+    async fn authorize(
+        &self,
+        authorizer: &Authorizer,
+        conn: &mut PgConnection,
+        action: ActionType,
+    ) -> mysk_lib::prelude::Result<()> {
+        authorizer.authorize_person(self, conn, action).await
+    }
+}
+
+pub struct Person(pub Guarded<DbPerson>);
+
+#[Object]
+impl Person {
+    async fn id(&self, ctx: &Context<'_>) -> Result<Uuid> {
+        Ok(self.0.read(ctx, FetchLevel::IdOnly).await?.id)
+    }
+
+    async fn prefix(&self, ctx: &Context<'_>) -> Result<MultiLangString> {
+        let person = self.0.read(ctx, FetchLevel::Default).await?;
+
+        Ok(MultiLangString::new(
+            person.prefix_th.clone(),
+            person.prefix_en.clone(),
+        ))
+    }
+
+    async fn first_name(&self, ctx: &Context<'_>) -> Result<MultiLangString> {
+        let person = self.0.read(ctx, FetchLevel::Default).await?;
+
+        Ok(MultiLangString::new(
+            person.first_name_th.clone(),
+            person.first_name_en.clone(),
+        ))
+    }
+
+    async fn last_name(&self, ctx: &Context<'_>) -> Result<MultiLangString> {
+        let person = self.0.read(ctx, FetchLevel::Default).await?;
+
+        Ok(MultiLangString::new(
+            person.last_name_th.clone(),
+            person.last_name_en.clone(),
+        ))
+    }
+
+    async fn middle_name(&self, ctx: &Context<'_>) -> Result<Option<MultiLangString>> {
+        let person = self.0.read(ctx, FetchLevel::Default).await?;
+
+        Ok(person
+            .middle_name_th
+            .clone()
+            .map(|th| MultiLangString::new(th, person.middle_name_en.clone())))
+    }
+
+    async fn nickname(&self, ctx: &Context<'_>) -> Result<Option<MultiLangString>> {
+        let person = self.0.read(ctx, FetchLevel::Default).await?;
+
+        Ok(person
+            .nickname_th
+            .clone()
+            .map(|th| MultiLangString::new(th, person.nickname_en.clone())))
+    }
+
+    async fn birthdate(&self, ctx: &Context<'_>) -> Result<Option<NaiveDate>> {
+        Ok(self.0.read(ctx, FetchLevel::Default).await?.birthdate)
+    }
+
+    async fn allergies(&self, ctx: &Context<'_>) -> Result<Vec<String>> {
+        let person = self.0.read(ctx, FetchLevel::Default).await?;
+
+        Ok(load_edge::<PersonAllergies>(ctx, person.id)
+            .await?
+            .unwrap_or_default())
+    }
+
+    async fn shirt_size(&self, ctx: &Context<'_>) -> Result<Option<ShirtSize>> {
+        Ok(self.0.read(ctx, FetchLevel::Default).await?.shirt_size)
+    }
+
+    async fn pants_size(&self, ctx: &Context<'_>) -> Result<Option<String>> {
+        Ok(self
+            .0
+            .read(ctx, FetchLevel::Default)
+            .await?
+            .pants_size
+            .clone())
+    }
+
+    async fn sex(&self, ctx: &Context<'_>) -> Result<Sex> {
+        Ok(self.0.read(ctx, FetchLevel::Default).await?.sex)
+    }
+}
@@ -0,0 +1,94 @@
+use crate::graphql::{
+    edges::{StudentClassroom, StudentContacts},
+    loaders::Row,
+    node::{Guarded, Node, load_edge, load_row, load_rows},
+    objects::{classroom::Classroom, contact::Contact, person::Person},
+};
+use async_graphql::{Context, Object, Result};
+use mysk_lib::{
+    common::requests::FetchLevel,
+    models::{
+        classroom::db::DbClassroom, contact::db::DbContact, person::db::DbPerson,
+        student::db::DbStudent,
+    },
+    permissions::{ActionType, Authorizable as _, Authorizer},
+};
+use sqlx::PgConnection;
+use uuid::Uuid;
+
+impl Row for DbStudent {
+    fn id(&self) -> Uuid {
+        self.id
+    }
+}
+
+impl Node for DbStudent {
+    async fn authorize(
+        &self,
+        authorizer: &Authorizer,
+        conn: &mut PgConnection,
+        action: ActionType,
+    ) -> mysk_lib::prelude::Result<()> {
+        authorizer.authorize_student(self, conn, action).await
+    }
+}
+
+pub struct Student(pub Guarded<DbStudent>);
+
+#[Object]
+impl Student {
+    async fn id(&self, ctx: &Context<'_>) -> Result<Uuid> {
+        Ok(self.0.read(ctx, FetchLevel::IdOnly).await?.id)
+    }
+
+    async fn student_id(&self, ctx: &Context<'_>) -> Result<Option<String>> {
+        Ok(self
+            .0
+            .read(ctx, FetchLevel::Compact)
+            .await?
+            .student_id
+            .clone())
+    }
+
+    async fn person(&self, ctx: &Context<'_>) -> Result<Option<Person>> {
+        let student = self.0.read(ctx, FetchLevel::Default).await?;
+
+        Ok(load_row::<DbPerson>(ctx, student.person_id)
+            .await?
+            .map(Person))
+    }
+
+    /// The classroom of the student this academic year.
+    async fn classroom(&self, ctx: &Context<'_>) -> Result<Option<Classroom>> {
+        let student = self.0.read(ctx, FetchLevel::Default).await?;
+        let Some((classroom_id, _)) = load_edge::<StudentClassroom>(ctx, student.id).await? else {
+            return Ok(None);
+        };
+
+        Ok(load_row::<DbClassroom>(ctx, classroom_id)
+            .await?
+            .map(Classroom))
+    }
+
+    /// The class number of the student in their classroom this academic year.
+    async fn class_no(&self, ctx: &Context<'_>) -> Result<Option<i64>> {
+        let student = self.0.read(ctx, FetchLevel::Default).await?;
+
+        Ok(load_edge::<StudentClassroom>(ctx, student.id)
+            .await?
+            .map(|(_, class_no)| class_no))
+    }
+
+    async fn contacts(&self, ctx: &Context<'_>) -> Result<Vec<Contact>> {
+        let student = self.0.read(ctx, FetchLevel::Default).await?;
+        let contact_ids = load_edge::<StudentContacts>(ctx, student.id)
+            .await?
+            .unwrap_or_default();
+
+        Ok(load_rows::<DbContact>(ctx, &contact_ids)
+            .await?
+            .into_iter()
+            .map(Contact)
+            .collect())
+    }
+}
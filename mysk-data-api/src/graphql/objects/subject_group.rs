@@ -0,0 +1,46 @@
+use crate::graphql::{
+    loaders::Row,
+    node::{Guarded, Node},
+};
+use async_graphql::{Context, Object, Result};
+use mysk_lib::{
+    common::{requests::FetchLevel, string::MultiLangString},
+    models::subject_group::db::DbSubjectGroup,
+    permissions::{ActionType, Authorizable as _, Authorizer},
+};
+use sqlx::PgConnection;
+
+impl Row for DbSubjectGroup {
+    fn id(&self) -> i64 {
+        self.id
+    }
+}
+
+impl Node for DbSubjectGroup {
+    async fn authorize(
+        &self,
+        authorizer: &Authorizer,
+        conn: &mut PgConnection,
+        action: ActionType,
+    ) -> mysk_lib::prelude::Result<()> {
+        authorizer.authorize_subject_group(self, conn, action).await
+    }
+}
+
+pub struct SubjectGroup(pub Guarded<DbSubjectGroup>);
+
+#[Object]
+impl SubjectGroup {
+    async fn id(&self, ctx: &Context<'_>) -> Result<i64> {
+        Ok(self.0.read(ctx, FetchLevel::IdOnly).await?.id)
+    }
+
+    async fn name(&self, ctx: &Context<'_>) -> Result<MultiLangString> {
+        let subject_group = self.0.read(ctx, FetchLevel::Default).await?;
+
+        Ok(MultiLangString::new(
+            subject_group.name_th.clone(),
+            Some(subject_group.name_en.clone()),
+        ))
+    }
+}
@@ -0,0 +1,96 @@
+use crate::graphql::{
+    edges::{TeacherAdvisorAt, TeacherContacts},
+    loaders::Row,
+    node::{Guarded, Node, load_edge, load_row, load_rows},
+    objects::{
+        classroom::Classroom, contact::Contact, person::Person, subject_group::SubjectGroup,
+    },
+};
+use async_graphql::{Context, Object, Result};
+use mysk_lib::{
+    common::requests::FetchLevel,
+    models::{
+        classroom::db::DbClassroom, contact::db::DbContact, person::db::DbPerson,
+        subject_group::db::DbSubjectGroup, teacher::db::DbTeacher,
+    },
+    permissions::{ActionType, Authorizable as _, Authorizer},
+};
+use sqlx::PgConnection;
+use uuid::Uuid;
+
+impl Row for DbTeacher {
+    fn id(&self) -> Uuid {
+        self.id
+    }
+}
+
+impl Node for DbTeacher {
+    async fn authorize(
+        &self,
+        authorizer: &Authorizer,
+        conn: &mut PgConnection,
+        action: ActionType,
+    ) -> mysk_lib::prelude::Result<()> {
+        authorizer.authorize_teacher(self, conn, action).await
+    }
+}
+
+pub struct Teacher(pub Guarded<DbTeacher>);
+
+#[Object]
+impl Teacher {
+    async fn id(&self, ctx: &Context<'_>) -> Result<Uuid> {
+        Ok(self.0.read(ctx, FetchLevel::IdOnly).await?.id)
+    }
+
+    async fn teacher_id(&self, ctx: &Context<'_>) -> Result<Option<String>> {
+        Ok(self
+            .0
+            .read(ctx, FetchLevel::Compact)
+            .await?
+            .teacher_id
+            .clone())
+    }
+
+    async fn subject_group(&self, ctx: &Context<'_>) -> Result<Option<SubjectGroup>> {
+        let teacher = self.0.read(ctx, FetchLevel::Compact).await?;
+
+        Ok(load_row::<DbSubjectGroup>(ctx, teacher.subject_group_id)
+            .await?
+            .map(SubjectGroup))
+    }
+
+    async fn person(&self, ctx: &Context<'_>) -> Result<Option<Person>> {
+        let teacher = self.0.read(ctx, FetchLevel::Default).await?;
+        let Some(person_id) = teacher.person_id else {
+            return Ok(None);
+        };
+
+        Ok(load_row::<DbPerson>(ctx, person_id).await?.map(Person))
+    }
+
+    async fn contacts(&self, ctx: &Context<'_>) -> Result<Vec<Contact>> {
+        let teacher = self.0.read(ctx, FetchLevel::Default).await?;
+        let contact_ids = load_edge::<TeacherContacts>(ctx, teacher.id)
+            .await?
+            .unwrap_or_default();
+
+        Ok(load_rows::<DbContact>(ctx, &contact_ids)
+            .await?
+            .into_iter()
+            .map(Contact)
+            .collect())
+    }
+
+    /// The classroom the teacher advises this academic year.
+    async fn class_advisor_at(&self, ctx: &Context<'_>) -> Result<Option<Classroom>> {
+        let teacher = self.0.read(ctx, FetchLevel::Default).await?;
+        let Some(classroom_id) = load_edge::<TeacherAdvisorAt>(ctx, teacher.id).await? else {
+            return Ok(None);
+        };
+
+        Ok(load_row::<DbClassroom>(ctx, classroom_id)
+            .await?
+            .map(Classroom))
+    }
+}
@@ -0,0 +1,151 @@
+use crate::graphql::{
+    GraphQlContext,
+    node::{Guarded, Node, load_row},
+    objects::{classroom::Classroom, club::Club, student::Student, teacher::Teacher},
+    to_graphql_error,
+};
+use async_graphql::{Context, Object, Result};
+use mysk_lib::{
+    common::{
+        pagination::{PaginationConfig, PaginationType},
+        requests::{FilterConfig, SortingConfig},
+    },
+    models::{
+        classroom::db::DbClassroom,
+        club::{
+            db::DbClub,
+            request::{queryable::QueryableClub, sortable::SortableClub},
+        },
+        student::{
+            db::DbStudent,
+            request::{queryable::QueryableStudent, sortable::SortableStudent},
+        },
+        teacher::{
+            db::DbTeacher,
+            request::{queryable::QueryableTeacher, sortable::SortableTeacher},
+        },
+        traits::QueryRelation as _,
+    },
+    prelude::*,
+};
+use std::fmt::Display;
+use uuid::Uuid;
+
+/// The most rows a query for a list returns at once.
+const MAX_PAGE_SIZE: u32 = 100;
+
+pub struct Query;
+
+/// The arguments shared by every query for a list of rows, as taken by
+/// [`QueryRelation::query`](mysk_lib::models::traits::QueryRelation::query).
+struct ListArgs<Q, S: Display> {
+    filter: Option<FilterConfig<Q>>,
+    sort: Option<SortingConfig<S>>,
+    pagination: PaginationConfig,
+}
+
+impl<Q, S: Display> ListArgs<Q, S> {
+    fn new(
+        filter: Option<Q>,
+        search: Option<String>,
+        sort: Option<Vec<S>>,
+        ascending: Option<bool>,
+        page: u32,
+        size: u32,
+    ) -> Self {
+        Self {
+            filter: match (filter, search) {
+                (None, None) => None,
+                (data, q) => Some(FilterConfig { data, q }),
+            },
+            sort: sort.map(|by| SortingConfig::new(by, ascending)),
+            pagination: PaginationConfig::new(page, Some(size.min(MAX_PAGE_SIZE))),
+        }
+    }
+}
+
+/// Wraps the rows of a list query, converting its error.
+fn guard_rows<R: Node, T>(
+    rows: std::result::Result<(Vec<R>, PaginationType), Error>,
+    wrap: fn(Guarded<R>) -> T,
+) -> Result<Vec<T>> {
+    let (rows, _) = rows.map_err(|err| to_graphql_error(&err))?;
+
+    Ok(rows.into_iter().map(Guarded::new).map(wrap).collect())
+}
+
+#[Object]
+impl Query {
+    async fn student(&self, ctx: &Context<'_>, id: Uuid) -> Result<Option<Student>> {
+        Ok(load_row::<DbStudent>(ctx, id).await?.map(Student))
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    #[graphql(complexity = "size.min(MAX_PAGE_SIZE) as usize * child_complexity")]
+    async fn students(
+        &self,
+        ctx: &Context<'_>,
+        filter: Option<QueryableStudent>,
+        search: Option<String>,
+        sort: Option<Vec<SortableStudent>>,
+        ascending: Option<bool>,
+        #[graphql(default = 1)] page: u32,
+        #[graphql(default = 50)] size: u32,
+    ) -> Result<Vec<Student>> {
+        let GraphQlContext { pool, .. } = ctx.data_unchecked();
+        let args = ListArgs::new(filter, search, sort, ascending, page, size);
+        let rows = DbStudent::query(pool, args.filter, args.sort, Some(args.pagination)).await;
+
+        guard_rows(rows, Student)
+    }
+
+    async fn teacher(&self, ctx: &Context<'_>, id: Uuid) -> Result<Option<Teacher>> {
+        Ok(load_row::<DbTeacher>(ctx, id).await?.map(Teacher))
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    #[graphql(complexity = "size.min(MAX_PAGE_SIZE) as usize * child_complexity")]
+    async fn teachers(
+        &self,
+        ctx: &Context<'_>,
+        filter: Option<QueryableTeacher>,
+        search: Option<String>,
+        sort: Option<Vec<SortableTeacher>>,
+        ascending: Option<bool>,
+        #[graphql(default = 1)] page: u32,
+        #[graphql(default = 50)] size: u32,
+    ) -> Result<Vec<Teacher>> {
+        let GraphQlContext { pool, .. } = ctx.data_unchecked();
+        let args = ListArgs::new(filter, search, sort, ascending, page, size);
+        let rows = DbTeacher::query(pool, args.filter, args.sort, Some(args.pagination)).await;
+
+        guard_rows(rows, Teacher)
+    }
+
+    async fn classroom(&self, ctx: &Context<'_>, id: Uuid) -> Result<Option<Classroom>> {
+        Ok(load_row::<DbClassroom>(ctx, id).await?.map(Classroom))
+    }
+
+    async fn club(&self, ctx: &Context<'_>, id: Uuid) -> Result<Option<Club>> {
+        Ok(load_row::<DbClub>(ctx, id).await?.map(Club))
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    #[graphql(complexity = "size.min(MAX_PAGE_SIZE) as usize * child_complexity")]
+    async fn clubs(
+        &self,
+        ctx: &Context<'_>,
+        filter: Option<QueryableClub>,
+        search: Option<String>,
+        sort: Option<Vec<SortableClub>>,
+        ascending: Option<bool>,
+        #[graphql(default = 1)] page: u32,
+        #[graphql(default = 50)] size: u32,
+    ) -> Result<Vec<Club>> {
+        let GraphQlContext { pool, .. } = ctx.data_unchecked();
+        let args = ListArgs::new(filter, search, sort, ascending, page, size);
+        let rows = DbClub::query(pool, args.filter, args.sort, Some(args.pagination)).await;
+
+        guard_rows(rows, Club)
+    }
+}
@@ -0,0 +1,84 @@
+use crate::background::BackgroundTasks;
+use chrono::NaiveDate;
+use mysk_lib::cache::GlobalCache;
+use serde::Deserialize;
+use sqlx::{PgPool, postgres::PgListener};
+use std::{sync::Arc, time::Duration};
+use uuid::Uuid;
+
+/// The channel `notify_jaturamitr_period` announces changed practice periods on.
+const CHANNEL: &str = "jaturamitr_periods";
+/// How long to wait before listening again after losing the connection.
+const RETRY_DELAY: Duration = Duration::from_secs(5);
+
+/// A practice period as it was marked or unmarked as Jaturamitr.
+#[derive(Debug, Deserialize)]
+struct JaturamitrPeriodEvent {
+    id: Uuid,
+    date: NaiveDate,
+    is_jaturamitr: bool,
+}
+
+/// The background task keeping the Jaturamitr periods in the [`GlobalCache`] in step with the
+/// database. Changes are announced through Postgres on commit, so every instance sees those made
+/// by the others. The whole set is reloaded whenever the connection is (re)established, as changes
+/// made meanwhile are missed.
+pub struct JaturamitrSync;
+
+impl JaturamitrSync {
+    pub fn spawn(pool: PgPool, cache: Arc<GlobalCache>, tasks: &mut BackgroundTasks) {
+        tasks.spawn(|mut stop| async move {
+            tokio::select! {
+                () = Self::run(pool, cache) => (),
+                () = stop.stopped() => (),
+            }
+        });
+    }
+
+    async fn run(pool: PgPool, cache: Arc<GlobalCache>) {
+        loop {
+            let mut listener = match PgListener::connect_with(&pool).await {
+                Ok(listener) => listener,
+                Err(err) => {
+                    tracing::error!("Failed to connect the Jaturamitr period sync: {err}");
+                    tokio::time::sleep(RETRY_DELAY).await;
+                    continue;
+                }
+            };
+            if let Err(err) = listener.listen(CHANNEL).await {
+                tracing::error!("Failed to listen for Jaturamitr periods: {err}");
+                tokio::time::sleep(RETRY_DELAY).await;
+                continue;
+            }
+            if let Err(err) = cache.reload_jaturamitr_periods(&pool).await {
+                tracing::error!("Failed to reload Jaturamitr periods: {err}");
+                tokio::time::sleep(RETRY_DELAY).await;
+                continue;
+            }
+
+            loop {
+                match listener.try_recv().await {
+                    Ok(Some(notification)) => {
+                        match serde_json::from_str::<JaturamitrPeriodEvent>(notification.payload())
+                        {
+                            Ok(event) if event.is_jaturamitr => {
+                                cache.insert_jaturamitr_period(event.id, event.date).await;
+                            }
+                            Ok(event) => cache.remove_jaturamitr_period(event.id).await,
+                            Err(err) => {
+                                tracing::error!("Malformed Jaturamitr period notification: {err}");
+                            }
+                        }
+                    }
+                    // The connection was lost, so listen again and reload what was missed
+                    Ok(None) => break,
+                    Err(err) => {
+                        tracing::error!("Lost the Jaturamitr period sync: {err}");
+                        tokio::time::sleep(RETRY_DELAY).await;
+                        break;
+                    }
+                }
+            }
+        }
+    }
+}
@@ -4,46 +4,116 @@
 use actix_cors::Cors;
 use actix_web::{
     App, HttpServer,
+    body::MessageBody,
+    dev::{ServiceFactory, ServiceRequest, ServiceResponse},
     http::header,
-    middleware::{Logger, NormalizePath},
+    middleware::{Logger, NormalizePath, from_fn},
     web::{Data, JsonConfig},
 };
-use anyhow::{Context as _, Result as AnyhowResult};
+use anyhow::{Context as _, Result as AnyhowResult, bail};
+use attendance_feed::AttendanceFeed;
+use background::BackgroundTasks;
 use dotenvy::dotenv;
-use mysk_lib::{cache::GlobalCache, common::config::Config, prelude::*};
-use parking_lot::Mutex;
-use sqlx::postgres::{PgConnectOptions, PgSslMode};
-use sqlx::{PgPool, postgres::PgPoolOptions};
-use std::{collections::HashSet, env, sync::Arc};
-use tracing_subscriber::{layer::SubscriberExt as _, util::SubscriberInitExt as _};
+use jaturamitr_sync::JaturamitrSync;
+use middlewares::error_log::ErrorLogSink;
+use mysk_lib::{
+    cache::GlobalCache,
+    common::config::{Config, ReloadableConfig},
+    migrations,
+    prelude::*,
+};
+use parking_lot::{Mutex, RwLock};
+use signals::SignalHandler;
+use sqlx::{
+    PgPool,
+    postgres::{PgConnectOptions, PgPoolOptions},
+};
+use std::{collections::HashSet, env, sync::Arc, time::Duration};
+use tracing_subscriber::{layer::SubscriberExt as _, reload, util::SubscriberInitExt as _};
+use trade_offer_sweeper::TradeOfferSweeper;
+use webhook_dispatcher::WebhookDispatcher;
 
+mod attendance_feed;
+mod background;
 mod extractors;
+mod graphql;
+mod jaturamitr_sync;
+mod metrics;
+mod middlewares;
 mod routes;
+mod signals;
+#[cfg(test)]
+mod tests;
+mod trade_offer_sweeper;
+mod webhook_dispatcher;
 
 /// The shared state of the application.
 pub struct AppState {
     db: PgPool,
     oauth_states: Mutex<HashSet<String>>,
     env: Config,
+    /// The part of the configuration reloaded on SIGHUP.
+    reloadable_config: Arc<RwLock<ReloadableConfig>>,
     cache: Arc<GlobalCache>,
+    error_log: ErrorLogSink,
+    attendance_feed: AttendanceFeed,
+}
+
+impl AppState {
+    /// Copies the state to use another database pool. OAuth states aren't shared with the copy.
+    fn with_db(&self, db: PgPool) -> Self {
+        Self {
+            db,
+            oauth_states: Mutex::new(HashSet::new()),
+            env: self.env.clone(),
+            reloadable_config: Arc::clone(&self.reloadable_config),
+            cache: Arc::clone(&self.cache),
+            error_log: self.error_log.clone(),
+            attendance_feed: self.attendance_feed.clone(),
+        }
+    }
+}
+
+/// One-off commands run instead of the server.
+#[derive(Clone, Copy)]
+enum Command {
+    /// Applies every pending migration.
+    Migrate,
+    /// Marks a database set up before migrations existed as migrated.
+    Baseline,
 }
 
 #[actix_web::main]
 async fn main() -> AnyhowResult<()> {
-    tracing_subscriber::registry()
-        .with(tracing_subscriber::EnvFilter::builder().parse(
-            #[cfg(debug_assertions)]
-            "mysk_data_api=trace,mysk_lib=trace,actix_web=info,sqlx=trace",
-            #[cfg(not(debug_assertions))]
-            "mysk_data_api=info,mysk_lib=info,actix_web=warn,sqlx=warn",
-        )?)
-        .with(tracing_subscriber::fmt::layer())
-        .try_init()?;
+    let command = match env::args().nth(1).as_deref() {
+        None => None,
+        Some("--migrate") => Some(Command::Migrate),
+        Some("--baseline") => Some(Command::Baseline),
+        Some(arg) => bail!("Unknown argument `{arg}`, expected `--migrate` or `--baseline`"),
+    };
 
     dotenv().ok();
-    let config = Config::try_from_env()?;
+    let config = Config::load()?;
     let Config { host, port, .. } = config;
 
+    let (log_filter, log_filter_handle) = reload::Layer::new(
+        tracing_subscriber::EnvFilter::builder()
+            .parse(&config.log_filter)
+            .context("LOG_FILTER must be valid tracing filter directives")?,
+    );
+    let registry = tracing_subscriber::registry().with(log_filter);
+    #[cfg(debug_assertions)]
+    registry.with(tracing_subscriber::fmt::layer()).try_init()?;
+    // Structured logs for log aggregation in production
+    #[cfg(not(debug_assertions))]
+    registry
+        .with(
+            tracing_subscriber::fmt::layer()
+                .json()
+                .with_span_list(false),
+        )
+        .try_init()?;
+
     tracing::info!(
         "MySK API v{} on {} [{} {}]",
         env!("CARGO_PKG_VERSION"),
@@ -55,19 +125,39 @@ async fn main() -> AnyhowResult<()> {
     tracing::warn!("Running on DEBUG, not optimised for production");
 
     let pool = PgPoolOptions::new()
-        .max_connections(16)
+        .max_connections(config.database_max_connections)
         .test_before_acquire(false)
         .connect_with(
             config
                 .database_url
                 .parse::<PgConnectOptions>()
                 .context("Failed to parse DATABASE_URL")?
-                .ssl_mode(PgSslMode::Require),
+                .ssl_mode(config.database_ssl_mode),
         )
         .await
         .context("Failed to connect to the database")?;
 
     tracing::info!("Established connection to the database successfully");
+
+    match command {
+        Some(Command::Migrate) => {
+            migrations::run(&pool).await?;
+            tracing::info!("Applied all pending migrations");
+        }
+        Some(Command::Baseline) => {
+            migrations::baseline(&pool).await?;
+            tracing::info!("Marked the initial migration as applied");
+        }
+        None => (),
+    }
+    if command.is_some() {
+        pool.close().await;
+
+        return Ok(());
+    }
+    migrations::check_schema_version(&pool)
+        .await
+        .context("The database schema is incompatible with this build")?;
     tracing::info!("Running on http://{host}:{port}");
     tracing::debug!("You can use this link to login with Google via OAuth:");
     tracing::debug!("{}/auth/oauth/init", config.root_uri);
@@ -75,41 +165,102 @@ async fn main() -> AnyhowResult<()> {
     let app_cache = GlobalCache::new().populate_cache(&pool).await?;
     tracing::debug!("Built global cache successfully");
 
+    let reloadable_config = Arc::new(RwLock::new(config.reloadable()));
+    let signal_handler = SignalHandler::new(Arc::clone(&reloadable_config), log_filter_handle)
+        .context("Failed to register signal handlers")?;
+    let shutdown_timeout = config.shutdown_timeout;
+
+    let mut background_tasks = BackgroundTasks::new();
     let app_state = Data::new(AppState {
         db: pool.clone(),
         oauth_states: Mutex::new(HashSet::new()),
         env: config,
+        reloadable_config,
         cache: Arc::clone(&app_cache),
+        error_log: ErrorLogSink::spawn(pool.clone(), &mut background_tasks),
+        attendance_feed: AttendanceFeed::spawn(pool.clone(), &mut background_tasks),
     });
+    WebhookDispatcher::spawn(pool.clone(), &mut background_tasks);
+    TradeOfferSweeper::spawn(pool.clone(), &mut background_tasks);
+    JaturamitrSync::spawn(pool.clone(), Arc::clone(&app_cache), &mut background_tasks);
+
+    let server = HttpServer::new(move || app(app_state.clone()))
+        .bind((host, port))?
+        .shutdown_timeout(shutdown_timeout)
+        .disable_signals()
+        .run();
+
+    tokio::spawn(signal_handler.run(server.handle()));
+    server.await?;
 
-    HttpServer::new(move || {
-        let cors_middleware = Cors::default()
-            .allowed_origin("http://localhost:3000")
-            .allowed_origin("http://localhost:8000")
-            .allowed_origin("https://mysk.school")
-            .allowed_methods(vec!["GET", "POST", "PATCH", "DELETE", "PUT"])
-            .allowed_headers(vec![
-                header::CONTENT_TYPE,
-                header::AUTHORIZATION,
-                header::ACCESS_CONTROL_ALLOW_ORIGIN,
-                header::ACCEPT,
-                header::HeaderName::from_lowercase(b"x-api-key").unwrap(),
-            ])
-            .supports_credentials();
-
-        App::new()
-            .app_data(app_state.clone())
-            .app_data(JsonConfig::default().error_handler(|err, req| {
-                Error::InvalidRequest(format!("{err}"), req.path().into()).into()
-            }))
-            .wrap(Logger::default())
-            .wrap(NormalizePath::trim())
-            .wrap(cors_middleware)
-            .configure(routes::config)
-    })
-    .bind((host, port))?
-    .run()
-    .await?;
+    // The tasks may still be using the database, e.g. to flush error logs of the last requests
+    tracing::info!("Stopping the background tasks");
+    background_tasks
+        .stop(Duration::from_secs(shutdown_timeout))
+        .await;
+
+    tracing::info!("Closing the database connections");
+    pool.close().await;
 
     Ok(())
 }
+
+/// Builds the application with every route and the middlewares they depend on. Batches run their
+/// operations through another instance of it.
+fn app(
+    app_state: Data<AppState>,
+) -> App<
+    impl ServiceFactory<
+        ServiceRequest,
+        Config = (),
+        Response = ServiceResponse<impl MessageBody>,
+        Error = actix_web::Error,
+        InitError = (),
+    >,
+> {
+    // Origins are looked up on every request, so they can be reloaded
+    let reloadable_config = Arc::clone(&app_state.reloadable_config);
+    let cors_middleware = Cors::default()
+        .allowed_origin_fn(move |origin, _| {
+            reloadable_config
+                .read()
+                .cors_allowed_origins
+                .iter()
+                .any(|allowed_origin| allowed_origin.as_bytes() == origin.as_bytes())
+        })
+        .allowed_methods(vec!["GET", "POST", "PATCH", "DELETE", "PUT"])
+        .allowed_headers(vec![
+            header::CONTENT_TYPE,
+            header::AUTHORIZATION,
+            header::ACCESS_CONTROL_ALLOW_ORIGIN,
+            header::ACCEPT,
+            header::HeaderName::from_lowercase(b"x-api-key").unwrap(),
+            middlewares::request_id::X_REQUEST_ID,
+            middlewares::idempotency::IDEMPOTENCY_KEY,
+        ])
+        .expose_headers(vec![
+            middlewares::request_id::X_REQUEST_ID,
+            middlewares::idempotency::IDEMPOTENT_REPLAYED,
+        ])
+        .supports_credentials();
+
+    App::new()
+        .app_data(json_config(app_state.env.json_limit))
+        .app_data(app_state)
+        .wrap(from_fn(middlewares::idempotency::idempotency))
+        .wrap(from_fn(middlewares::language::language))
+        .wrap(Logger::default())
+        .wrap(from_fn(middlewares::error_log::log_errors))
+        .wrap(from_fn(middlewares::metrics::record_metrics))
+        .wrap(from_fn(middlewares::request_id::request_id))
+        .wrap(NormalizePath::trim())
+        .wrap(cors_middleware)
+        .configure(routes::config)
+}
+
+/// Rejects JSON bodies over `limit` bytes, and reports malformed ones as [`Error::InvalidRequest`].
+fn json_config(limit: usize) -> JsonConfig {
+    JsonConfig::default()
+        .limit(limit)
+        .error_handler(|err, req| Error::InvalidRequest(format!("{err}"), req.path().into()).into())
+}
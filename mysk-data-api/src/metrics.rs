@@ -0,0 +1,161 @@
+//! Prometheus metrics of the application, exposed at `/metrics`.
+
+use prometheus::{
+    Encoder as _, HistogramOpts, HistogramVec, IntCounter, IntCounterVec, IntGauge, Opts, Registry,
+    TextEncoder,
+};
+use sqlx::PgPool;
+use std::sync::LazyLock;
+
+pub static METRICS: LazyLock<Metrics> = LazyLock::new(Metrics::new);
+
+pub struct Metrics {
+    registry: Registry,
+    /// Labelled by `method`, `route` and `status`.
+    pub http_request_duration: HistogramVec,
+    /// Labelled by `error_type`.
+    pub errors: IntCounterVec,
+    pub error_logs_dropped: IntCounter,
+    pub db_pool_size: IntGauge,
+    pub db_pool_idle: IntGauge,
+    /// Labelled by `lock`.
+    pub advisory_lock_wait: HistogramVec,
+    /// Labelled by `action`, either `enroll`, `modify`, `withdraw`, `waitlist`,
+    /// `allocate` or `trade_cycle`.
+    pub elective_enrollments: IntCounterVec,
+    /// Labelled by `status`, either `pending`, `approved` or `declined`, or else the reason an offer
+    /// was declined without a student declining it: `cancelled`, `expired` or `enrollment_closed`.
+    pub elective_trade_offers: IntCounterVec,
+    /// Labelled by `phase`, either `start` or `end`.
+    pub cheer_practice_checks: IntCounterVec,
+    /// Labelled by `outcome`, either `delivered`, `failed` or `abandoned`.
+    pub webhook_deliveries: IntCounterVec,
+}
+
+impl Metrics {
+    #[allow(clippy::too_many_lines)]
+    fn new() -> Self {
+        let registry = Registry::new_custom(Some("mysk".to_string()), None)
+            .expect("Irrecoverable error, invalid metrics namespace");
+
+        let http_request_duration = HistogramVec::new(
+            HistogramOpts::new(
+                "http_request_duration_seconds",
+                "Time taken to handle HTTP requests",
+            ),
+            &["method", "route", "status"],
+        )
+        .unwrap();
+        let errors = IntCounterVec::new(
+            Opts::new("errors_total", "Errors returned to clients"),
+            &["error_type"],
+        )
+        .unwrap();
+        let error_logs_dropped = IntCounter::new(
+            "error_logs_dropped_total",
+            "Error logs dropped instead of being inserted",
+        )
+        .unwrap();
+        let db_pool_size =
+            IntGauge::new("db_pool_size", "Connections currently held by the pool").unwrap();
+        let db_pool_idle = IntGauge::new(
+            "db_pool_idle",
+            "Idle connections currently held by the pool",
+        )
+        .unwrap();
+        let advisory_lock_wait = HistogramVec::new(
+            HistogramOpts::new(
+                "advisory_lock_wait_seconds",
+                "Time spent waiting to acquire Postgres advisory locks",
+            )
+            .buckets(vec![
+                0.001, 0.005, 0.01, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0,
+            ]),
+            &["lock"],
+        )
+        .unwrap();
+        let elective_enrollments = IntCounterVec::new(
+            Opts::new(
+                "elective_enrollments_total",
+                "Successful elective enrollments and modifications",
+            ),
+            &["action"],
+        )
+        .unwrap();
+        let elective_trade_offers = IntCounterVec::new(
+            Opts::new(
+                "elective_trade_offers_total",
+                "Elective trade offers created, approved, declined, cancelled or expired",
+            ),
+            &["status"],
+        )
+        .unwrap();
+        let cheer_practice_checks = IntCounterVec::new(
+            Opts::new(
+                "cheer_practice_checks_total",
+                "Cheer practice attendances taken",
+            ),
+            &["phase"],
+        )
+        .unwrap();
+        let webhook_deliveries = IntCounterVec::new(
+            Opts::new(
+                "webhook_deliveries_total",
+                "Attempts at delivering webhook events",
+            ),
+            &["outcome"],
+        )
+        .unwrap();
+
+        registry
+            .register(Box::new(http_request_duration.clone()))
+            .unwrap();
+        registry.register(Box::new(errors.clone())).unwrap();
+        registry
+            .register(Box::new(error_logs_dropped.clone()))
+            .unwrap();
+        registry.register(Box::new(db_pool_size.clone())).unwrap();
+        registry.register(Box::new(db_pool_idle.clone())).unwrap();
+        registry
+            .register(Box::new(advisory_lock_wait.clone()))
+            .unwrap();
+        registry
+            .register(Box::new(elective_enrollments.clone()))
+            .unwrap();
+        registry
+            .register(Box::new(elective_trade_offers.clone()))
+            .unwrap();
+        registry
+            .register(Box::new(cheer_practice_checks.clone()))
+            .unwrap();
+        registry
+            .register(Box::new(webhook_deliveries.clone()))
+            .unwrap();
+
+        Self {
+            registry,
+            http_request_duration,
+            errors,
+            error_logs_dropped,
+            db_pool_size,
+            db_pool_idle,
+            advisory_lock_wait,
+            elective_enrollments,
+            elective_trade_offers,
+            cheer_practice_checks,
+            webhook_deliveries,
+        }
+    }
+
+    /// Encodes every metric in the Prometheus text format, sampling the pool statistics first.
+    pub fn encode(&self, pool: &PgPool) -> prometheus::Result<String> {
+        self.db_pool_size.set(i64::from(pool.size()));
+        self.db_pool_idle
+            .set(i64::try_from(pool.num_idle()).unwrap_or(i64::MAX));
+
+        let mut buffer = Vec::new();
+        TextEncoder::new().encode(&self.registry.gather(), &mut buffer)?;
+
+        Ok(String::from_utf8(buffer).unwrap_or_default())
+    }
+}
@@ -0,0 +1,100 @@
+use crate::{
+    AppState,
+    background::{BackgroundTasks, StopSignal},
+    extractors::{api_key::ApiKeyId, logged_in::UserId},
+    metrics::METRICS,
+};
+use actix_web::{
+    HttpMessage as _,
+    body::MessageBody,
+    dev::{ServiceRequest, ServiceResponse},
+    middleware::Next,
+    web::Data,
+};
+use mysk_lib::{models::error_log::db::DbErrorLog, prelude::*};
+use sqlx::PgPool;
+use tokio::sync::mpsc::{self, Receiver, Sender, error::TrySendError};
+
+/// The number of error logs which can be waiting to be inserted before new ones are dropped.
+const CHANNEL_CAPACITY: usize = 1024;
+/// The maximum number of error logs inserted by one statement.
+const BATCH_SIZE: usize = 64;
+
+/// A handle to the background task inserting error logs into `api_logging.error_logs`. Logging
+/// never waits for the database; records are dropped instead when the task falls behind. Once
+/// stopped, the task inserts the records still waiting before it returns.
+#[derive(Clone)]
+pub struct ErrorLogSink {
+    sender: Sender<DbErrorLog>,
+}
+
+impl ErrorLogSink {
+    pub fn spawn(pool: PgPool, tasks: &mut BackgroundTasks) -> Self {
+        let (sender, receiver) = mpsc::channel(CHANNEL_CAPACITY);
+        tasks.spawn(|stop| Self::run(pool, receiver, stop));
+
+        Self { sender }
+    }
+
+    pub fn log(&self, error_log: DbErrorLog) {
+        match self.sender.try_send(error_log) {
+            Ok(()) => (),
+            Err(TrySendError::Full(error_log)) => {
+                METRICS.error_logs_dropped.inc();
+                tracing::warn!(request_id = %error_log.request_id, "Dropped error log");
+            }
+            Err(TrySendError::Closed(_)) => {
+                tracing::error!("Error log sink has stopped");
+            }
+        }
+    }
+
+    async fn run(pool: PgPool, mut receiver: Receiver<DbErrorLog>, mut stop: StopSignal) {
+        let mut batch = Vec::with_capacity(BATCH_SIZE);
+        loop {
+            tokio::select! {
+                count = receiver.recv_many(&mut batch, BATCH_SIZE) => {
+                    // Only once the channel is closed and drained
+                    if count == 0 {
+                        break;
+                    }
+                    if let Err(err) = DbErrorLog::insert_many(&pool, &batch).await {
+                        METRICS.error_logs_dropped.inc_by(batch.len() as u64);
+                        tracing::error!("Failed to insert {} error logs: {err}", batch.len());
+                    }
+                    batch.clear();
+                }
+                // Stop accepting records, but keep inserting those already waiting
+                () = stop.stopped(), if !receiver.is_closed() => receiver.close(),
+            }
+        }
+    }
+}
+
+/// Captures every [`Error`] returned while handling a request and hands it to the
+/// [`ErrorLogSink`].
+pub async fn log_errors(
+    req: ServiceRequest,
+    next: Next<impl MessageBody>,
+) -> Result<ServiceResponse<impl MessageBody>, actix_web::Error> {
+    let res = next.call(req).await?;
+
+    if let Some(err) = res
+        .response()
+        .error()
+        .and_then(|err| err.as_error::<Error>())
+        && let Some(data) = res.request().app_data::<Data<AppState>>()
+    {
+        let req = res.request();
+        let extensions = req.extensions();
+        data.error_log.log(DbErrorLog::new(
+            err.into(),
+            req.method().to_string(),
+            req.path().to_string(),
+            extensions.get::<ApiKeyId>().map(|ApiKeyId(id)| *id),
+            extensions.get::<UserId>().map(|UserId(id)| *id),
+        ));
+    }
+
+    Ok(res)
+}
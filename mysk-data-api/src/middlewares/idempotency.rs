@@ -0,0 +1,258 @@
+use crate::AppState;
+use actix_web::{
+    HttpResponse,
+    body::{self, BoxBody, MessageBody},
+    dev::{ServiceRequest, ServiceResponse},
+    http::{
+        Method, StatusCode,
+        header::{self, HeaderName},
+    },
+    middleware::Next,
+    web::{Bytes, Data},
+};
+use chrono::{TimeDelta, Utc};
+use jsonwebtoken::{DecodingKey, Validation, decode};
+use mysk_lib::{auth::oauth::TokenClaims, prelude::*};
+use sha2::{Digest as _, Sha256};
+use sqlx::{PgPool, query};
+use utoipa::IntoParams;
+use uuid::Uuid;
+
+pub const IDEMPOTENCY_KEY: HeaderName = HeaderName::from_static("idempotency-key");
+pub const IDEMPOTENT_REPLAYED: HeaderName = HeaderName::from_static("idempotent-replayed");
+
+/// How long the response to a key is kept for its retries.
+const KEY_TTL: TimeDelta = TimeDelta::hours(24);
+/// How long a request may go without a response before its key can be claimed again, in case the
+/// instance handling it went down.
+const ABANDONED_AFTER: TimeDelta = TimeDelta::minutes(1);
+const MAX_KEY_LENGTH: usize = 255;
+
+/// Documents the `Idempotency-Key` header on the routes students are likely to retry.
+#[allow(dead_code)]
+#[derive(IntoParams)]
+#[into_params(parameter_in = Header)]
+pub struct IdempotencyKeyHeader {
+    /// A unique key for the request, such as a UUID. Retrying with the same key within 24 hours
+    /// replays the first response with `Idempotent-Replayed: true` instead of handling the request
+    /// again. Reusing a key for a different request is a conflict.
+    #[param(rename = "Idempotency-Key")]
+    idempotency_key: Option<String>,
+}
+
+enum Claim {
+    /// The key is new, so the request should be handled.
+    New,
+    /// The key was used for the same request before, which got this response.
+    Replay(HttpResponse),
+}
+
+/// Stores the first response to each `POST` or `PUT` request sent with an `Idempotency-Key` by a
+/// logged in user, and replays it to the requests retrying it. Server errors aren't stored, so
+/// those requests can be retried for real.
+pub async fn idempotency(
+    mut req: ServiceRequest,
+    next: Next<impl MessageBody + 'static>,
+) -> Result<ServiceResponse<BoxBody>, actix_web::Error> {
+    if !matches!(*req.method(), Method::POST | Method::PUT) {
+        return Ok(next.call(req).await?.map_into_boxed_body());
+    }
+    let Some(key) = req.headers().get(&IDEMPOTENCY_KEY) else {
+        return Ok(next.call(req).await?.map_into_boxed_body());
+    };
+    let source = req.path().to_string();
+    let key = match key.to_str() {
+        Ok(key) if !key.is_empty() && key.len() <= MAX_KEY_LENGTH => key.to_string(),
+        _ => {
+            return Ok(req
+                .error_response(Error::InvalidRequest(
+                    format!(
+                        "Idempotency-Key must be between 1 and {MAX_KEY_LENGTH} visible ASCII \
+                        characters"
+                    ),
+                    source,
+                ))
+                .map_into_boxed_body());
+        }
+    };
+    let data = req
+        .app_data::<Data<AppState>>()
+        .expect("Irrecoverable error, AppState is None")
+        .clone();
+    // Requests without a valid token are rejected by the route itself
+    let Some(user_id) = token_user_id(&req, &data.env.token_secret) else {
+        return Ok(next.call(req).await?.map_into_boxed_body());
+    };
+
+    let body = req.extract::<Bytes>().await?;
+    let request_hash = format!(
+        "{:x}",
+        Sha256::new()
+            .chain_update(req.method().as_str())
+            .chain_update(b" ")
+            .chain_update(req.uri().to_string())
+            .chain_update(b"\n")
+            .chain_update(&body)
+            .finalize()
+    );
+    req.set_payload(body.into());
+
+    match claim(&data.db, user_id, &key, &request_hash, &source).await {
+        Ok(Claim::New) => (),
+        Ok(Claim::Replay(res)) => return Ok(req.into_response(res)),
+        Err(err) => return Ok(req.error_response(err).map_into_boxed_body()),
+    }
+
+    let res = match next.call(req).await {
+        Ok(res) if !res.status().is_server_error() => res,
+        res => {
+            if let Err(err) = release(&data.db, user_id, &key).await {
+                tracing::error!("Failed to release an idempotency key: {err}");
+            }
+
+            return Ok(res?.map_into_boxed_body());
+        }
+    };
+
+    let status = res.status();
+    let content_type = res
+        .headers()
+        .get(header::CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .map(ToString::to_string);
+    let (req, res) = res.into_parts();
+    let (res, body) = res.into_parts();
+    let body = body::to_bytes(body)
+        .await
+        .map_err(|err| Error::InternalServerError(err.into().to_string(), source))?;
+
+    if let Err(err) = query!(
+        "\
+        UPDATE idempotency_keys SET status_code = $3, content_type = $4, response_body = $5 \
+        WHERE user_id = $1 AND key = $2\
+        ",
+        user_id,
+        key,
+        i32::from(status.as_u16()),
+        content_type,
+        &body[..],
+    )
+    .execute(&data.db)
+    .await
+    {
+        tracing::error!("Failed to store the response to an idempotency key: {err}");
+    }
+
+    Ok(ServiceResponse::new(
+        req,
+        res.set_body(body).map_into_boxed_body(),
+    ))
+}
+
+/// The user the request's bearer token was issued to, if it is valid.
+fn token_user_id(req: &ServiceRequest, token_secret: &str) -> Option<Uuid> {
+    let token = req
+        .headers()
+        .get(header::AUTHORIZATION)?
+        .to_str()
+        .ok()?
+        .strip_prefix("Bearer ")?;
+
+    decode::<TokenClaims>(
+        token,
+        &DecodingKey::from_secret(token_secret.as_bytes()),
+        &Validation::default(),
+    )
+    .ok()
+    .map(|token| token.claims.sub)
+}
+
+async fn claim(
+    pool: &PgPool,
+    user_id: Uuid,
+    key: &str,
+    request_hash: &str,
+    source: &str,
+) -> Result<Claim> {
+    let now = Utc::now();
+    query!(
+        "DELETE FROM idempotency_keys WHERE user_id = $1 AND created_at < $2",
+        user_id,
+        now - KEY_TTL,
+    )
+    .execute(pool)
+    .await?;
+
+    let claimed = query!(
+        "\
+        INSERT INTO idempotency_keys (user_id, key, request_hash) VALUES ($1, $2, $3) \
+        ON CONFLICT (user_id, key) DO UPDATE \
+        SET created_at = now(), request_hash = EXCLUDED.request_hash \
+        WHERE idempotency_keys.status_code IS NULL AND idempotency_keys.created_at < $4 \
+        RETURNING user_id\
+        ",
+        user_id,
+        key,
+        request_hash,
+        now - ABANDONED_AFTER,
+    )
+    .fetch_optional(pool)
+    .await?
+    .is_some();
+    if claimed {
+        return Ok(Claim::New);
+    }
+
+    let stored = query!(
+        "\
+        SELECT request_hash, status_code, content_type, response_body FROM idempotency_keys \
+        WHERE user_id = $1 AND key = $2\
+        ",
+        user_id,
+        key,
+    )
+    .fetch_optional(pool)
+    .await?;
+
+    match stored {
+        Some(stored) if stored.request_hash != request_hash => Err(Error::BrokenRule(
+            ErrorCode::IdempotencyKeyReused,
+            source.to_string(),
+        )),
+        Some(stored) if stored.status_code.is_some() => {
+            let mut res = HttpResponse::build(
+                stored
+                    .status_code
+                    .and_then(|status_code| u16::try_from(status_code).ok())
+                    .and_then(|status_code| StatusCode::from_u16(status_code).ok())
+                    .unwrap_or(StatusCode::OK),
+            );
+            if let Some(content_type) = stored.content_type {
+                res.content_type(content_type);
+            }
+
+            Ok(Claim::Replay(
+                res.insert_header((IDEMPOTENT_REPLAYED, "true"))
+                    .body(stored.response_body.unwrap_or_default()),
+            ))
+        }
+        // Also when the key was just released by a request which failed
+        _ => Err(Error::BrokenRule(
+            ErrorCode::IdempotencyKeyInProgress,
+            source.to_string(),
+        )),
+    }
+}
+
+/// Forgets the key, so the request can be retried.
+async fn release(pool: &PgPool, user_id: Uuid, key: &str) -> Result<()> {
+    query!(
+        "DELETE FROM idempotency_keys WHERE user_id = $1 AND key = $2",
+        user_id,
+        key,
+    )
+    .execute(pool)
+    .await?;
+
+    Ok(())
+}
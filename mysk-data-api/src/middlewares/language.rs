@@ -0,0 +1,57 @@
+use actix_web::{
+    body::MessageBody,
+    dev::{ServiceRequest, ServiceResponse},
+    http::header::{self, HeaderValue},
+    middleware::Next,
+    web::Query,
+};
+use mysk_lib::common::language::{self, Language};
+use serde::Deserialize;
+
+#[derive(Deserialize)]
+struct LanguageQuery {
+    lang: Option<String>,
+}
+
+/// Localizes the response to the language asked for by the `lang` query parameter, or with
+/// `lang=auto`, to the one negotiated from the `Accept-Language` header. Unsupported languages are
+/// ignored, and responses to requests without `lang` are left as they are.
+pub async fn language(
+    req: ServiceRequest,
+    next: Next<impl MessageBody>,
+) -> Result<ServiceResponse<impl MessageBody>, actix_web::Error> {
+    let lang = Query::<LanguageQuery>::from_query(req.query_string())
+        .ok()
+        .and_then(|query| query.into_inner().lang);
+    let negotiated = lang.as_deref() == Some("auto");
+    let language = if negotiated {
+        req.headers()
+            .get(header::ACCEPT_LANGUAGE)
+            .and_then(|value| value.to_str().ok())
+            .and_then(Language::negotiate)
+    } else {
+        lang.and_then(|lang| lang.parse::<Language>().ok())
+    };
+
+    let mut res = match language {
+        Some(language) => {
+            let mut res = language::scope(language, next.call(req)).await?;
+            res.headers_mut().insert(
+                header::CONTENT_LANGUAGE,
+                HeaderValue::from_static(match language {
+                    Language::English => "en-US",
+                    Language::Thai => "th",
+                }),
+            );
+            res
+        }
+        None => next.call(req).await?,
+    };
+    if negotiated {
+        // Appended so that other `Vary` headers, e.g. CORS's, are kept
+        res.headers_mut()
+            .append(header::VARY, HeaderValue::from_static("accept-language"));
+    }
+
+    Ok(res)
+}
@@ -0,0 +1,40 @@
+use crate::metrics::METRICS;
+use actix_web::{
+    body::MessageBody,
+    dev::{ServiceRequest, ServiceResponse},
+    middleware::Next,
+};
+use mysk_lib::{common::response::ErrorType, prelude::*};
+use std::time::Instant;
+
+/// Records the duration of each request and the errors returned by the request, labelled by the
+/// matched route so that arbitrary paths can't blow up the number of series.
+pub async fn record_metrics(
+    req: ServiceRequest,
+    next: Next<impl MessageBody>,
+) -> Result<ServiceResponse<impl MessageBody>, actix_web::Error> {
+    let started_at = Instant::now();
+    let method = req.method().to_string();
+    let route = req
+        .match_pattern()
+        .unwrap_or_else(|| "unmatched".to_string());
+
+    let res = next.call(req).await?;
+
+    METRICS
+        .http_request_duration
+        .with_label_values(&[method.as_str(), route.as_str(), res.status().as_str()])
+        .observe(started_at.elapsed().as_secs_f64());
+    if let Some(err) = res
+        .response()
+        .error()
+        .and_then(|err| err.as_error::<Error>())
+    {
+        METRICS
+            .errors
+            .with_label_values(&[ErrorType::from(err).error_type.as_str()])
+            .inc();
+    }
+
+    Ok(res)
+}
@@ -0,0 +1,7 @@
+//! Custom middlewares wrapping every request.
+
+pub mod error_log;
+pub mod idempotency;
+pub mod language;
+pub mod metrics;
+pub mod request_id;
@@ -0,0 +1,58 @@
+use actix_web::{
+    body::MessageBody,
+    dev::{ServiceRequest, ServiceResponse},
+    http::header::{HeaderName, HeaderValue},
+    middleware::Next,
+};
+use mysk_lib::common::request_id;
+use tracing::{Instrument as _, field::Empty};
+use uuid::Uuid;
+
+pub const X_REQUEST_ID: HeaderName = HeaderName::from_static("x-request-id");
+
+/// Assigns an ID to each request, or propagates the one given in `X-Request-Id`, and handles the
+/// request inside a span carrying that ID. The `user_id` and `api_key_id` fields of the span are
+/// recorded by the extractors once known.
+pub async fn request_id(
+    req: ServiceRequest,
+    next: Next<impl MessageBody>,
+) -> Result<ServiceResponse<impl MessageBody>, actix_web::Error> {
+    let request_id = req
+        .headers()
+        .get(&X_REQUEST_ID)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| Uuid::try_parse(value).ok())
+        .unwrap_or_else(Uuid::new_v4);
+    let route = req
+        .match_pattern()
+        .unwrap_or_else(|| req.path().to_string());
+    let span = tracing::info_span!(
+        "request",
+        %request_id,
+        method = %req.method(),
+        route,
+        user_id = Empty,
+        api_key_id = Empty,
+    );
+
+    let mut res = request_id::scope(request_id, next.call(req))
+        .instrument(span.clone())
+        .await?;
+
+    span.in_scope(|| {
+        let status = res.status();
+        match res.response().error() {
+            Some(err) if status.is_server_error() => {
+                tracing::error!(status = status.as_u16(), "{err}");
+            }
+            Some(err) => tracing::debug!(status = status.as_u16(), "{err}"),
+            None => tracing::debug!(status = status.as_u16(), "Request handled"),
+        }
+    });
+
+    if let Ok(value) = HeaderValue::from_str(&request_id.to_string()) {
+        res.headers_mut().insert(X_REQUEST_ID, value);
+    }
+
+    Ok(res)
+}
@@ -5,17 +5,23 @@ use actix_web::{
 };
 use mysk_lib::{auth::key::ApiKey, common::response::ResponseType, prelude::*};
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, ToSchema)]
 struct CreateApiKeyRequest {
     expire_days: Option<i64>,
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, ToSchema)]
 struct CreateApiKeyResponse {
     api_key: String,
 }
 
+#[utoipa::path(
+    tag = "Auth",
+    responses((status = OK, description = "The new API key", body = ResponseType<CreateApiKeyResponse>)),
+    security(("token" = [])),
+)]
 #[post("/keys")]
 pub async fn create_api_key(
     data: Data<AppState>,
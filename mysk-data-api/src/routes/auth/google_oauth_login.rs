@@ -17,7 +17,13 @@ use mysk_lib::{
     prelude::*,
 };
 use serde::Deserialize;
+use utoipa::IntoParams;
 
+#[utoipa::path(
+    tag = "Auth",
+    responses((status = TEMPORARY_REDIRECT, description = "Redirects to Google's consent screen")),
+    security(()),
+)]
 #[get("/oauth/init")]
 pub async fn oauth_initiator(data: Data<AppState>) -> Result<impl Responder> {
     let (redirect_url, state) = generate_oauth_init_url(
@@ -34,12 +40,19 @@ pub async fn oauth_initiator(data: Data<AppState>) -> Result<impl Responder> {
     Ok(Redirect::to(redirect_url))
 }
 
-#[derive(Deserialize)]
+#[derive(Deserialize, IntoParams)]
 struct GoogleOAuthCodeRequest {
     code: String,
     state: String,
 }
 
+#[utoipa::path(
+    tag = "Auth",
+    responses(
+        (status = OK, description = "A session token, also set as the `token` cookie", body = ResponseType<GoogleTokenResponse>),
+    ),
+    security(()),
+)]
 #[allow(clippy::cast_possible_wrap)]
 #[get("/oauth/google")]
 pub async fn google_oauth_handler(
@@ -101,6 +114,7 @@ pub async fn google_oauth_handler(
             ) => Some(id),
             _ => None,
         },
+        imp: None,
         exp,
         iat,
     };
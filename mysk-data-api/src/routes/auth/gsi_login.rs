@@ -15,12 +15,13 @@ use mysk_lib::{
     prelude::*,
 };
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, ToSchema)]
 pub struct OAuthRequest {
     pub credential: String,
 }
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, ToSchema)]
 pub struct GoogleTokenResponse<'a> {
     pub access_token: &'a str,
     pub expires_in: u64,
@@ -29,6 +30,13 @@ pub struct GoogleTokenResponse<'a> {
     pub id_token: String,
 }
 
+#[utoipa::path(
+    tag = "Auth",
+    responses(
+        (status = OK, description = "A session token, also set as the `token` cookie", body = ResponseType<GoogleTokenResponse>),
+    ),
+    security(()),
+)]
 #[allow(clippy::cast_possible_wrap)]
 #[post("/oauth/gsi")]
 pub async fn gsi_handler(
@@ -71,6 +79,7 @@ pub async fn gsi_handler(
             }
             _ => None,
         },
+        imp: None,
         exp,
         iat,
     };
@@ -1,10 +1,21 @@
 use actix_web::web;
+use utoipa::OpenApi;
 
 mod create_api_key;
 mod google_oauth_login;
 mod gsi_login;
 mod user;
 
+#[derive(OpenApi)]
+#[openapi(paths(
+    create_api_key::create_api_key,
+    google_oauth_login::google_oauth_handler,
+    google_oauth_login::oauth_initiator,
+    gsi_login::gsi_handler,
+    user::get_user,
+))]
+pub struct ApiDoc;
+
 pub fn config(cfg: &mut web::ServiceConfig) {
     cfg.service(create_api_key::create_api_key)
         .service(google_oauth_login::google_oauth_handler)
@@ -1,7 +1,13 @@
 use crate::extractors::{api_key::ApiKeyHeader, logged_in::LoggedIn};
 use actix_web::{HttpResponse, Responder, get};
-use mysk_lib::{common::response::ResponseType, prelude::*};
+use mysk_lib::{common::response::ResponseType, models::user::User, prelude::*};
 
+#[utoipa::path(
+    tag = "Auth",
+    responses(
+        (status = OK, description = "The logged in user", body = ResponseType<User>),
+    ),
+)]
 #[get("/user")]
 pub async fn get_user(_: ApiKeyHeader, LoggedIn(user): LoggedIn) -> Result<impl Responder> {
     Ok(HttpResponse::Ok().json(ResponseType::new(user, None)))
@@ -2,33 +2,201 @@ use crate::AppState;
 use actix_web::{HttpResponse, Responder, get, web::Data};
 use chrono::{SecondsFormat, Utc};
 use mysk_lib::{common::response::ResponseType, prelude::*};
+use reqwest::{
+    Client,
+    header::{AUTHORIZATION, HeaderValue},
+};
 use serde::Serialize;
 use sqlx::query;
-use std::time;
+use std::{
+    sync::LazyLock,
+    time::{self, Duration},
+};
+use tokio::time::timeout;
+use utoipa::ToSchema;
 
-#[derive(Serialize)]
+/// How long a dependency may take to respond before it is considered down.
+const DEPENDENCY_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// Reused by every readiness probe, so they share its connection pool.
+static STORAGE_CLIENT: LazyLock<Client> = LazyLock::new(Client::new);
+
+#[derive(Serialize, ToSchema)]
 struct HealthCheckResponse {
     server_time: String,
     database_connection: bool,
     database_response_time: u128,
 }
 
+#[utoipa::path(
+    tag = "Health",
+    responses(
+        (status = OK, description = "The database is reachable", body = ResponseType<HealthCheckResponse>),
+        (status = SERVICE_UNAVAILABLE, description = "The database is unreachable", body = ResponseType<HealthCheckResponse>),
+    ),
+    security(()),
+)]
 #[get("/health-check")]
 pub async fn health_check(data: Data<AppState>) -> Result<impl Responder> {
-    let mut conn = data.db.acquire().await?;
-
-    let start = time::Instant::now();
-    let database_connection = query("SELECT 1").execute(&mut *conn).await.is_ok();
-    let database_response_time = start.elapsed().as_millis();
+    let database = check_database(&data).await;
 
     let response = ResponseType::new(
         HealthCheckResponse {
             server_time: Utc::now().to_rfc3339_opts(SecondsFormat::Secs, true),
-            database_connection,
-            database_response_time,
+            database_connection: database.connected,
+            database_response_time: database.response_time,
+        },
+        None,
+    );
+
+    Ok(if database.connected {
+        HttpResponse::Ok().json(response)
+    } else {
+        HttpResponse::ServiceUnavailable().json(response)
+    })
+}
+
+#[derive(Serialize, ToSchema)]
+struct LivenessResponse {
+    server_time: String,
+}
+
+/// Whether the process is up and serving requests. This never touches any dependency, so an
+/// outage elsewhere does not get the instance restarted.
+#[utoipa::path(
+    tag = "Health",
+    responses((status = OK, description = "The process is up", body = ResponseType<LivenessResponse>)),
+    security(()),
+)]
+#[get("/health/live")]
+pub async fn liveness() -> Result<impl Responder> {
+    let response = ResponseType::new(
+        LivenessResponse {
+            server_time: Utc::now().to_rfc3339_opts(SecondsFormat::Secs, true),
         },
         None,
     );
 
     Ok(HttpResponse::Ok().json(response))
 }
+
+#[derive(Serialize, ToSchema)]
+struct BuildInfo {
+    version: &'static str,
+    commit_short_hash: &'static str,
+    commit_date: &'static str,
+    target_triple: &'static str,
+}
+
+#[derive(Serialize, ToSchema)]
+struct DatabaseStatus {
+    connected: bool,
+    response_time: u128,
+    pool_size: u32,
+    pool_idle: usize,
+    pool_max_size: u32,
+}
+
+#[derive(Serialize, ToSchema)]
+struct CacheStatus {
+    populated: bool,
+}
+
+#[derive(Serialize, ToSchema)]
+struct StorageStatus {
+    reachable: bool,
+    response_time: u128,
+}
+
+#[derive(Serialize, ToSchema)]
+struct ReadinessResponse {
+    ready: bool,
+    server_time: String,
+    build: BuildInfo,
+    database: DatabaseStatus,
+    cache: CacheStatus,
+    storage: StorageStatus,
+}
+
+/// Whether the instance can serve traffic. Responds with 503 when the database or the global
+/// cache, which every request depends on, is unavailable. Supabase storage is only needed for
+/// report images, so it is reported but doesn't affect readiness.
+#[utoipa::path(
+    tag = "Health",
+    responses(
+        (status = OK, description = "The instance is ready", body = ResponseType<ReadinessResponse>),
+        (status = SERVICE_UNAVAILABLE, description = "The database or the cache is unavailable", body = ResponseType<ReadinessResponse>),
+    ),
+    security(()),
+)]
+#[get("/health/ready")]
+pub async fn readiness(data: Data<AppState>) -> Result<impl Responder> {
+    let (database, storage) = tokio::join!(check_database(&data), check_storage(&data));
+    let cache = CacheStatus {
+        populated: data.cache.is_populated(),
+    };
+    let ready = database.connected && cache.populated;
+
+    let response = ResponseType::new(
+        ReadinessResponse {
+            ready,
+            server_time: Utc::now().to_rfc3339_opts(SecondsFormat::Secs, true),
+            build: BuildInfo {
+                version: env!("CARGO_PKG_VERSION"),
+                commit_short_hash: env!("COMMIT_SHORT_HASH"),
+                commit_date: env!("COMMIT_DATE"),
+                target_triple: env!("TARGET_TRIPLE"),
+            },
+            database,
+            cache,
+            storage,
+        },
+        None,
+    );
+
+    Ok(if ready {
+        HttpResponse::Ok().json(response)
+    } else {
+        HttpResponse::ServiceUnavailable().json(response)
+    })
+}
+
+async fn check_database(data: &AppState) -> DatabaseStatus {
+    let start = time::Instant::now();
+    let connected = timeout(DEPENDENCY_TIMEOUT, query("SELECT 1").execute(&data.db))
+        .await
+        .is_ok_and(|result| result.is_ok());
+    let response_time = start.elapsed().as_millis();
+
+    DatabaseStatus {
+        connected,
+        response_time,
+        pool_size: data.db.size(),
+        pool_idle: data.db.num_idle(),
+        pool_max_size: data.db.options().get_max_connections(),
+    }
+}
+
+async fn check_storage(data: &AppState) -> StorageStatus {
+    let supabase_authorization = format!("Bearer {}", data.env.supabase_secret_key);
+    let start = time::Instant::now();
+    let reachable = match HeaderValue::from_str(&supabase_authorization) {
+        Ok(authorization) => STORAGE_CLIENT
+            .get(format!(
+                "{}/storage/v1/bucket/online_teaching_reports",
+                data.env.supabase_uri,
+            ))
+            .header(AUTHORIZATION, authorization)
+            .timeout(DEPENDENCY_TIMEOUT)
+            .send()
+            .await
+            .is_ok_and(|response| response.status().is_success()),
+        Err(_) => false,
+    };
+    let response_time = start.elapsed().as_millis();
+
+    StorageStatus {
+        reachable,
+        response_time,
+    }
+}
@@ -0,0 +1,21 @@
+use crate::{AppState, extractors::admin_api_key::AdminApiKeyHeader, metrics::METRICS};
+use actix_web::{HttpResponse, Responder, get, web::Data};
+use mysk_lib::prelude::*;
+use prometheus::TEXT_FORMAT;
+
+#[utoipa::path(
+    tag = "Metrics",
+    responses((status = OK, description = "Prometheus metrics in the text exposition format", body = String, content_type = "text/plain")),
+    security(("api_key" = [])),
+)]
+#[get("/metrics")]
+pub async fn metrics(data: Data<AppState>, _: AdminApiKeyHeader) -> Result<impl Responder> {
+    let Ok(body) = METRICS.encode(&data.db) else {
+        return Err(Error::InternalServerError(
+            "Failed to encode metrics".to_string(),
+            "/metrics".to_string(),
+        ));
+    };
+
+    Ok(HttpResponse::Ok().content_type(TEXT_FORMAT).body(body))
+}
@@ -1,13 +1,45 @@
 use actix_web::web::{ServiceConfig, scope, to};
+use mysk_lib::common::response::ErrorResponseType;
+use openapi::{ErrorResponses, SecuritySchemes};
+use utoipa::OpenApi;
 
 pub mod auth;
 pub mod health;
+pub mod metrics;
 pub mod not_found;
+pub mod openapi;
 pub mod v1;
 
+/// The `OpenAPI` document of every route, served by [`openapi::openapi`]. Each scope documents its
+/// own routes in an `ApiDoc` next to its `config`, which also lists the `Queryable*` and `Sortable*`
+/// types of its routes, as schemas only used in query parameters aren't collected otherwise.
+#[derive(OpenApi)]
+#[openapi(
+    info(title = "MySK API"),
+    paths(
+        health::health_check,
+        health::liveness,
+        health::readiness,
+        metrics::metrics,
+        openapi::openapi,
+    ),
+    nest(
+        (path = "/auth", api = auth::ApiDoc),
+        (path = "/v1", api = v1::ApiDoc),
+    ),
+    components(schemas(ErrorResponseType)),
+    modifiers(&SecuritySchemes, &ErrorResponses),
+    security(("api_key" = [], "token" = [])),
+)]
+pub struct ApiDoc;
+
 pub fn config(cfg: &mut ServiceConfig) {
     cfg.service(scope("/auth").configure(auth::config))
         .service(scope("/v1").configure(v1::config))
         .service(health::health_check)
+        .service(health::liveness)
+        .service(health::readiness)
+        .service(metrics::metrics)
+        .service(openapi::openapi)
         .default_service(to(not_found::not_found));
 }
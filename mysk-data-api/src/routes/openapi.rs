@@ -0,0 +1,91 @@
+use crate::routes::ApiDoc;
+use actix_web::{HttpResponse, Responder, get};
+use mysk_lib::common::response::ErrorResponseType;
+use std::sync::LazyLock;
+use utoipa::{
+    Modify, OpenApi as _, ToSchema as _,
+    openapi::{
+        ContentBuilder, OpenApi, Ref, ResponseBuilder,
+        security::{ApiKey, ApiKeyValue, HttpAuthScheme, HttpBuilder, SecurityScheme},
+    },
+};
+
+static DOCUMENT: LazyLock<String> = LazyLock::new(|| {
+    ApiDoc::openapi()
+        .to_pretty_json()
+        .expect("The OpenAPI document should serialize")
+});
+
+/// The `OpenAPI` document describing every route, as committed in `openapi.json`.
+#[utoipa::path(
+    tag = "OpenAPI",
+    responses((status = OK, description = "The OpenAPI 3.1 document", content_type = "application/json")),
+    security(()),
+)]
+#[get("/openapi.json")]
+pub async fn openapi() -> impl Responder {
+    HttpResponse::Ok()
+        .content_type("application/json")
+        .body(DOCUMENT.as_str())
+}
+
+/// The headers checked by the `ApiKeyHeader` and `LoggedIn` extractors.
+pub struct SecuritySchemes;
+
+impl Modify for SecuritySchemes {
+    fn modify(&self, api: &mut OpenApi) {
+        let components = api.components.get_or_insert_default();
+        components.add_security_scheme(
+            "api_key",
+            SecurityScheme::ApiKey(ApiKey::Header(ApiKeyValue::new("X-Api-Key"))),
+        );
+        components.add_security_scheme(
+            "token",
+            SecurityScheme::Http(
+                HttpBuilder::new()
+                    .scheme(HttpAuthScheme::Bearer)
+                    .bearer_format("JWT")
+                    .build(),
+            ),
+        );
+    }
+}
+
+/// Documents `ErrorResponseType` as the response to every failed request, whatever the route. The
+/// schema itself is registered by `ApiDoc`.
+pub struct ErrorResponses;
+
+impl Modify for ErrorResponses {
+    fn modify(&self, api: &mut OpenApi) {
+        let components = api.components.get_or_insert_default();
+        components.responses.insert(
+            "Error".to_string(),
+            ResponseBuilder::new()
+                .description("The request failed, with the status code of the error")
+                .content(
+                    "application/json",
+                    ContentBuilder::new()
+                        .schema(Some(Ref::from_schema_name(ErrorResponseType::name())))
+                        .build(),
+                )
+                .into(),
+        );
+
+        for item in api.paths.paths.values_mut() {
+            let operations = [
+                &mut item.get,
+                &mut item.put,
+                &mut item.post,
+                &mut item.delete,
+                &mut item.patch,
+            ];
+            for operation in operations.into_iter().flatten() {
+                operation
+                    .responses
+                    .responses
+                    .entry("default".to_string())
+                    .or_insert_with(|| Ref::from_response_name("Error").into());
+            }
+        }
+    }
+}
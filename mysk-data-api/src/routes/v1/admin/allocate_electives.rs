@@ -0,0 +1,66 @@
+use crate::{
+    AppState,
+    extractors::{admin::LoggedInAdmin, api_key::ApiKeyHeader},
+    metrics::METRICS,
+};
+use actix_web::{HttpResponse, Responder, post, web::Data};
+use mysk_lib::{
+    allocation::{self, AllocationReport},
+    audit::{AuditContext, AuditEntity, AuditSnapshot},
+    common::{
+        requests::{Json, RequestType},
+        response::ResponseType,
+        validation::Validate,
+    },
+    prelude::*,
+};
+use serde::Deserialize;
+use utoipa::ToSchema;
+
+#[derive(Debug, Deserialize, ToSchema, Validate)]
+struct AllocateElectivesRequest {
+    /// Repeats the allocation of an earlier run with this seed. A new seed is drawn if left out.
+    seed: Option<u32>,
+    /// Reports the seats which would be given without giving them.
+    #[serde(default)]
+    dry_run: bool,
+}
+
+#[utoipa::path(
+    tag = "Admin",
+    responses(
+        (status = OK, description = "The seats given to students who didn't enroll", body = ResponseType<AllocationReport>),
+    ),
+)]
+#[post("/electives/allocate")]
+pub async fn allocate_electives(
+    data: Data<AppState>,
+    _: ApiKeyHeader,
+    LoggedInAdmin(user): LoggedInAdmin,
+    Json(RequestType {
+        data: request_data, ..
+    }): Json<RequestType<AllocateElectivesRequest>>,
+) -> Result<impl Responder> {
+    let mut transaction = data.db.begin().await?;
+
+    let mut snapshot =
+        AuditSnapshot::take(&mut transaction, AuditEntity::ElectiveEnrollment, &[]).await?;
+    let report = allocation::run(&mut transaction, request_data.seed, request_data.dry_run).await?;
+
+    if !report.dry_run {
+        for allocation in &report.allocations {
+            snapshot.track(allocation.student_id);
+        }
+        AuditContext::new(&user, "POST /v1/admin/electives/allocate")
+            .record(&mut transaction, snapshot)
+            .await?;
+        transaction.commit().await?;
+        METRICS
+            .elective_enrollments
+            .with_label_values(&["allocate"])
+            .inc_by(report.allocations.len() as u64);
+    }
+    let response = ResponseType::new(report, None);
+
+    Ok(HttpResponse::Ok().json(response))
+}
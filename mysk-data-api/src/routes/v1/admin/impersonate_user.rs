@@ -0,0 +1,104 @@
+use crate::{
+    AppState,
+    extractors::{admin::LoggedInAdmin, api_key::ApiKeyHeader},
+};
+use actix_web::{HttpResponse, Responder, post, web::Data};
+use chrono::{Duration, Utc};
+use jsonwebtoken::{EncodingKey, Header};
+use mysk_lib::{
+    auth::oauth::TokenClaims,
+    common::{
+        requests::{Json, RequestType},
+        response::ResponseType,
+        validation::Validate,
+    },
+    models::user::{User, UserMeta},
+    prelude::*,
+};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+#[derive(Debug, Deserialize, ToSchema, Validate)]
+struct ImpersonateUserRequest {
+    /// The user to act as.
+    #[validate(not_nil)]
+    user_id: Uuid,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+struct ImpersonationTokenResponse {
+    access_token: String,
+    expires_in: u64,
+    token_type: &'static str,
+}
+
+/// Issues a session token to act as another user. Every change made with it is recorded in the
+/// audit logs with the admin as its impersonator. Unlike a login, the token isn't set as a cookie so
+/// the admin's own session is kept.
+#[utoipa::path(
+    tag = "Admin",
+    responses(
+        (status = OK, description = "A session token acting as the user", body = ResponseType<ImpersonationTokenResponse>),
+    ),
+)]
+#[allow(clippy::cast_possible_wrap)]
+#[post("/impersonate")]
+pub async fn impersonate_user(
+    data: Data<AppState>,
+    _: ApiKeyHeader,
+    LoggedInAdmin(admin): LoggedInAdmin,
+    Json(RequestType {
+        data: request_data, ..
+    }): Json<RequestType<ImpersonateUserRequest>>,
+) -> Result<impl Responder> {
+    let user =
+        User::get_by_id(&mut *(data.db.acquire().await?), request_data.user_id, None).await?;
+    // Otherwise, an admin could act under the name of another
+    if user.is_admin {
+        return Err(Error::BrokenRule(
+            ErrorCode::ImpersonateAdmin,
+            "/admin/impersonate".to_string(),
+        ));
+    }
+
+    let now = Utc::now();
+    let iat = usize::try_from(now.timestamp())
+        .expect("Irrecoverable error, i64 is out of range for usize");
+    let exp = usize::try_from((now + Duration::minutes(data.env.token_max_age as i64)).timestamp())
+        .expect("Irrecoverable error, i64 is out of range for usize");
+    let claims = TokenClaims {
+        sub: user.id,
+        mta: match user.meta {
+            Some(
+                UserMeta::Student { student_id: id }
+                | UserMeta::Teacher { teacher_id: id }
+                | UserMeta::Organization {
+                    organization_id: id,
+                },
+            ) => Some(id),
+            None => None,
+        },
+        imp: Some(admin.id),
+        exp,
+        iat,
+    };
+
+    let token = jsonwebtoken::encode(
+        &Header::default(),
+        &claims,
+        &EncodingKey::from_secret(data.env.token_secret.as_bytes()),
+    )?;
+    tracing::info!(admin_id = %admin.id, user_id = %user.id, "Issued an impersonation token");
+
+    let response = ResponseType::new(
+        ImpersonationTokenResponse {
+            access_token: token,
+            expires_in: data.env.token_max_age * 60,
+            token_type: "Bearer",
+        },
+        None,
+    );
+
+    Ok(HttpResponse::Ok().json(response))
+}
@@ -0,0 +1,40 @@
+use actix_web::web::{ServiceConfig, scope};
+use mysk_lib::models::{
+    audit_log::request::{queryable::QueryableAuditLog, sortable::SortableAuditLog},
+    error_log::request::{queryable::QueryableErrorLog, sortable::SortableErrorLog},
+};
+use utoipa::OpenApi;
+
+pub mod allocate_electives;
+pub mod impersonate_user;
+pub mod query_audit_logs;
+pub mod query_error_logs;
+pub mod webhooks;
+
+#[derive(OpenApi)]
+#[openapi(
+    paths(
+        query_audit_logs::query_audit_logs,
+        query_error_logs::query_error_logs,
+        allocate_electives::allocate_electives,
+        impersonate_user::impersonate_user,
+    ),
+    nest(
+        (path = "/webhooks", api = webhooks::ApiDoc),
+    ),
+    components(schemas(
+        QueryableAuditLog,
+        SortableAuditLog,
+        QueryableErrorLog,
+        SortableErrorLog
+    ))
+)]
+pub struct ApiDoc;
+
+pub fn config(cfg: &mut ServiceConfig) {
+    cfg.service(scope("/webhooks").configure(webhooks::config))
+        .service(query_audit_logs::query_audit_logs)
+        .service(query_error_logs::query_error_logs)
+        .service(allocate_electives::allocate_electives)
+        .service(impersonate_user::impersonate_user);
+}
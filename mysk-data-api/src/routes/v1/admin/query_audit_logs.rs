@@ -0,0 +1,53 @@
+use crate::{
+    AppState,
+    extractors::{admin::LoggedInAdmin, api_key::ApiKeyHeader},
+};
+use actix_web::{HttpResponse, Responder, get, web::Data};
+use mysk_lib::{
+    common::{
+        requests::{EmptyRequestData, RequestType, SortingConfig},
+        response::{MetadataType, ResponseType},
+    },
+    models::{
+        audit_log::{
+            db::DbAuditLog,
+            request::{queryable::QueryableAuditLog, sortable::SortableAuditLog},
+        },
+        traits::QueryRelation as _,
+    },
+    prelude::*,
+};
+
+#[utoipa::path(
+    tag = "Admin",
+    params(RequestType<EmptyRequestData, QueryableAuditLog, SortableAuditLog>),
+    responses(
+        (status = OK, description = "The matching audit logs, newest first", body = ResponseType<Vec<DbAuditLog>>),
+    ),
+)]
+#[get("/audit-logs")]
+pub async fn query_audit_logs(
+    data: Data<AppState>,
+    _: ApiKeyHeader,
+    _: LoggedInAdmin,
+    RequestType {
+        pagination,
+        filter,
+        sort,
+        ..
+    }: RequestType<EmptyRequestData, QueryableAuditLog, SortableAuditLog>,
+) -> Result<impl Responder> {
+    let pool = &data.db;
+
+    // Most recent changes first unless asked otherwise
+    let sort = sort.or_else(|| {
+        Some(SortingConfig::new(
+            vec![SortableAuditLog::CreatedAt],
+            Some(false),
+        ))
+    });
+    let (audit_logs, pagination) = DbAuditLog::query(pool, filter, sort, pagination).await?;
+    let response = ResponseType::new(audit_logs, Some(MetadataType::new(Some(pagination))));
+
+    Ok(HttpResponse::Ok().json(response))
+}
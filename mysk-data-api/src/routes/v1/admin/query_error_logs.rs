@@ -0,0 +1,53 @@
+use crate::{
+    AppState,
+    extractors::{admin::LoggedInAdmin, api_key::ApiKeyHeader},
+};
+use actix_web::{HttpResponse, Responder, get, web::Data};
+use mysk_lib::{
+    common::{
+        requests::{EmptyRequestData, RequestType, SortingConfig},
+        response::{MetadataType, ResponseType},
+    },
+    models::{
+        error_log::{
+            db::DbErrorLog,
+            request::{queryable::QueryableErrorLog, sortable::SortableErrorLog},
+        },
+        traits::QueryRelation as _,
+    },
+    prelude::*,
+};
+
+#[utoipa::path(
+    tag = "Admin",
+    params(RequestType<EmptyRequestData, QueryableErrorLog, SortableErrorLog>),
+    responses(
+        (status = OK, description = "The matching error logs, newest first", body = ResponseType<Vec<DbErrorLog>>),
+    ),
+)]
+#[get("/error-logs")]
+pub async fn query_error_logs(
+    data: Data<AppState>,
+    _: ApiKeyHeader,
+    _: LoggedInAdmin,
+    RequestType {
+        pagination,
+        filter,
+        sort,
+        ..
+    }: RequestType<EmptyRequestData, QueryableErrorLog, SortableErrorLog>,
+) -> Result<impl Responder> {
+    let pool = &data.db;
+
+    // Most recent errors first unless asked otherwise
+    let sort = sort.or_else(|| {
+        Some(SortingConfig::new(
+            vec![SortableErrorLog::CreatedAt],
+            Some(false),
+        ))
+    });
+    let (error_logs, pagination) = DbErrorLog::query(pool, filter, sort, pagination).await?;
+    let response = ResponseType::new(error_logs, Some(MetadataType::new(Some(pagination))));
+
+    Ok(HttpResponse::Ok().json(response))
+}
@@ -0,0 +1,77 @@
+use crate::{
+    AppState,
+    extractors::{admin::LoggedInAdmin, api_key::ApiKeyHeader},
+};
+use actix_web::{HttpResponse, Responder, post, web::Data};
+use mysk_lib::{
+    common::{
+        requests::{Json, RequestType},
+        response::ResponseType,
+        validation::Validate,
+    },
+    models::webhook_endpoint::db::DbWebhookEndpoint,
+    prelude::*,
+    webhooks::WebhookEvent,
+};
+use reqwest::Url;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(Debug, Deserialize, ToSchema, Validate)]
+struct CreateWebhookRequest {
+    /// An `http` or `https` URL the events are posted to.
+    url: String,
+    events: Vec<WebhookEvent>,
+    description: Option<String>,
+}
+
+/// A newly registered endpoint, along with the secret its payloads are signed with.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct CreatedWebhookEndpoint {
+    #[serde(flatten)]
+    endpoint: DbWebhookEndpoint,
+    /// Only ever shown here, so it must be stored by the receiving system right away.
+    secret: String,
+}
+
+#[utoipa::path(
+    tag = "Admin",
+    responses(
+        (status = OK, description = "The new endpoint and its secret", body = ResponseType<CreatedWebhookEndpoint>),
+    ),
+)]
+#[post("")]
+pub async fn create_webhook(
+    data: Data<AppState>,
+    _: ApiKeyHeader,
+    _: LoggedInAdmin,
+    Json(RequestType {
+        data: request_data, ..
+    }): Json<RequestType<CreateWebhookRequest>>,
+) -> Result<impl Responder> {
+    let mut conn = data.db.acquire().await?;
+
+    if !Url::parse(&request_data.url).is_ok_and(|url| matches!(url.scheme(), "http" | "https")) {
+        return Err(Error::InvalidRequest(
+            "The URL must be an absolute `http` or `https` URL".to_string(),
+            "/admin/webhooks".to_string(),
+        ));
+    }
+    if request_data.events.is_empty() {
+        return Err(Error::InvalidRequest(
+            "The endpoint must subscribe to at least one event".to_string(),
+            "/admin/webhooks".to_string(),
+        ));
+    }
+
+    let (endpoint, secret) = DbWebhookEndpoint::create(
+        &mut conn,
+        &request_data.url,
+        &request_data.events,
+        request_data.description.as_deref(),
+    )
+    .await?;
+    let response = ResponseType::new(CreatedWebhookEndpoint { endpoint, secret }, None);
+
+    Ok(HttpResponse::Ok().json(response))
+}
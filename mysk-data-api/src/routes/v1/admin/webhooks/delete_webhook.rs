@@ -0,0 +1,37 @@
+use crate::{
+    AppState,
+    extractors::{admin::LoggedInAdmin, api_key::ApiKeyHeader},
+};
+use actix_web::{
+    HttpResponse, Responder, delete,
+    web::{Data, Path},
+};
+use mysk_lib::{
+    common::response::{EmptyResponseData, ResponseType},
+    models::webhook_endpoint::db::DbWebhookEndpoint,
+    prelude::*,
+};
+use uuid::Uuid;
+
+/// Removes the endpoint along with its undelivered events and delivery logs. Prefer disabling it to
+/// keep its history.
+#[utoipa::path(
+    tag = "Admin",
+    responses(
+        (status = OK, description = "The endpoint was deleted", body = ResponseType<EmptyResponseData>),
+    ),
+)]
+#[delete("/{id}")]
+pub async fn delete_webhook(
+    data: Data<AppState>,
+    _: ApiKeyHeader,
+    _: LoggedInAdmin,
+    endpoint_id: Path<Uuid>,
+) -> Result<impl Responder> {
+    let mut conn = data.db.acquire().await?;
+
+    DbWebhookEndpoint::delete(&mut conn, endpoint_id.into_inner()).await?;
+    let response = ResponseType::new(EmptyResponseData {}, None);
+
+    Ok(HttpResponse::Ok().json(response))
+}
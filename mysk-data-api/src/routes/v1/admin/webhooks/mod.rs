@@ -0,0 +1,35 @@
+use actix_web::web::ServiceConfig;
+use mysk_lib::{
+    models::webhook_delivery::request::{
+        queryable::QueryableWebhookDelivery, sortable::SortableWebhookDelivery,
+    },
+    webhooks::WebhookEvent,
+};
+use utoipa::OpenApi;
+
+pub mod create_webhook;
+pub mod delete_webhook;
+pub mod query_webhook_deliveries;
+pub mod query_webhooks;
+pub mod update_webhook;
+
+#[derive(OpenApi)]
+#[openapi(
+    paths(
+        query_webhooks::query_webhooks,
+        create_webhook::create_webhook,
+        query_webhook_deliveries::query_webhook_deliveries,
+        update_webhook::update_webhook,
+        delete_webhook::delete_webhook,
+    ),
+    components(schemas(WebhookEvent, QueryableWebhookDelivery, SortableWebhookDelivery))
+)]
+pub struct ApiDoc;
+
+pub fn config(cfg: &mut ServiceConfig) {
+    cfg.service(query_webhooks::query_webhooks)
+        .service(create_webhook::create_webhook)
+        .service(query_webhook_deliveries::query_webhook_deliveries)
+        .service(update_webhook::update_webhook)
+        .service(delete_webhook::delete_webhook);
+}
@@ -0,0 +1,53 @@
+use crate::{
+    AppState,
+    extractors::{admin::LoggedInAdmin, api_key::ApiKeyHeader},
+};
+use actix_web::{HttpResponse, Responder, get, web::Data};
+use mysk_lib::{
+    common::{
+        requests::{EmptyRequestData, RequestType, SortingConfig},
+        response::{MetadataType, ResponseType},
+    },
+    models::{
+        traits::QueryRelation as _,
+        webhook_delivery::{
+            db::DbWebhookDelivery,
+            request::{queryable::QueryableWebhookDelivery, sortable::SortableWebhookDelivery},
+        },
+    },
+    prelude::*,
+};
+
+#[utoipa::path(
+    tag = "Admin",
+    params(RequestType<EmptyRequestData, QueryableWebhookDelivery, SortableWebhookDelivery>),
+    responses(
+        (status = OK, description = "The matching delivery attempts, newest first", body = ResponseType<Vec<DbWebhookDelivery>>),
+    ),
+)]
+#[get("/deliveries")]
+pub async fn query_webhook_deliveries(
+    data: Data<AppState>,
+    _: ApiKeyHeader,
+    _: LoggedInAdmin,
+    RequestType {
+        pagination,
+        filter,
+        sort,
+        ..
+    }: RequestType<EmptyRequestData, QueryableWebhookDelivery, SortableWebhookDelivery>,
+) -> Result<impl Responder> {
+    let pool = &data.db;
+
+    // Most recent attempts first unless asked otherwise
+    let sort = sort.or_else(|| {
+        Some(SortingConfig::new(
+            vec![SortableWebhookDelivery::CreatedAt],
+            Some(false),
+        ))
+    });
+    let (deliveries, pagination) = DbWebhookDelivery::query(pool, filter, sort, pagination).await?;
+    let response = ResponseType::new(deliveries, Some(MetadataType::new(Some(pagination))));
+
+    Ok(HttpResponse::Ok().json(response))
+}
@@ -0,0 +1,26 @@
+use crate::{
+    AppState,
+    extractors::{admin::LoggedInAdmin, api_key::ApiKeyHeader},
+};
+use actix_web::{HttpResponse, Responder, get, web::Data};
+use mysk_lib::{
+    common::response::ResponseType, models::webhook_endpoint::db::DbWebhookEndpoint, prelude::*,
+};
+
+#[utoipa::path(
+    tag = "Admin",
+    responses(
+        (status = OK, description = "Every registered endpoint, oldest first", body = ResponseType<Vec<DbWebhookEndpoint>>),
+    ),
+)]
+#[get("")]
+pub async fn query_webhooks(
+    data: Data<AppState>,
+    _: ApiKeyHeader,
+    _: LoggedInAdmin,
+) -> Result<impl Responder> {
+    let endpoints = DbWebhookEndpoint::get_all(&data.db).await?;
+    let response = ResponseType::new(endpoints, None);
+
+    Ok(HttpResponse::Ok().json(response))
+}
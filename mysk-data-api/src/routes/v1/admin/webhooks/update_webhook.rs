@@ -0,0 +1,52 @@
+use crate::{
+    AppState,
+    extractors::{admin::LoggedInAdmin, api_key::ApiKeyHeader},
+};
+use actix_web::{
+    HttpResponse, Responder, put,
+    web::{Data, Path},
+};
+use mysk_lib::{
+    common::{
+        requests::{Json, RequestType},
+        response::ResponseType,
+        validation::Validate,
+    },
+    models::{traits::GetById as _, webhook_endpoint::db::DbWebhookEndpoint},
+    prelude::*,
+};
+use serde::Deserialize;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+#[derive(Debug, Deserialize, ToSchema, Validate)]
+struct UpdateWebhookRequest {
+    /// Whether new events are sent to the endpoint.
+    is_active: bool,
+}
+
+#[utoipa::path(
+    tag = "Admin",
+    responses(
+        (status = OK, description = "The updated endpoint", body = ResponseType<DbWebhookEndpoint>),
+    ),
+)]
+#[put("/{id}")]
+pub async fn update_webhook(
+    data: Data<AppState>,
+    _: ApiKeyHeader,
+    _: LoggedInAdmin,
+    endpoint_id: Path<Uuid>,
+    Json(RequestType {
+        data: request_data, ..
+    }): Json<RequestType<UpdateWebhookRequest>>,
+) -> Result<impl Responder> {
+    let mut conn = data.db.acquire().await?;
+    let endpoint_id = endpoint_id.into_inner();
+
+    DbWebhookEndpoint::set_active(&mut conn, endpoint_id, request_data.is_active).await?;
+    let endpoint = DbWebhookEndpoint::get_by_id(&mut conn, endpoint_id).await?;
+    let response = ResponseType::new(endpoint, None);
+
+    Ok(HttpResponse::Ok().json(response))
+}
@@ -1,5 +1,3 @@
-use std::{collections::HashSet, sync::LazyLock};
-
 use crate::{AppState, extractors::api_key::ApiKeyHeader};
 use actix_web::{
     HttpResponse, Responder, get,
@@ -8,21 +6,24 @@ use actix_web::{
 use chrono::NaiveDate;
 use mysk_lib::{common::response::ResponseType, prelude::*};
 
-static JATURAMITR_DATES: LazyLock<HashSet<NaiveDate>> = LazyLock::new(|| {
-    HashSet::from([
-        NaiveDate::from_ymd_opt(2025, 11, 13).unwrap(),
-        NaiveDate::from_ymd_opt(2025, 11, 15).unwrap(),
-        NaiveDate::from_ymd_opt(2025, 11, 22).unwrap(),
-    ])
-});
-
+#[utoipa::path(
+    tag = "Attendance",
+    responses(
+        (status = OK, description = "Whether the date is in a Jaturamitr period", body = ResponseType<bool>),
+    ),
+    security(("api_key" = [])),
+)]
 #[get("/in-jaturamitr-period/{date}")]
 pub async fn in_jaturamitr_period(
-    _data: Data<AppState>,
+    data: Data<AppState>,
     _: ApiKeyHeader,
-    date: Path<NaiveDate>,
+    practice_date: Path<NaiveDate>,
 ) -> Result<impl Responder> {
-    let response = ResponseType::new(JATURAMITR_DATES.contains(&date.into_inner()), None);
+    let response = ResponseType::new(
+        data.cache
+            .contains_jaturamitr_date(practice_date.into_inner()),
+        None,
+    );
 
     Ok(HttpResponse::Ok().json(response))
 }
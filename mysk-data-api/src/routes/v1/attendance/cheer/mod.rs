@@ -1,9 +1,26 @@
 use actix_web::web::{ServiceConfig, scope};
+use mysk_lib::models::cheer_practice_attendance::request::{
+    queryable::QueryableCheerPracticeAttendance, sortable::SortableCheerPracticeAttendance,
+};
+use utoipa::OpenApi;
 
 pub mod in_jaturamitr_period;
 pub mod periods;
 pub mod query_cheer_practice_attendances;
 
+#[derive(OpenApi)]
+#[openapi(
+    paths(
+        query_cheer_practice_attendances::query_cheer_practice_attendances,
+        in_jaturamitr_period::in_jaturamitr_period,
+    ),
+    nest(
+        (path = "/periods", api = periods::ApiDoc),
+    ),
+    components(schemas(QueryableCheerPracticeAttendance, SortableCheerPracticeAttendance)),
+)]
+pub struct ApiDoc;
+
 pub fn config(cfg: &mut ServiceConfig) {
     cfg.service(scope("/periods").configure(periods::config))
         .service(query_cheer_practice_attendances::query_cheer_practice_attendances)
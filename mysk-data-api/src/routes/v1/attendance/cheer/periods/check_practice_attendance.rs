@@ -1,13 +1,19 @@
 use crate::{
     AppState,
     extractors::{api_key::ApiKeyHeader, logged_in::LoggedIn},
+    metrics::METRICS,
 };
 use actix_web::{
     HttpResponse, Responder, post,
-    web::{Data, Json, Path},
+    web::{Data, Path},
 };
 use mysk_lib::{
-    common::{requests::RequestType, response::ResponseType},
+    audit::{AuditContext, AuditEntity, AuditSnapshot},
+    common::{
+        requests::{Json, RequestType},
+        response::ResponseType,
+        validation::Validate,
+    },
     models::{
         cheer_practice_attendance::CheerPracticeAttendance,
         cheer_practice_period::db::DbCheerPracticePeriod,
@@ -22,17 +28,26 @@ use mysk_lib::{
 };
 use serde::Deserialize;
 use sqlx::query_scalar;
+use utoipa::ToSchema;
 use uuid::Uuid;
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, ToSchema, Validate)]
 struct CheckPracticeAttendanceRequest {
     is_start: bool,
+    #[validate(not_nil)]
     student_id: Uuid,
     presence: Option<CheerPracticeAttendanceType>,
+    #[validate(not_empty)]
     absence_reason: Option<String>,
 }
 
 #[allow(clippy::too_many_lines)]
+#[utoipa::path(
+    tag = "Attendance",
+    responses(
+        (status = OK, description = "The recorded attendance", body = ResponseType<CheerPracticeAttendance>),
+    ),
+)]
 #[post("/{id}/check")]
 pub async fn check_practice_attendance(
     data: Data<AppState>,
@@ -64,15 +79,15 @@ pub async fn check_practice_attendance(
             ..
         } => {
             if !DbCheerPracticePeriod::is_student_cheer_staff(&data.cache, s_checker_id) {
-                return Err(Error::InvalidPermission(
-                    "Student must be a staff member to update attendances".to_string(),
+                return Err(Error::BrokenRule(
+                    ErrorCode::CheerStaffOnly,
                     format!("/attendance/cheer/periods/{practice_period_id}/check"),
                 ));
             }
 
             if s_checker_id == request_data.student_id {
-                return Err(Error::InvalidPermission(
-                    "Cheer staff cannot take their own cheer practice attendance".to_string(),
+                return Err(Error::BrokenRule(
+                    ErrorCode::CheerOwnAttendance,
                     format!("/attendance/cheer/periods/{practice_period_id}/check"),
                 ));
             }
@@ -86,11 +101,11 @@ pub async fn check_practice_attendance(
         } => {
             // Only teachers in `cheer_practice_teachers` can take attendance of any classroom
             // unless that day is Jaturamitr day
-            if !DbCheerPracticePeriod::in_jaturamitr_period(practice_period_id)
+            if !DbCheerPracticePeriod::in_jaturamitr_period(&data.cache, practice_period_id)
                 && !DbCheerPracticePeriod::is_teacher_cheer_staff(&data.cache, t_checker_id)
             {
-                return Err(Error::InvalidPermission(
-                    "Teacher is not allowed to take attendance on this period".to_string(),
+                return Err(Error::BrokenRule(
+                    ErrorCode::CheerTeacherNotAllowed,
                     format!("/attendance/cheer/periods/{practice_period_id}/check"),
                 ));
             }
@@ -111,9 +126,8 @@ pub async fn check_practice_attendance(
                 | CheerPracticeAttendanceType::AbsentWithoutLeave
         ) && request_data.absence_reason.is_some()
         {
-            return Err(Error::InvalidRequest(
-                "Absence reason was specified for a presence type that forbids a reason"
-                    .to_string(),
+            return Err(Error::BrokenRule(
+                ErrorCode::CheerAbsenceReasonForbidden,
                 format!("/attendance/cheer/periods/{practice_period_id}/check"),
             ));
         }
@@ -125,8 +139,8 @@ pub async fn check_practice_attendance(
                 CheerPracticeAttendanceType::Present | CheerPracticeAttendanceType::Deserted
             )
         {
-            return Err(Error::InvalidRequest(
-                "Invalid presence type for the current attendance-taking phase".to_string(),
+            return Err(Error::BrokenRule(
+                ErrorCode::CheerPresenceNotAllowed,
                 format!("/attendance/cheer/periods/{practice_period_id}/check"),
             ));
         }
@@ -153,6 +167,23 @@ pub async fn check_practice_attendance(
         ));
     }
 
+    let existing_attendance_id = query_scalar!(
+        "\
+        SELECT id FROM cheer_practice_attendances \
+        WHERE practice_period_id = $1 AND student_id = $2\
+        ",
+        practice_period_id,
+        request_data.student_id,
+    )
+    .fetch_optional(&mut *transaction)
+    .await?;
+    let mut snapshot = AuditSnapshot::take(
+        &mut transaction,
+        AuditEntity::CheerPracticeAttendance,
+        existing_attendance_id.as_slice(),
+    )
+    .await?;
+
     let is_presence_unset = request_data.presence.is_none();
 
     // Upsert with coalescing if the presence given isn't null (not unset by the client)
@@ -183,7 +214,19 @@ pub async fn check_practice_attendance(
     .fetch_one(&mut *transaction)
     .await?;
 
+    snapshot.track(practice_attendance_id);
+    AuditContext::new(&user, "POST /v1/attendance/cheer/periods/{id}/check")
+        .record(&mut transaction, snapshot)
+        .await?;
     transaction.commit().await?;
+    METRICS
+        .cheer_practice_checks
+        .with_label_values(&[if request_data.is_start {
+            "start"
+        } else {
+            "end"
+        }])
+        .inc();
 
     let practice_attendance = CheerPracticeAttendance::get_by_id(
         pool,
@@ -1,11 +1,37 @@
+use crate::attendance_feed::CheerPracticeAttendanceEvent;
 use actix_web::web::ServiceConfig;
+use mysk_lib::models::cheer_practice_period::request::{
+    queryable::QueryableCheerPracticePeriod, sortable::SortableCheerPracticePeriod,
+};
+use utoipa::OpenApi;
 
 pub mod check_practice_attendance;
 pub mod query_practice_period_details;
 pub mod query_practice_periods;
+pub mod set_jaturamitr_period;
+pub mod stream_practice_attendances;
+
+#[derive(OpenApi)]
+#[openapi(
+    paths(
+        check_practice_attendance::check_practice_attendance,
+        query_practice_periods::query_practice_periods,
+        query_practice_period_details::query_practice_period_details,
+        set_jaturamitr_period::set_jaturamitr_period,
+        stream_practice_attendances::stream_practice_attendances,
+    ),
+    components(schemas(
+        CheerPracticeAttendanceEvent,
+        QueryableCheerPracticePeriod,
+        SortableCheerPracticePeriod,
+    ))
+)]
+pub struct ApiDoc;
 
 pub fn config(cfg: &mut ServiceConfig) {
     cfg.service(check_practice_attendance::check_practice_attendance)
         .service(query_practice_periods::query_practice_periods)
-        .service(query_practice_period_details::query_practice_period_details);
+        .service(query_practice_period_details::query_practice_period_details)
+        .service(set_jaturamitr_period::set_jaturamitr_period)
+        .service(stream_practice_attendances::stream_practice_attendances);
 }
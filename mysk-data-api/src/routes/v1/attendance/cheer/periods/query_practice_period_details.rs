@@ -11,6 +11,14 @@ use mysk_lib::{
 };
 use uuid::Uuid;
 
+#[utoipa::path(
+    tag = "Attendance",
+    params(RequestType),
+    responses(
+        (status = OK, description = "The practice period", body = ResponseType<CheerPracticePeriod>),
+    ),
+    security(("api_key" = [])),
+)]
 #[get("/{id}")]
 pub async fn query_practice_period_details(
     data: Data<AppState>,
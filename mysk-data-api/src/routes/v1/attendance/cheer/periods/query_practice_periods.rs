@@ -3,7 +3,7 @@ use actix_web::{HttpResponse, Responder, get, web::Data};
 use mysk_lib::{
     common::{
         requests::{EmptyRequestData, FetchLevel, RequestType},
-        response::{MetadataType, ResponseType},
+        response::{MetadataType, ResponseListType, ResponseType},
     },
     models::cheer_practice_period::{
         CheerPracticePeriod,
@@ -13,6 +13,14 @@ use mysk_lib::{
     prelude::*,
 };
 
+#[utoipa::path(
+    tag = "Attendance",
+    params(RequestType<EmptyRequestData, QueryableCheerPracticePeriod, SortableCheerPracticePeriod>),
+    responses(
+        (status = OK, description = "The matching practice periods", body = ResponseListType<CheerPracticePeriod>),
+    ),
+    security(("api_key" = [])),
+)]
 #[get("")]
 pub async fn query_practice_periods(
     data: Data<AppState>,
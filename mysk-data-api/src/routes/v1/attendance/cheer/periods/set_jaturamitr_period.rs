@@ -0,0 +1,110 @@
+use crate::{
+    AppState,
+    extractors::{api_key::ApiKeyHeader, logged_in::LoggedIn},
+};
+use actix_web::{
+    HttpResponse, Responder, put,
+    web::{Data, Path},
+};
+use mysk_lib::{
+    common::{
+        requests::{Json, RequestType},
+        response::ResponseType,
+        validation::Validate,
+    },
+    models::{
+        cheer_practice_period::{CheerPracticePeriod, db::DbCheerPracticePeriod},
+        enums::UserRole,
+        user::{User, UserMeta},
+    },
+    permissions::Authorizer,
+    prelude::*,
+};
+use serde::Deserialize;
+use sqlx::query_scalar;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+#[derive(Debug, Deserialize, ToSchema, Validate)]
+struct SetJaturamitrPeriodRequest {
+    is_jaturamitr: bool,
+}
+
+#[utoipa::path(
+    tag = "Attendance",
+    responses(
+        (status = OK, description = "The updated practice period", body = ResponseType<CheerPracticePeriod>),
+    ),
+)]
+#[put("/{id}/jaturamitr")]
+pub async fn set_jaturamitr_period(
+    data: Data<AppState>,
+    _: ApiKeyHeader,
+    LoggedIn(user): LoggedIn,
+    practice_period_id: Path<Uuid>,
+    Json(RequestType {
+        data: request_data,
+        fetch_level,
+        descendant_fetch_level,
+        ..
+    }): Json<RequestType<SetJaturamitrPeriodRequest>>,
+) -> Result<impl Responder> {
+    let pool = &data.db;
+    let practice_period_id = practice_period_id.into_inner();
+
+    // Only admins and teachers in `cheer_practice_teachers` can mark Jaturamitr periods
+    let is_allowed = match user {
+        User { is_admin: true, .. } => true,
+        User {
+            role: UserRole::Teacher,
+            meta: Some(UserMeta::Teacher { teacher_id }),
+            ..
+        } => DbCheerPracticePeriod::is_teacher_cheer_staff(&data.cache, teacher_id),
+        _ => false,
+    };
+    if !is_allowed {
+        return Err(Error::InvalidPermission(
+            "Insufficient permissions to perform this action".to_string(),
+            format!("/attendance/cheer/periods/{practice_period_id}/jaturamitr"),
+        ));
+    }
+
+    let authorizer = Authorizer::new(
+        &user,
+        format!("/attendance/cheer/periods/{practice_period_id}/jaturamitr"),
+    );
+
+    let practice_date = query_scalar!(
+        "UPDATE cheer_practice_periods SET is_jaturamitr = $1 WHERE id = $2 RETURNING date",
+        request_data.is_jaturamitr,
+        practice_period_id,
+    )
+    .fetch_optional(pool)
+    .await?
+    .ok_or(Error::EntityNotFound(
+        "Practice period not found".to_string(),
+        format!("/attendance/cheer/periods/{practice_period_id}/jaturamitr"),
+    ))?;
+
+    if request_data.is_jaturamitr {
+        data.cache
+            .insert_jaturamitr_period(practice_period_id, practice_date)
+            .await;
+    } else {
+        data.cache
+            .remove_jaturamitr_period(practice_period_id)
+            .await;
+    }
+
+    let practice_period = CheerPracticePeriod::get_by_id(
+        pool,
+        practice_period_id,
+        fetch_level,
+        descendant_fetch_level,
+        &authorizer,
+    )
+    .await?;
+    let response = ResponseType::new(practice_period, None);
+
+    Ok(HttpResponse::Ok().json(response))
+}
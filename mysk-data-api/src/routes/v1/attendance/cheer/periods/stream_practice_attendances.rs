@@ -0,0 +1,96 @@
+use crate::{
+    AppState,
+    attendance_feed::CheerPracticeAttendanceEvent,
+    extractors::{api_key::ApiKeyHeader, logged_in::LoggedIn},
+};
+use actix_web::{
+    HttpResponse, Responder, get,
+    http::header::{CacheControl, CacheDirective},
+    web::{Bytes, Data, Path, Query},
+};
+use futures::stream::{self, StreamExt as _};
+use mysk_lib::{
+    models::{cheer_practice_period::db::DbCheerPracticePeriod, traits::GetById as _},
+    prelude::*,
+};
+use serde::Deserialize;
+use std::{convert::Infallible, time::Duration};
+use tokio::{sync::broadcast::error::RecvError, time::timeout};
+use utoipa::IntoParams;
+use uuid::Uuid;
+
+/// How often a comment is sent while no attendance is checked, so proxies keep the stream open.
+const KEEP_ALIVE_INTERVAL: Duration = Duration::from_secs(15);
+
+#[derive(Debug, Deserialize, IntoParams)]
+struct StreamPracticeAttendancesQuery {
+    /// Only stream the attendances of this classroom.
+    classroom_id: Option<Uuid>,
+}
+
+/// Streams the attendances of a practice period as Server-Sent Events while they are checked, by
+/// any staff member on any instance. Each `attendance` event holds a
+/// [`CheerPracticeAttendanceEvent`]. A `resync` event means some attendances were missed, and they
+/// should be fetched again.
+#[utoipa::path(
+    tag = "Attendance",
+    params(StreamPracticeAttendancesQuery),
+    responses(
+        (
+            status = OK,
+            description = "A stream of `attendance` events",
+            body = CheerPracticeAttendanceEvent,
+            content_type = "text/event-stream",
+        ),
+    ),
+)]
+#[get("/{id}/stream")]
+pub async fn stream_practice_attendances(
+    data: Data<AppState>,
+    _: ApiKeyHeader,
+    LoggedIn(user): LoggedIn,
+    practice_period_id: Path<Uuid>,
+    Query(StreamPracticeAttendancesQuery { classroom_id }): Query<StreamPracticeAttendancesQuery>,
+) -> Result<impl Responder> {
+    let practice_period_id = practice_period_id.into_inner();
+
+    if !DbCheerPracticePeriod::is_attendance_staff(&data.cache, &user, practice_period_id) {
+        return Err(Error::BrokenRule(
+            ErrorCode::CheerStreamStaffOnly,
+            format!("/attendance/cheer/periods/{practice_period_id}/stream"),
+        ));
+    }
+    let mut conn = data.db.acquire().await?;
+    DbCheerPracticePeriod::get_by_id(&mut conn, practice_period_id).await?;
+    drop(conn);
+
+    let receiver = data.attendance_feed.subscribe();
+    let events = stream::unfold(receiver, move |mut receiver| async move {
+        loop {
+            let chunk = match timeout(KEEP_ALIVE_INTERVAL, receiver.recv()).await {
+                Err(_) => Bytes::from_static(b": keep-alive\n\n"),
+                Ok(Ok(event)) => {
+                    if event.practice_period_id != practice_period_id
+                        || classroom_id.is_some_and(|id| event.classroom_id != Some(id))
+                    {
+                        continue;
+                    }
+
+                    let event = serde_json::to_string(&*event).unwrap_or_default();
+                    Bytes::from(format!("event: attendance\ndata: {event}\n\n"))
+                }
+                Ok(Err(RecvError::Lagged(_))) => Bytes::from_static(b"event: resync\ndata:\n\n"),
+                Ok(Err(RecvError::Closed)) => return None,
+            };
+
+            return Some((Ok::<_, Infallible>(chunk), receiver));
+        }
+    });
+    // Sent first so clients know the stream is open
+    let opened = stream::once(async { Ok(Bytes::from_static(b": open\n\n")) });
+
+    Ok(HttpResponse::Ok()
+        .insert_header(CacheControl(vec![CacheDirective::NoCache]))
+        .content_type("text/event-stream")
+        .streaming(opened.chain(events)))
+}
@@ -6,7 +6,7 @@ use actix_web::{HttpResponse, Responder, get, web::Data};
 use mysk_lib::{
     common::{
         requests::{EmptyRequestData, RequestType},
-        response::{MetadataType, ResponseType},
+        response::{MetadataType, ResponseListType, ResponseType},
     },
     models::cheer_practice_attendance::{
         CheerPracticeAttendance,
@@ -18,6 +18,13 @@ use mysk_lib::{
     prelude::*,
 };
 use sqlx::query_scalar;
+#[utoipa::path(
+    tag = "Attendance",
+    params(RequestType<EmptyRequestData, QueryableCheerPracticeAttendance, SortableCheerPracticeAttendance>),
+    responses(
+        (status = OK, description = "The matching attendances", body = ResponseListType<CheerPracticeAttendance>),
+    ),
+)]
 #[get("")]
 pub async fn query_cheer_practice_attendances(
     data: Data<AppState>,
@@ -37,7 +44,6 @@ pub async fn query_cheer_practice_attendances(
     >,
 ) -> Result<impl Responder> {
     let pool = &data.db;
-    let mut conn = data.db.acquire().await?;
     let authorizer = Authorizer::new(&user, "/attendance/cheer".to_string());
 
     // TODO: Using `practice_period_id` and `classroom_id` filters separately or none at all may
@@ -53,14 +59,13 @@ pub async fn query_cheer_practice_attendances(
             practice_period_id,
             classroom_id
         )
-        .fetch_one(&mut *conn)
+        .fetch_one(pool)
         .await?
         .unwrap_or(false);
 
         if !is_valid {
-            return Err(Error::InvalidRequest(
-                "Requested classroom is not a part of the current cheer practice period"
-                    .to_string(),
+            return Err(Error::BrokenRule(
+                ErrorCode::CheerClassroomNotInPeriod,
                 format!("/attendance/cheer/{practice_period_id}/{classroom_id}"),
             ));
         }
@@ -1,7 +1,16 @@
 use actix_web::web::{ServiceConfig, scope};
+use utoipa::OpenApi;
 
 pub mod cheer;
 
+#[derive(OpenApi)]
+#[openapi(
+    nest(
+        (path = "/cheer", api = cheer::ApiDoc),
+    ),
+)]
+pub struct ApiDoc;
+
 pub fn config(cfg: &mut ServiceConfig) {
     cfg.service(scope("/cheer").configure(cheer::config));
 }
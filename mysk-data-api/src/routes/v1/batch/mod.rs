@@ -0,0 +1,12 @@
+use actix_web::web::ServiceConfig;
+use utoipa::OpenApi;
+
+pub mod run_batch;
+
+#[derive(OpenApi)]
+#[openapi(paths(run_batch::run_batch))]
+pub struct ApiDoc;
+
+pub fn config(cfg: &mut ServiceConfig) {
+    cfg.service(run_batch::run_batch);
+}
@@ -0,0 +1,411 @@
+use crate::{
+    AppState, app,
+    extractors::{
+        SharedCredentials,
+        api_key::{ApiKeyHeader, ApiKeyId},
+        logged_in::{LoggedIn, UserId},
+    },
+};
+use actix_http::{Payload, Request};
+use actix_service::{
+    IntoServiceFactory as _, ServiceExt as _,
+    boxed::{self, RcService},
+};
+use actix_web::{
+    HttpMessage as _, HttpRequest, HttpResponse, Responder,
+    body::{self, BoxBody},
+    dev::{AppConfig, Service as _, ServiceFactory as _, ServiceResponse},
+    http::{
+        Method, Uri,
+        header::{self, HeaderValue},
+    },
+    post,
+    web::{Bytes, Data},
+};
+use futures::future;
+use mysk_lib::{
+    cache,
+    common::{
+        requests::{Json, RequestType},
+        response::ResponseType,
+        validation::Validate,
+    },
+    prelude::*,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use sqlx::{
+    PgPool, TransactionManager as _,
+    postgres::{PgPoolOptions, PgTransactionManager},
+};
+use std::{cell::RefCell, ptr, rc::Rc, time::Duration};
+use tokio::sync::Semaphore;
+use utoipa::ToSchema;
+
+/// The most operations a single batch can hold.
+const MAX_OPERATIONS: usize = 20;
+
+/// The most atomic batches run at once by each worker, each holding a connection of its own.
+const MAX_ATOMIC_BATCHES: usize = 2;
+
+/// How long an operation of an atomic batch waits for the connection of the batch, should its route
+/// need a second one at once.
+const ATOMIC_ACQUIRE_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// The headers of the batch passed on to each of its operations.
+const FORWARDED_HEADERS: [header::HeaderName; 4] = [
+    header::ACCEPT_LANGUAGE,
+    header::AUTHORIZATION,
+    header::COOKIE,
+    header::HeaderName::from_static("x-api-key"),
+];
+
+type Routes = RcService<Request, HttpResponse, actix_web::Error>;
+
+thread_local! {
+    /// The routes built for batches on this worker, along with the state they were built with.
+    static ROUTES: RefCell<Option<(Data<AppState>, Routes)>> = const { RefCell::new(None) };
+
+    /// The lanes of this worker which no atomic batch is running on, along with the state they were
+    /// built with.
+    static ATOMIC_LANES: RefCell<Option<(Data<AppState>, Vec<AtomicLane>)>> =
+        const { RefCell::new(None) };
+
+    /// Limits how many atomic batches this worker runs at once.
+    static ATOMIC_PERMITS: Rc<Semaphore> = Rc::new(Semaphore::new(MAX_ATOMIC_BATCHES));
+}
+
+/// A connection for atomic batches, with the routes built to run on it, kept by a worker to be
+/// reused by its next atomic batches.
+struct AtomicLane {
+    pool: PgPool,
+    routes: Routes,
+}
+
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "UPPERCASE")]
+enum BatchMethod {
+    Get,
+    Post,
+    Put,
+    Patch,
+    Delete,
+}
+
+impl From<BatchMethod> for Method {
+    fn from(method: BatchMethod) -> Self {
+        match method {
+            BatchMethod::Get => Method::GET,
+            BatchMethod::Post => Method::POST,
+            BatchMethod::Put => Method::PUT,
+            BatchMethod::Patch => Method::PATCH,
+            BatchMethod::Delete => Method::DELETE,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+struct BatchOperation {
+    method: BatchMethod,
+    /// The path of the route from the root of the API, with its query string, such as
+    /// `/v1/clubs?fetch_level=compact`.
+    path: String,
+    /// The JSON body of the request, if the route takes one.
+    body: Option<Value>,
+}
+
+#[derive(Debug, Deserialize, ToSchema, Validate)]
+struct BatchRequest {
+    operations: Vec<BatchOperation>,
+    /// Runs the operations in order in a single transaction, which is rolled back if one of them
+    /// fails. The operations after it aren't run.
+    #[serde(default)]
+    atomic: bool,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+struct BatchResult {
+    /// The status code the route responded with.
+    status: u16,
+    /// The body the route responded with, as a string if it isn't JSON.
+    body: Value,
+}
+
+/// Runs several operations in one request, each through the same routes as if it were requested on
+/// its own. The credentials of the batch are checked once and shared by every operation. Reads are
+/// run concurrently; a batch holding any write is run in order instead, each operation in its own
+/// transaction unless the batch is atomic.
+///
+/// An atomic batch runs in order on a connection of its own, in a single transaction committed once
+/// every operation has succeeded. Otherwise, it is rolled back and the results end with the
+/// operation which failed. Only a few atomic batches are run at once, the others waiting for their
+/// turn.
+#[utoipa::path(
+    tag = "Batch",
+    responses(
+        (status = OK, description = "The result of each operation, in order", body = ResponseType<Vec<BatchResult>>),
+    ),
+)]
+#[post("")]
+pub async fn run_batch(
+    data: Data<AppState>,
+    req: HttpRequest,
+    ApiKeyHeader(api_key): ApiKeyHeader,
+    user: Option<LoggedIn>,
+    Json(RequestType {
+        data: BatchRequest { operations, atomic },
+        ..
+    }): Json<RequestType<BatchRequest>>,
+) -> Result<impl Responder> {
+    if req.extensions().contains::<SharedCredentials>() {
+        return Err(Error::InvalidRequest(
+            "Batches cannot be nested".to_string(),
+            "/batch".to_string(),
+        ));
+    }
+    if operations.len() > MAX_OPERATIONS {
+        return Err(Error::InvalidRequest(
+            format!("A batch can hold at most {MAX_OPERATIONS} operations"),
+            "/batch".to_string(),
+        ));
+    }
+
+    let credentials = SharedCredentials {
+        api_key,
+        user: user.map(|LoggedIn(user)| user),
+    };
+    let is_read_only = operations
+        .iter()
+        .all(|operation| operation.method == BatchMethod::Get);
+    let requests = operations
+        .iter()
+        .map(|operation| build_request(&req, operation, &credentials))
+        .collect::<Result<Vec<_>>>()?;
+
+    if atomic {
+        let results = run_atomic(&data, requests).await?;
+
+        return Ok(HttpResponse::Ok().json(ResponseType::new(results, None)));
+    }
+
+    let routes = routes(&data).await?;
+    let results = if is_read_only {
+        future::try_join_all(requests.into_iter().map(|request| call(&routes, request))).await?
+    } else {
+        let mut results = Vec::with_capacity(requests.len());
+        for request in requests {
+            results.push(call(&routes, request).await?);
+        }
+
+        results
+    };
+
+    Ok(HttpResponse::Ok().json(ResponseType::new(results, None)))
+}
+
+/// Runs the operations in order in a transaction on a connection of their own. The transactions
+/// begun by the routes are nested in it as savepoints.
+async fn run_atomic(data: &Data<AppState>, requests: Vec<Request>) -> Result<Vec<BatchResult>> {
+    let permits = ATOMIC_PERMITS.with(Rc::clone);
+    let _permit = permits.acquire().await.map_err(|_| {
+        Error::InternalServerError(
+            "Failed to wait for an atomic batch to finish".to_string(),
+            "/batch".to_string(),
+        )
+    })?;
+    let lane = match take_atomic_lane(data) {
+        Some(lane) => lane,
+        None => AtomicLane::new(data).await?,
+    };
+
+    // The connection goes back to the pool with the transaction still open. Should the batch stop
+    // before it is committed or rolled back, the lane is dropped, closing the connection.
+    PgTransactionManager::begin(&mut *lane.pool.acquire().await?, None).await?;
+    // Cache writes depend on the transaction, so they wait for it to commit
+    let (results, writes) = cache::hold_writes(call_in_order(&lane.routes, requests)).await;
+    let results = results?;
+    let has_failed = results.last().is_some_and(|result| result.status >= 400);
+
+    let mut conn = lane.pool.acquire().await?;
+    if has_failed {
+        PgTransactionManager::rollback(&mut conn).await?;
+    } else {
+        PgTransactionManager::commit(&mut conn).await?;
+    }
+    drop(conn);
+    if !has_failed {
+        writes.apply().await;
+    }
+    return_atomic_lane(data, lane);
+
+    Ok(results)
+}
+
+/// Runs the operations in order, stopping after the first which fails.
+async fn call_in_order(routes: &Routes, requests: Vec<Request>) -> Result<Vec<BatchResult>> {
+    let mut results = Vec::with_capacity(requests.len());
+    for request in requests {
+        let result = call(routes, request).await?;
+        let has_failed = result.status >= 400;
+        results.push(result);
+        if has_failed {
+            break;
+        }
+    }
+
+    Ok(results)
+}
+
+/// Runs an operation, reading its response as a result.
+async fn call(routes: &Routes, request: Request) -> Result<BatchResult> {
+    let response = routes
+        .call(request)
+        .await
+        .unwrap_or_else(|err| err.error_response());
+
+    read_result(response).await
+}
+
+/// Gets the application which operations are run through, building it on the first batch handled by
+/// this worker.
+async fn routes(data: &Data<AppState>) -> Result<Routes> {
+    let cached = ROUTES.with_borrow(|cached| {
+        cached
+            .as_ref()
+            .filter(|(state, _)| ptr::eq(state.get_ref(), data.get_ref()))
+            .map(|(_, routes)| Rc::clone(routes))
+    });
+    if let Some(routes) = cached {
+        return Ok(routes);
+    }
+
+    let routes = build_routes(data.clone()).await?;
+    ROUTES.set(Some((data.clone(), Rc::clone(&routes))));
+
+    Ok(routes)
+}
+
+impl AtomicLane {
+    /// Builds a lane, connecting to the database once it is first used.
+    async fn new(data: &Data<AppState>) -> Result<Self> {
+        let pool = PgPoolOptions::new()
+            .max_connections(1)
+            .acquire_timeout(ATOMIC_ACQUIRE_TIMEOUT)
+            .connect_lazy_with((*data.db.connect_options()).clone());
+        let routes = build_routes(Data::new(data.with_db(pool.clone()))).await?;
+
+        Ok(Self { pool, routes })
+    }
+}
+
+/// Takes an idle lane of this worker built with the same state, if any.
+fn take_atomic_lane(data: &Data<AppState>) -> Option<AtomicLane> {
+    ATOMIC_LANES.with_borrow_mut(|lanes| {
+        lanes
+            .as_mut()
+            .filter(|(state, _)| ptr::eq(state.get_ref(), data.get_ref()))
+            .and_then(|(_, idle)| idle.pop())
+    })
+}
+
+/// Keeps a lane for the next atomic batches of this worker, replacing those built with another
+/// state.
+fn return_atomic_lane(data: &Data<AppState>, lane: AtomicLane) {
+    ATOMIC_LANES.with_borrow_mut(|lanes| match lanes {
+        Some((state, idle)) if ptr::eq(state.get_ref(), data.get_ref()) => idle.push(lane),
+        _ => *lanes = Some((data.clone(), vec![lane])),
+    });
+}
+
+/// Builds the application with the same middlewares as the server.
+async fn build_routes(data: Data<AppState>) -> Result<Routes> {
+    let routes = app(data)
+        .into_factory()
+        .new_service(AppConfig::default())
+        .await
+        .map_err(|()| {
+            Error::InternalServerError(
+                "Failed to initialise the routes".to_string(),
+                "/batch".to_string(),
+            )
+        })?;
+
+    Ok(boxed::rc_service(routes.map(
+        |response: ServiceResponse<_>| response.into_parts().1.map_into_boxed_body(),
+    )))
+}
+
+fn build_request(
+    req: &HttpRequest,
+    operation: &BatchOperation,
+    credentials: &SharedCredentials,
+) -> Result<Request> {
+    let Ok(uri) = operation.path.parse::<Uri>() else {
+        return Err(Error::InvalidRequest(
+            format!("Invalid operation path {}", operation.path),
+            "/batch".to_string(),
+        ));
+    };
+    if !uri.path().starts_with('/') || uri.host().is_some() {
+        return Err(Error::InvalidRequest(
+            format!("Operation path {} must start with /", operation.path),
+            "/batch".to_string(),
+        ));
+    }
+
+    let body = match &operation.body {
+        Some(body) => Bytes::from(serde_json::to_vec(body).unwrap_or_default()),
+        None => Bytes::new(),
+    };
+    let mut request = Request::with_payload(Payload::from(body));
+    let head = request.head_mut();
+    head.method = operation.method.into();
+    head.uri = uri;
+    for name in FORWARDED_HEADERS {
+        if let Some(value) = req.headers().get(&name) {
+            head.headers.insert(name, value.clone());
+        }
+    }
+    if operation.body.is_some() {
+        head.headers.insert(
+            header::CONTENT_TYPE,
+            HeaderValue::from_static("application/json"),
+        );
+    }
+
+    let mut extensions = request.extensions_mut();
+    extensions.insert(credentials.clone());
+    extensions.insert(ApiKeyId(credentials.api_key.id));
+    if let Some(user) = &credentials.user {
+        extensions.insert(UserId(user.id));
+    }
+    drop(extensions);
+
+    Ok(request)
+}
+
+async fn read_result(response: HttpResponse<BoxBody>) -> Result<BatchResult> {
+    let status = response.status().as_u16();
+    let is_json = response
+        .headers()
+        .get(header::CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .is_some_and(|value| value.starts_with("application/json"));
+    let Ok(bytes) = body::to_bytes(response.into_body()).await else {
+        return Err(Error::InternalServerError(
+            "Failed to read the response of an operation".to_string(),
+            "/batch".to_string(),
+        ));
+    };
+
+    let body = if bytes.is_empty() {
+        Value::Null
+    } else if is_json {
+        serde_json::from_slice(&bytes)
+            .unwrap_or_else(|_| Value::String(String::from_utf8_lossy(&bytes).into_owned()))
+    } else {
+        Value::String(String::from_utf8_lossy(&bytes).into_owned())
+    };
+
+    Ok(BatchResult { status, body })
+}
@@ -1,7 +1,16 @@
 use actix_web::web::{ServiceConfig, scope};
+use utoipa::OpenApi;
 
 pub mod rsvp;
 
+#[derive(OpenApi)]
+#[openapi(
+    nest(
+        (path = "/rsvp", api = rsvp::ApiDoc),
+    ),
+)]
+pub struct ApiDoc;
+
 pub fn config(cfg: &mut ServiceConfig) {
     cfg.service(scope("/rsvp").configure(rsvp::config));
 }
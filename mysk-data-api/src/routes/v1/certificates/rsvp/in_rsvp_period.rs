@@ -7,6 +7,12 @@ use mysk_lib::{
     common::response::ResponseType, models::certificate::db::DbCertificate, prelude::*,
 };
 
+#[utoipa::path(
+    tag = "Certificates",
+    responses(
+        (status = OK, description = "Whether RSVPs are open", body = ResponseType<bool>),
+    ),
+)]
 #[get("/in-rsvp-period")]
 pub async fn in_rsvp_period(
     data: Data<AppState>,
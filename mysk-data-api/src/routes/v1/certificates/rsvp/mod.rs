@@ -1,9 +1,18 @@
 use actix_web::web::ServiceConfig;
+use utoipa::OpenApi;
 
 pub mod in_rsvp_period;
 pub mod modify_invitation;
 pub mod query_invitation_details;
 
+#[derive(OpenApi)]
+#[openapi(paths(
+    in_rsvp_period::in_rsvp_period,
+    modify_invitation::modify_invitation,
+    query_invitation_details::query_invitation_details,
+))]
+pub struct ApiDoc;
+
 pub fn config(cfg: &mut ServiceConfig) {
     cfg.service(in_rsvp_period::in_rsvp_period)
         .service(modify_invitation::modify_invitation)
@@ -2,24 +2,34 @@ use crate::{
     AppState,
     extractors::{api_key::ApiKeyHeader, student::LoggedInStudent},
 };
-use actix_web::{
-    HttpResponse, Responder, put,
-    web::{Data, Json},
-};
+use actix_web::{HttpResponse, Responder, put, web::Data};
 use mysk_lib::{
-    common::{requests::RequestType, response::ResponseType},
+    common::{
+        requests::{Json, RequestType},
+        response::ResponseType,
+        validation::Validate,
+    },
     helpers::date::get_current_academic_year,
     models::{certificate::db::DbCertificate, enums::SubmissionStatus},
     prelude::*,
+    webhooks::{self, WebhookEvent},
 };
 use serde::Deserialize;
+use serde_json::json;
 use sqlx::query;
+use utoipa::ToSchema;
 
-#[derive(Deserialize)]
+#[derive(Deserialize, ToSchema, Validate)]
 struct ModifyInvitationRequest {
     rsvp_status: SubmissionStatus,
 }
 
+#[utoipa::path(
+    tag = "Certificates",
+    responses(
+        (status = OK, description = "The RSVP was recorded", body = ResponseType<bool>),
+    ),
+)]
 #[put("")]
 pub async fn modify_invitation(
     data: Data<AppState>,
@@ -41,21 +51,33 @@ pub async fn modify_invitation(
 
     // Checks if the current time is within the rsvp period
     if !DbCertificate::is_rsvp_period(&mut transaction).await? {
-        return Err(Error::InvalidPermission(
-            "The certificate ceremony RSVP period has ended".to_string(),
+        return Err(Error::BrokenRule(
+            ErrorCode::RsvpClosed,
             format!("/certificates/rsvp/{student_id}"),
         ));
     }
 
+    let year = get_current_academic_year(None);
     query!(
         "UPDATE student_certificates SET rsvp_status = $1 WHERE student_id = $2 AND year = $3",
         rsvp_status as SubmissionStatus,
         student_id,
-        get_current_academic_year(None),
+        year,
     )
     .execute(&mut *transaction)
     .await?;
 
+    webhooks::enqueue(
+        &mut transaction,
+        WebhookEvent::RsvpChanged,
+        json!({
+            "student_id": student_id,
+            "year": year,
+            "rsvp_status": rsvp_status,
+        }),
+    )
+    .await?;
+
     transaction.commit().await?;
 
     // TODO: Data models
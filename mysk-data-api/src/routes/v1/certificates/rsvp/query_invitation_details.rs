@@ -7,10 +7,18 @@ use actix_web::{
     web::{Data, Path},
 };
 use mysk_lib::{
-    common::response::ResponseType, models::certificate::db::DbCertificate, prelude::*,
+    common::response::ResponseType,
+    models::{certificate::db::DbCertificate, enums::SubmissionStatus},
+    prelude::*,
 };
 use uuid::Uuid;
 
+#[utoipa::path(
+    tag = "Certificates",
+    responses(
+        (status = OK, description = "The student's RSVP, if any", body = ResponseType<Option<SubmissionStatus>>),
+    ),
+)]
 #[get("/{id}")]
 pub async fn query_invitation_details(
     data: Data<AppState>,
@@ -4,12 +4,13 @@ use crate::{
 };
 use actix_web::{
     HttpResponse, Responder, post,
-    web::{Data, Json, Path},
+    web::{Data, Path},
 };
 use mysk_lib::{
     common::{
-        requests::{FetchLevel, RequestType},
+        requests::{FetchLevel, Json, RequestType},
         response::ResponseType,
+        validation::Validate,
     },
     helpers::date::get_current_academic_year,
     models::{club::Club, club_request::ClubRequest, enums::SubmissionStatus, student::Student},
@@ -18,14 +19,21 @@ use mysk_lib::{
 };
 use serde::Deserialize;
 use sqlx::query;
+use utoipa::ToSchema;
 use uuid::Uuid;
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, ToSchema, Validate)]
 struct AddClubMemberRequest {
     id: Uuid,
 }
 
 #[allow(clippy::similar_names, clippy::too_many_lines)]
+#[utoipa::path(
+    tag = "Clubs",
+    responses(
+        (status = OK, description = "The invitation sent to the new member", body = ResponseType<ClubRequest>),
+    ),
+)]
 #[post("/{id}/add")]
 pub async fn add_club_members(
     data: Data<AppState>,
@@ -41,7 +49,6 @@ pub async fn add_club_members(
     }): Json<RequestType<AddClubMemberRequest>>,
 ) -> Result<impl Responder> {
     let pool = &data.db;
-    let mut conn = data.db.acquire().await?;
     let club_id = club_id.into_inner();
     let invitee_student_id = request_data.id;
     let authorizer = Authorizer::new(&user, format!("/clubs/{club_id}/add"));
@@ -99,8 +106,8 @@ pub async fn add_club_members(
         Student::IdOnly(staff, _) => staff.id == inviter_student_id,
         _ => unreachable!("Staff should always be an IdOnly variant"),
     }) {
-        return Err(Error::InvalidPermission(
-            "Student must be a staff of the club to add club members".to_string(),
+        return Err(Error::BrokenRule(
+            ErrorCode::ClubStaffOnly,
             format!("/clubs/{club_id}/add"),
         ));
     }
@@ -110,8 +117,8 @@ pub async fn add_club_members(
         Student::IdOnly(staff, _) => staff.id == invitee_student_id,
         _ => unreachable!("Staff should always be an IdOnly variant"),
     }) {
-        return Err(Error::InvalidPermission(
-            "Invitee student is already a staff member of the club".to_string(),
+        return Err(Error::BrokenRule(
+            ErrorCode::ClubInviteeAlreadyStaff,
             format!("/clubs/{club_id}/add"),
         ));
     }
@@ -129,7 +136,7 @@ pub async fn add_club_members(
         SubmissionStatus::Approved as SubmissionStatus,
         invitee_student_id
     )
-    .fetch_one(&mut *conn)
+    .fetch_one(pool)
     .await?
     .id;
 
@@ -4,12 +4,13 @@ use crate::{
 };
 use actix_web::{
     HttpResponse, Responder, post,
-    web::{Data, Json, Path},
+    web::{Data, Path},
 };
 use mysk_lib::{
     common::{
-        requests::{FetchLevel, RequestType},
+        requests::{FetchLevel, Json, RequestType},
         response::ResponseType,
+        validation::Validate,
     },
     models::{club::db::DbClub, contact::Contact, enums::ContactType, traits::GetById as _},
     permissions::Authorizer,
@@ -17,14 +18,22 @@ use mysk_lib::{
 };
 use serde::Deserialize;
 use sqlx::query;
+use utoipa::ToSchema;
 use uuid::Uuid;
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, ToSchema, Validate)]
 struct ClubContactRequest {
     r#type: ContactType,
+    #[validate(contact_type = r#type)]
     value: String,
 }
 
+#[utoipa::path(
+    tag = "Clubs",
+    responses(
+        (status = OK, description = "The new contact", body = ResponseType<Contact>),
+    ),
+)]
 #[post("/{id}/contacts")]
 pub async fn create_club_contacts(
     data: Data<AppState>,
@@ -56,9 +65,11 @@ pub async fn create_club_contacts(
     }
 
     // Check if the contact is a duplicate
+    let club_contact_ids = DbClub::get_club_contacts(&mut conn, club_id).await?;
+    drop(conn);
     let club_contacts = Contact::get_by_ids(
         pool,
-        &DbClub::get_club_contacts(&mut conn, club_id).await?,
+        &club_contact_ids,
         FetchLevel::Default,
         FetchLevel::IdOnly,
         &authorizer,
@@ -68,8 +79,8 @@ pub async fn create_club_contacts(
         Contact::Default(contact, _) => contact.value == club_contact.value,
         _ => unreachable!("Contact::get_by_ids should always return a Default variant"),
     }) {
-        return Err(Error::InvalidRequest(
-            "Contact with the same value already exists".to_string(),
+        return Err(Error::BrokenRule(
+            ErrorCode::ContactExists,
             format!("/clubs/{club_id}/contacts"),
         ));
     }
@@ -5,8 +5,9 @@ use mysk_lib::{
 };
 use serde::Serialize;
 use sqlx::query;
+use utoipa::ToSchema;
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, ToSchema)]
 struct ClubStatistics {
     pub club_members: i64,
     pub club_staffs: i64,
@@ -15,6 +16,13 @@ struct ClubStatistics {
     pub total_students: i64,
 }
 
+#[utoipa::path(
+    tag = "Clubs",
+    responses(
+        (status = OK, description = "This academic year's club statistics", body = ResponseType<ClubStatistics>),
+    ),
+    security(("api_key" = [])),
+)]
 #[get("/statistics")]
 pub async fn get_club_statistics(data: Data<AppState>, _: ApiKeyHeader) -> Result<impl Responder> {
     let mut conn = data.db.acquire().await?;
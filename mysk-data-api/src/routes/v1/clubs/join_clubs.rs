@@ -1,6 +1,7 @@
 use crate::{
     AppState,
     extractors::{api_key::ApiKeyHeader, logged_in::LoggedIn, student::LoggedInStudent},
+    middlewares::idempotency::IdempotencyKeyHeader,
 };
 use actix_web::{
     HttpResponse, Responder, post,
@@ -15,10 +16,19 @@ use mysk_lib::{
     models::{club::Club, club_request::ClubRequest, enums::SubmissionStatus, student::Student},
     permissions::Authorizer,
     prelude::*,
+    webhooks::{self, WebhookEvent},
 };
+use serde_json::json;
 use sqlx::query;
 use uuid::Uuid;
 
+#[utoipa::path(
+    tag = "Clubs",
+    params(RequestType, IdempotencyKeyHeader),
+    responses(
+        (status = OK, description = "The request to join the club", body = ResponseType<ClubRequest>),
+    ),
+)]
 #[post("/{id}/join")]
 pub async fn join_clubs(
     data: Data<AppState>,
@@ -33,7 +43,6 @@ pub async fn join_clubs(
     }: RequestType,
 ) -> Result<impl Responder> {
     let pool = &data.db;
-    let mut conn = data.db.acquire().await?;
     let club_id = club_id.into_inner();
     let current_year = get_current_academic_year(None);
     let authorizer = Authorizer::new(&user, format!("/clubs/{club_id}/join"));
@@ -56,8 +65,8 @@ pub async fn join_clubs(
         Student::IdOnly(staff, _) => staff.id == student_id,
         _ => unreachable!("Staff should always be an IdOnly variant"),
     }) {
-        return Err(Error::InvalidPermission(
-            "Student is already a staff member of the club".to_string(),
+        return Err(Error::BrokenRule(
+            ErrorCode::ClubAlreadyStaff,
             format!("/clubs/{club_id}/join"),
         ));
     }
@@ -67,12 +76,14 @@ pub async fn join_clubs(
         Student::IdOnly(member, _) => member.id == student_id,
         _ => unreachable!("Staff should always be an IdOnly variant"),
     }) {
-        return Err(Error::InvalidPermission(
-            "Student is already a member of the club".to_string(),
+        return Err(Error::BrokenRule(
+            ErrorCode::ClubAlreadyMember,
             format!("/clubs/{club_id}/join"),
         ));
     }
 
+    let mut transaction = data.db.begin().await?;
+
     // Check if student has exceeded their quota
     if let Some(eligibility) = query!(
         "\
@@ -82,12 +93,12 @@ pub async fn join_clubs(
         student_id,
         current_year,
     )
-    .fetch_optional(&mut *conn)
+    .fetch_optional(&mut *transaction)
     .await?
         && eligibility.club_count >= eligibility.max_clubs
     {
-        return Err(Error::InvalidPermission(
-            "Student has reached the maximum number of clubs allowed".to_string(),
+        return Err(Error::BrokenRule(
+            ErrorCode::ClubQuotaExceeded,
             format!("/clubs/{club_id}/join"),
         ));
     }
@@ -102,10 +113,24 @@ pub async fn join_clubs(
         SubmissionStatus::Approved as SubmissionStatus,
         student_id,
     )
-    .fetch_one(&mut *conn)
+    .fetch_one(&mut *transaction)
     .await?
     .id;
 
+    webhooks::enqueue(
+        &mut transaction,
+        WebhookEvent::ClubRequestCreated,
+        json!({
+            "club_request_id": club_member_id,
+            "club_id": club_id,
+            "student_id": student_id,
+            "year": current_year,
+            "membership_status": SubmissionStatus::Approved,
+        }),
+    )
+    .await?;
+    transaction.commit().await?;
+
     let club_request_id = ClubRequest::get_by_id(
         pool,
         club_member_id,
@@ -1,4 +1,6 @@
 use actix_web::web::{ServiceConfig, scope};
+use mysk_lib::models::club::request::{queryable::QueryableClub, sortable::SortableClub};
+use utoipa::OpenApi;
 
 pub mod add_club_members;
 pub mod create_club_contacts;
@@ -8,6 +10,23 @@ pub mod query_club_details;
 pub mod query_clubs;
 pub mod requests;
 
+#[derive(OpenApi)]
+#[openapi(
+    paths(
+        get_club_statistics::get_club_statistics,
+        add_club_members::add_club_members,
+        join_clubs::join_clubs,
+        create_club_contacts::create_club_contacts,
+        query_club_details::query_club_details,
+        query_clubs::query_clubs,
+    ),
+    nest(
+        (path = "/requests", api = requests::ApiDoc),
+    ),
+    components(schemas(QueryableClub, SortableClub)),
+)]
+pub struct ApiDoc;
+
 pub fn config(cfg: &mut ServiceConfig) {
     cfg.service(scope("/requests").configure(requests::config))
         .service(get_club_statistics::get_club_statistics)
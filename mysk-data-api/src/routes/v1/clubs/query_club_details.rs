@@ -11,6 +11,14 @@ use mysk_lib::{
 };
 use uuid::Uuid;
 
+#[utoipa::path(
+    tag = "Clubs",
+    params(RequestType),
+    responses(
+        (status = OK, description = "The club", body = ResponseType<Club>),
+    ),
+    security(("api_key" = [])),
+)]
 #[get("/{id}")]
 pub async fn query_club_details(
     data: Data<AppState>,
@@ -6,7 +6,7 @@ use actix_web::{HttpResponse, Responder, get, web::Data};
 use mysk_lib::{
     common::{
         requests::{EmptyRequestData, RequestType},
-        response::{MetadataType, ResponseType},
+        response::{MetadataType, ResponseListType, ResponseType},
     },
     models::club::{
         Club,
@@ -16,6 +16,13 @@ use mysk_lib::{
     prelude::*,
 };
 
+#[utoipa::path(
+    tag = "Clubs",
+    params(RequestType<EmptyRequestData, QueryableClub, SortableClub>),
+    responses(
+        (status = OK, description = "The matching clubs", body = ResponseListType<Club>),
+    ),
+)]
 #[get("")]
 pub async fn query_clubs(
     data: Data<AppState>,
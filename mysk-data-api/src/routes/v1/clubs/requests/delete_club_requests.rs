@@ -18,6 +18,12 @@ use mysk_lib::{
 use sqlx::query;
 use uuid::Uuid;
 
+#[utoipa::path(
+    tag = "Clubs",
+    responses(
+        (status = OK, description = "The request was deleted", body = ResponseType<EmptyResponseData>),
+    ),
+)]
 #[delete("/{id}")]
 pub async fn delete_club_requests(
     data: Data<AppState>,
@@ -27,7 +33,6 @@ pub async fn delete_club_requests(
     club_request_id: Path<Uuid>,
 ) -> Result<impl Responder> {
     let pool = &data.db;
-    let mut conn = data.db.acquire().await?;
     let club_request_id = club_request_id.into_inner();
     let authorizer = Authorizer::new(&user, format!("/clubs/requests/{club_request_id}"));
 
@@ -51,14 +56,14 @@ pub async fn delete_club_requests(
             _ => unreachable!("Student should always be an IdOnly variant"),
         }
     {
-        return Err(Error::InvalidPermission(
-            "Student is not allowed to interact with this club request".to_string(),
+        return Err(Error::BrokenRule(
+            ErrorCode::ClubRequestNotOwned,
             format!("/clubs/requests/{club_request_id}"),
         ));
     }
 
     query!("DELETE FROM club_members WHERE id = $1", club_request_id,)
-        .execute(&mut *conn)
+        .execute(pool)
         .await?;
 
     let response = ResponseType::new(EmptyResponseData {}, None);
@@ -1,9 +1,24 @@
 use actix_web::web::ServiceConfig;
+use mysk_lib::models::club_request::request::{
+    queryable::QueryableClubRequest, sortable::SortableClubRequest,
+};
+use utoipa::OpenApi;
 
 pub mod delete_club_requests;
 pub mod query_club_request_details;
 pub mod query_club_requests;
 
+#[derive(OpenApi)]
+#[openapi(
+    paths(
+        delete_club_requests::delete_club_requests,
+        query_club_request_details::query_club_request_details,
+        query_club_requests::query_club_requests,
+    ),
+    components(schemas(QueryableClubRequest, SortableClubRequest))
+)]
+pub struct ApiDoc;
+
 pub fn config(cfg: &mut ServiceConfig) {
     cfg.service(delete_club_requests::delete_club_requests)
         .service(query_club_request_details::query_club_request_details)
@@ -14,6 +14,13 @@ use mysk_lib::{
 };
 use uuid::Uuid;
 
+#[utoipa::path(
+    tag = "Clubs",
+    params(RequestType),
+    responses(
+        (status = OK, description = "The club request", body = ResponseType<ClubRequest>),
+    ),
+)]
 #[get("/{id}")]
 pub async fn query_club_request_details(
     data: Data<AppState>,
@@ -6,7 +6,7 @@ use actix_web::{HttpResponse, Responder, get, web::Data};
 use mysk_lib::{
     common::{
         requests::{EmptyRequestData, RequestType},
-        response::{MetadataType, ResponseType},
+        response::{MetadataType, ResponseListType, ResponseType},
     },
     models::club_request::{
         ClubRequest,
@@ -16,6 +16,13 @@ use mysk_lib::{
     prelude::*,
 };
 
+#[utoipa::path(
+    tag = "Clubs",
+    params(RequestType<EmptyRequestData, QueryableClubRequest, SortableClubRequest>),
+    responses(
+        (status = OK, description = "The matching club requests", body = ResponseListType<ClubRequest>),
+    ),
+)]
 #[get("")]
 pub async fn query_club_requests(
     data: Data<AppState>,
@@ -2,14 +2,11 @@ use crate::{
     AppState,
     extractors::{api_key::ApiKeyHeader, logged_in::LoggedIn},
 };
-use actix_web::{
-    HttpResponse, Responder, delete,
-    web::{Data, Json},
-};
+use actix_web::{HttpResponse, Responder, delete, web::Data};
 use futures::future;
 use mysk_lib::{
     common::{
-        requests::RequestType,
+        requests::{Json, RequestType},
         response::{EmptyResponseData, ResponseType},
     },
     models::{contact::db::DbContact, traits::GetById as _},
@@ -19,6 +16,12 @@ use mysk_lib::{
 use sqlx::query;
 use uuid::Uuid;
 
+#[utoipa::path(
+    tag = "Contacts",
+    responses(
+        (status = OK, description = "The contacts were deleted", body = ResponseType<EmptyResponseData>),
+    ),
+)]
 #[delete("")]
 pub async fn delete_contacts(
     data: Data<AppState>,
@@ -29,7 +32,6 @@ pub async fn delete_contacts(
     }): Json<RequestType<Vec<Uuid>>>,
 ) -> Result<impl Responder> {
     let pool = &data.db;
-    let mut conn = data.db.acquire().await?;
     let authorizer = Authorizer::new(&user, "/contacts".to_string());
 
     // Check if the contacts exists
@@ -50,8 +52,9 @@ pub async fn delete_contacts(
     future::try_join_all(futures).await?;
 
     query!("DELETE FROM contacts WHERE id = ANY($1)", &contact_ids[..])
-        .execute(&mut *conn)
+        .execute(pool)
         .await?;
+    DbContact::evict_cached(&contact_ids).await;
 
     let response = ResponseType::new(EmptyResponseData {}, None);
 
@@ -1,10 +1,24 @@
 use actix_web::web::ServiceConfig;
+use mysk_lib::models::contact::request::{queryable::QueryableContact, sortable::SortableContact};
+use utoipa::OpenApi;
 
 pub mod delete_contacts;
 pub mod modify_contacts;
 pub mod query_contact_details;
 pub mod query_contacts;
 
+#[derive(OpenApi)]
+#[openapi(
+    paths(
+        delete_contacts::delete_contacts,
+        modify_contacts::modify_contacts,
+        query_contact_details::query_contact_details,
+        query_contacts::query_contacts,
+    ),
+    components(schemas(QueryableContact, SortableContact))
+)]
+pub struct ApiDoc;
+
 pub fn config(cfg: &mut ServiceConfig) {
     cfg.service(delete_contacts::delete_contacts)
         .service(modify_contacts::modify_contacts)
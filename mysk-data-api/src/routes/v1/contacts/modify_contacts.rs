@@ -4,10 +4,16 @@ use crate::{
 };
 use actix_web::{
     HttpResponse, Responder, put,
-    web::{Data, Json, Path},
+    web::{Data, Path},
 };
 use mysk_lib::{
-    common::{requests::RequestType, response::ResponseType, string::FlexibleMultiLangString},
+    audit::{AuditContext, AuditEntity, AuditSnapshot},
+    common::{
+        requests::{Json, RequestType},
+        response::ResponseType,
+        string::FlexibleMultiLangString,
+        validation::Validate,
+    },
     models::{
         contact::{Contact, db::DbContact},
         enums::ContactType,
@@ -18,15 +24,23 @@ use mysk_lib::{
     query::{QueryParam, SqlSetClause},
 };
 use serde::Deserialize;
+use utoipa::ToSchema;
 use uuid::Uuid;
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, ToSchema, Validate)]
 struct ModifyContactsRequest {
     pub name: Option<FlexibleMultiLangString>,
     pub r#type: Option<ContactType>,
+    #[validate(contact_type = r#type)]
     pub value: Option<String>,
 }
 
+#[utoipa::path(
+    tag = "Contacts",
+    responses(
+        (status = OK, description = "The updated contact", body = ResponseType<Contact>),
+    ),
+)]
 #[put("/{id}")]
 pub async fn modify_contacts(
     data: Data<AppState>,
@@ -51,6 +65,11 @@ pub async fn modify_contacts(
     authorizer
         .authorize_contact(&db_contact, &mut conn, ActionType::Update)
         .await?;
+    drop(conn);
+
+    let mut transaction = pool.begin().await?;
+    let snapshot =
+        AuditSnapshot::take(&mut transaction, AuditEntity::Contact, &[contact_id]).await?;
 
     let mut qb = SqlSetClause::new();
     qb.push_multilang_update_field("name", contact.name)
@@ -61,8 +80,14 @@ pub async fn modify_contacts(
     qb.push(" WHERE id = ")
         .push_bind(contact_id)
         .build()
-        .execute(&mut *conn)
+        .execute(&mut *transaction)
+        .await?;
+
+    AuditContext::new(&user, "PUT /v1/contacts/{id}")
+        .record(&mut transaction, snapshot)
         .await?;
+    transaction.commit().await?;
+    DbContact::evict_cached(&[contact_id]).await;
 
     let updated_contact = Contact::get_by_id(
         pool,
@@ -14,6 +14,13 @@ use mysk_lib::{
 };
 use uuid::Uuid;
 
+#[utoipa::path(
+    tag = "Contacts",
+    params(RequestType),
+    responses(
+        (status = OK, description = "The contact", body = ResponseType<Contact>),
+    ),
+)]
 #[get("/{id}")]
 pub async fn query_contact_details(
     data: Data<AppState>,
@@ -6,7 +6,7 @@ use actix_web::{HttpResponse, Responder, get, web::Data};
 use mysk_lib::{
     common::{
         requests::{EmptyRequestData, RequestType},
-        response::{MetadataType, ResponseType},
+        response::{MetadataType, ResponseListType, ResponseType},
     },
     models::contact::{
         Contact,
@@ -16,6 +16,13 @@ use mysk_lib::{
     prelude::*,
 };
 
+#[utoipa::path(
+    tag = "Contacts",
+    params(RequestType<EmptyRequestData, QueryableContact, SortableContact>),
+    responses(
+        (status = OK, description = "The matching contacts", body = ResponseListType<Contact>),
+    ),
+)]
 #[get("")]
 pub async fn query_contacts(
     data: Data<AppState>,
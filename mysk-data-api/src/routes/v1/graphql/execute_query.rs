@@ -0,0 +1,37 @@
+use crate::{
+    AppState,
+    extractors::{api_key::ApiKeyHeader, logged_in::LoggedIn},
+    graphql::{self, SCHEMA},
+};
+use actix_web::{
+    HttpResponse, Responder, post,
+    web::{Data, Json},
+};
+use async_graphql::Request;
+use mysk_lib::{permissions::Authorizer, prelude::*};
+
+/// Runs a GraphQL query over the students, teachers, classrooms and clubs. Each field is only
+/// resolved if the user may read its parent at the fetch level the field belongs to, so a query
+/// can partially succeed with an error for each denied field.
+#[utoipa::path(
+    tag = "GraphQL",
+    request_body(
+        content = Object,
+        description = "A GraphQL request with `query`, and optionally `variables` and `operationName`",
+    ),
+    responses(
+        (status = OK, description = "The GraphQL response, with `data` and any `errors`", body = Object),
+    ),
+)]
+#[post("")]
+pub async fn execute_query(
+    data: Data<AppState>,
+    _: ApiKeyHeader,
+    LoggedIn(user): LoggedIn,
+    Json(request): Json<Request>,
+) -> Result<impl Responder> {
+    let authorizer = Authorizer::new(&user, "/graphql".to_string());
+    let request = graphql::prepare_request(request, &data.db, authorizer);
+
+    Ok(HttpResponse::Ok().json(SCHEMA.execute(request).await))
+}
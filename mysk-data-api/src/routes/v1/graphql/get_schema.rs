@@ -0,0 +1,16 @@
+use crate::graphql::SCHEMA;
+use actix_web::{HttpResponse, Responder, get, http::header::ContentType};
+
+/// Gets the GraphQL schema in the schema definition language.
+#[utoipa::path(
+    tag = "GraphQL",
+    responses(
+        (status = OK, description = "The GraphQL schema", body = String, content_type = "text/plain"),
+    ),
+)]
+#[get("/schema")]
+pub async fn get_schema() -> impl Responder {
+    HttpResponse::Ok()
+        .content_type(ContentType::plaintext())
+        .body(SCHEMA.sdl())
+}
@@ -0,0 +1,14 @@
+use actix_web::web::ServiceConfig;
+use utoipa::OpenApi;
+
+pub mod execute_query;
+pub mod get_schema;
+
+#[derive(OpenApi)]
+#[openapi(paths(execute_query::execute_query, get_schema::get_schema))]
+pub struct ApiDoc;
+
+pub fn config(cfg: &mut ServiceConfig) {
+    cfg.service(execute_query::execute_query)
+        .service(get_schema::get_schema);
+}
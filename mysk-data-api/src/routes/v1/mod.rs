@@ -1,18 +1,40 @@
 use actix_web::web::{ServiceConfig, scope};
+use utoipa::OpenApi;
 
+pub mod admin;
 pub mod attendance;
+pub mod batch;
 pub mod certificates;
 pub mod clubs;
 pub mod contacts;
+pub mod graphql;
 pub mod students;
 pub mod subjects;
 pub mod teachers;
 
+#[derive(OpenApi)]
+#[openapi(nest(
+    (path = "/admin", api = admin::ApiDoc),
+    (path = "/attendance", api = attendance::ApiDoc),
+    (path = "/batch", api = batch::ApiDoc),
+    (path = "/certificates", api = certificates::ApiDoc),
+    (path = "/clubs", api = clubs::ApiDoc),
+    (path = "/contacts", api = contacts::ApiDoc),
+    (path = "/graphql", api = graphql::ApiDoc),
+    (path = "/students", api = students::ApiDoc),
+    (path = "/teachers", api = teachers::ApiDoc),
+    (path = "/subjects", api = subjects::ApiDoc),
+))]
+pub struct ApiDoc;
+
 pub fn config(cfg: &mut ServiceConfig) {
-    cfg.service(scope("/attendance").configure(attendance::config))
+    cfg.service(scope("/admin").configure(admin::config))
+        .service(scope("/attendance").configure(attendance::config))
+        .service(scope("/batch").configure(batch::config))
         .service(scope("/certificates").configure(certificates::config))
         .service(scope("/clubs").configure(clubs::config))
         .service(scope("/contacts").configure(contacts::config))
+        .service(scope("/graphql").configure(graphql::config))
         .service(scope("/students").configure(students::config))
         .service(scope("/teachers").configure(teachers::config))
         .service(scope("/subjects").configure(subjects::config));
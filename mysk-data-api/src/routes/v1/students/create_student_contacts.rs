@@ -4,10 +4,15 @@ use crate::{
 };
 use actix_web::{
     HttpResponse, Responder, post,
-    web::{Data, Json, Path},
+    web::{Data, Path},
 };
 use mysk_lib::{
-    common::{requests::RequestType, response::ResponseType, string::MultiLangString},
+    common::{
+        requests::{Json, RequestType},
+        response::ResponseType,
+        string::MultiLangString,
+        validation::Validate,
+    },
     models::{
         contact::{Contact, db::DbContact},
         enums::ContactType,
@@ -19,15 +24,23 @@ use mysk_lib::{
 };
 use serde::Deserialize;
 use sqlx::query;
+use utoipa::ToSchema;
 use uuid::Uuid;
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, ToSchema, Validate)]
 struct StudentContactRequest {
     name: MultiLangString,
     r#type: ContactType,
+    #[validate(contact_type = r#type)]
     value: String,
 }
 
+#[utoipa::path(
+    tag = "Students",
+    responses(
+        (status = OK, description = "The new contact", body = ResponseType<Contact>),
+    ),
+)]
 #[post("/{id}/contacts")]
 pub async fn create_student_contacts(
     data: Data<AppState>,
@@ -54,12 +67,13 @@ pub async fn create_student_contacts(
     for contact_id in existing_contacts {
         let contact = DbContact::get_by_id(&mut conn, contact_id).await?;
         if contact.r#type == student_contact.r#type && contact.value == student_contact.value {
-            return Err(Error::InvalidRequest(
-                "Contact with the same value already exists".to_string(),
+            return Err(Error::BrokenRule(
+                ErrorCode::ContactExists,
                 format!("/students/{student_id}/contacts"),
             ));
         }
     }
+    drop(conn);
 
     let mut transaction = data.db.begin().await?;
 
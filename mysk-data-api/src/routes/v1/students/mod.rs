@@ -1,4 +1,6 @@
 use actix_web::web::ServiceConfig;
+use mysk_lib::models::student::request::{queryable::QueryableStudent, sortable::SortableStudent};
+use utoipa::OpenApi;
 
 pub mod create_student_contacts;
 pub mod modify_student;
@@ -7,6 +9,20 @@ pub mod query_students;
 pub mod query_students_cheer_practice_attendance;
 pub mod query_students_club_quota;
 
+#[derive(OpenApi)]
+#[openapi(
+    paths(
+        create_student_contacts::create_student_contacts,
+        modify_student::modify_student,
+        query_student_details::query_student_details,
+        query_students::query_students,
+        query_students_cheer_practice_attendance::query_students_cheer_practice_attendances,
+        query_students_club_quota::query_students_club_quota,
+    ),
+    components(schemas(QueryableStudent, SortableStudent))
+)]
+pub struct ApiDoc;
+
 pub fn config(cfg: &mut ServiceConfig) {
     cfg.service(create_student_contacts::create_student_contacts)
         .service(modify_student::modify_student)
@@ -4,14 +4,21 @@ use crate::{
 };
 use actix_web::{
     HttpResponse, Responder, put,
-    web::{Data, Json, Path},
+    web::{Data, Path},
 };
 use chrono::NaiveDate;
 use mysk_lib::{
-    common::{requests::RequestType, response::ResponseType, string::FlexibleMultiLangString},
+    audit::{AuditContext, AuditEntity, AuditSnapshot},
+    common::{
+        requests::{Json, RequestType},
+        response::ResponseType,
+        string::FlexibleMultiLangString,
+        validation::Validate,
+    },
     helpers::date::get_current_academic_year,
     models::{
         enums::ShirtSize,
+        person::db::DbPerson,
         student::{Student, db::DbStudent},
         traits::GetById as _,
     },
@@ -21,21 +28,29 @@ use mysk_lib::{
 };
 use serde::Deserialize;
 use sqlx::query;
+use utoipa::ToSchema;
 use uuid::Uuid;
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, ToSchema, Validate)]
 struct UpdateStudentRequest {
+    #[validate(nested)]
     person: Option<UpdatePersonInfo>,
+    #[validate(min = 0)]
     club_quota: Option<i32>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, ToSchema, Validate)]
+#[schema(as = UpdateStudentPersonInfo)]
 struct UpdatePersonInfo {
+    #[validate(not_empty)]
     prefix: Option<FlexibleMultiLangString>,
+    #[validate(not_empty)]
     first_name: Option<FlexibleMultiLangString>,
+    #[validate(not_empty)]
     last_name: Option<FlexibleMultiLangString>,
     middle_name: Option<FlexibleMultiLangString>,
     nickname: Option<FlexibleMultiLangString>,
+    #[validate(past)]
     birthdate: Option<NaiveDate>,
     allergies: Option<Vec<String>>,
     shirt_size: Option<ShirtSize>,
@@ -43,6 +58,12 @@ struct UpdatePersonInfo {
 }
 
 #[allow(clippy::too_many_lines)]
+#[utoipa::path(
+    tag = "Students",
+    responses(
+        (status = OK, description = "The updated student", body = ResponseType<Student>),
+    ),
+)]
 #[put("/{id}")]
 pub async fn modify_student(
     data: Data<AppState>,
@@ -60,6 +81,7 @@ pub async fn modify_student(
     let mut conn = data.db.acquire().await?;
     let student_id = student_id.into_inner();
     let authorizer = Authorizer::new(&user, format!("students/{student_id}"));
+    let audit = AuditContext::new(&user, "PUT /v1/students/{id}");
 
     let db_student = DbStudent::get_by_id(&mut conn, student_id).await?;
     let person_id = db_student.person_id;
@@ -75,10 +97,13 @@ pub async fn modify_student(
     authorizer
         .authorize_student(&db_student, &mut conn, ActionType::Update)
         .await?;
+    drop(conn);
 
     // NOTE: Person-related updates
     if let Some(pu) = update_data.person {
         let mut person_transaction = pool.begin().await?;
+        let snapshot =
+            AuditSnapshot::take(&mut person_transaction, AuditEntity::Person, &[person_id]).await?;
 
         if let Some(allergies) = pu.allergies {
             query!(
@@ -117,12 +142,22 @@ pub async fn modify_student(
             .execute(&mut *person_transaction)
             .await?;
 
+        audit.record(&mut person_transaction, snapshot).await?;
         person_transaction.commit().await?;
+        DbPerson::evict_cached(&[person_id]).await;
     }
 
     // NOTE: Club-related updates
     if let Some(quota) = update_data.club_quota {
         let current_year = get_current_academic_year(None);
+        let mut quota_transaction = pool.begin().await?;
+        let snapshot = AuditSnapshot::take(
+            &mut quota_transaction,
+            AuditEntity::StudentClubQuota,
+            &[student_id],
+        )
+        .await?;
+
         query!(
             r#"
             INSERT INTO student_club_quotas (student_id, year, max_clubs)
@@ -134,8 +169,11 @@ pub async fn modify_student(
             current_year,
             quota
         )
-        .execute(&mut *conn)
+        .execute(&mut *quota_transaction)
         .await?;
+
+        audit.record(&mut quota_transaction, snapshot).await?;
+        quota_transaction.commit().await?;
     }
 
     let student = Student::get_by_id(
@@ -14,6 +14,13 @@ use mysk_lib::{
 };
 use uuid::Uuid;
 
+#[utoipa::path(
+    tag = "Students",
+    params(RequestType),
+    responses(
+        (status = OK, description = "The student", body = ResponseType<Student>),
+    ),
+)]
 #[get("/{id}")]
 pub async fn query_student_details(
     data: Data<AppState>,
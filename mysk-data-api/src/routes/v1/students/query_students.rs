@@ -6,7 +6,7 @@ use actix_web::{HttpResponse, Responder, get, web::Data};
 use mysk_lib::{
     common::{
         requests::{EmptyRequestData, RequestType},
-        response::{MetadataType, ResponseType},
+        response::{MetadataType, ResponseListType, ResponseType},
     },
     models::student::{
         Student,
@@ -16,6 +16,13 @@ use mysk_lib::{
     prelude::*,
 };
 
+#[utoipa::path(
+    tag = "Students",
+    params(RequestType<EmptyRequestData, QueryableStudent, SortableStudent>),
+    responses(
+        (status = OK, description = "The matching students", body = ResponseListType<Student>),
+    ),
+)]
 #[get("")]
 pub async fn query_students(
     data: Data<AppState>,
@@ -9,7 +9,10 @@ use actix_web::{
 };
 
 use mysk_lib::{
-    common::{requests::RequestType, response::ResponseType},
+    common::{
+        requests::RequestType,
+        response::{ResponseListType, ResponseType},
+    },
     models::cheer_practice_attendance::{CheerPracticeAttendance, db::DbCheerPracticeAttendance},
     permissions::Authorizer,
     prelude::*,
@@ -17,6 +20,13 @@ use mysk_lib::{
 
 use uuid::Uuid;
 
+#[utoipa::path(
+    tag = "Students",
+    params(RequestType),
+    responses(
+        (status = OK, description = "The student's cheer practice attendances", body = ResponseListType<CheerPracticeAttendance>),
+    ),
+)]
 #[get("/{id}/attendance/cheer")]
 pub async fn query_students_cheer_practice_attendances(
     data: Data<AppState>,
@@ -35,6 +45,7 @@ pub async fn query_students_cheer_practice_attendances(
     let authorizer = Authorizer::new(&user, format!("/students/{student_id}/attendance/cheer"));
 
     let ids = DbCheerPracticeAttendance::get_by_student_id(&mut conn, student_id).await?;
+    drop(conn);
     let cheer_practice_attendances = CheerPracticeAttendance::get_by_ids(
         pool,
         &ids,
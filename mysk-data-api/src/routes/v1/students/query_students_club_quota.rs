@@ -16,6 +16,12 @@ use crate::{
     extractors::{api_key::ApiKeyHeader, logged_in::LoggedIn},
 };
 
+#[utoipa::path(
+    tag = "Students",
+    responses(
+        (status = OK, description = "How many clubs the student may join this academic year", body = ResponseType<i64>),
+    ),
+)]
 #[get("/{id}/clubs/quota")]
 pub async fn query_students_club_quota(
     data: Data<AppState>,
@@ -24,7 +30,6 @@ pub async fn query_students_club_quota(
     id: Path<Uuid>,
 ) -> Result<impl Responder> {
     let pool = &data.db;
-    let mut conn = data.db.acquire().await?;
     let student_id = id.into_inner();
     let authorizer = Authorizer::new(&user, format!("/v1/students/{student_id}/clubs/quota"));
 
@@ -41,7 +46,8 @@ pub async fn query_students_club_quota(
         unreachable!("Student should always be an IdOnly variant")
     };
 
-    let quota = DbStudent::get_student_club_quota(&mut conn, student.id, None).await?;
+    let quota =
+        DbStudent::get_student_club_quota(&mut *(pool.acquire().await?), student.id, None).await?;
 
     let response = ResponseType::new(quota, None);
 
@@ -2,13 +2,14 @@ use crate::{
     AppState,
     extractors::{api_key::ApiKeyHeader, logged_in::LoggedIn, teacher::LoggedInTeacher},
 };
-use actix_web::{
-    HttpResponse, Responder, post,
-    web::{Data, Json},
-};
+use actix_web::{HttpResponse, Responder, post, web::Data};
 use chrono::NaiveDate;
 use mysk_lib::{
-    common::{requests::RequestType, response::ResponseType},
+    common::{
+        requests::{Json, RequestType},
+        response::ResponseType,
+        validation::Validate,
+    },
     helpers::date::get_current_date,
     models::{
         classroom::db::DbClassroom, online_teaching_reports::OnlineTeachingReports,
@@ -19,9 +20,10 @@ use mysk_lib::{
 };
 use serde::Deserialize;
 use sqlx::{Error as SqlxError, query};
+use utoipa::ToSchema;
 use uuid::Uuid;
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, ToSchema, Validate)]
 struct CreateReportRequest {
     subject_id: Uuid,
     classroom_id: Option<Uuid>,
@@ -34,6 +36,12 @@ struct CreateReportRequest {
     absent_student_no: Option<String>,
 }
 
+#[utoipa::path(
+    tag = "Subjects",
+    responses(
+        (status = OK, description = "The new report", body = ResponseType<OnlineTeachingReports>),
+    ),
+)]
 #[post("")]
 pub async fn create_report(
     data: Data<AppState>,
@@ -109,6 +117,7 @@ pub async fn create_report(
     .fetch_one(&mut *conn)
     .await?
     .id;
+    drop(conn);
 
     let new_class_report = OnlineTeachingReports::get_by_id(
         pool,
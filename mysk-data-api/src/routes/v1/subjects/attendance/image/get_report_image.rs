@@ -17,6 +17,7 @@ use reqwest::{
     header::{AUTHORIZATION, HeaderValue},
 };
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 use uuid::Uuid;
 
 #[derive(Debug, Serialize)]
@@ -31,11 +32,17 @@ struct SignedUrl {
     signed_url: String,
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, ToSchema)]
 struct ReportImageResponse {
     image_url: String,
 }
 
+#[utoipa::path(
+    tag = "Subjects",
+    responses(
+        (status = OK, description = "A signed URL to the report's image", body = ReportImageResponse),
+    ),
+)]
 #[get("/{id}")]
 pub async fn get_report_image(
     data: Data<AppState>,
@@ -59,8 +66,8 @@ pub async fn get_report_image(
 
     // Check if the report has an image
     if !class_report.has_image || class_report.image_ext.is_none() {
-        return Err(Error::InvalidRequest(
-            "Class report is incomplete (missing image)".to_string(),
+        return Err(Error::BrokenRule(
+            ErrorCode::ReportImageMissing,
             format!("/subjects/attendance/image/{report_id}"),
         ));
     }
@@ -1,9 +1,18 @@
 use actix_web::web::{PayloadConfig, ServiceConfig};
+use utoipa::OpenApi;
 
 pub mod get_report_image;
 pub mod modify_report_image;
 pub mod upload_report_image;
 
+#[derive(OpenApi)]
+#[openapi(paths(
+    get_report_image::get_report_image,
+    modify_report_image::modify_report_image,
+    upload_report_image::upload_report_image,
+))]
+pub struct ApiDoc;
+
 pub fn config(cfg: &mut ServiceConfig) {
     cfg.service(get_report_image::get_report_image)
         // 4.5 * 2**20 = ~4.5 MiB (Vercel serverless functions limit)
@@ -7,7 +7,7 @@ use actix_web::{
     web::{Bytes, Data, Path},
 };
 use mysk_lib::{
-    common::{requests::RequestType, response::ResponseType},
+    common::{requests::RequestType, response::ResponseType, validation::Validate},
     models::{
         online_teaching_reports::{OnlineTeachingReports, db::DbOnlineTeachingReports},
         traits::GetById as _,
@@ -21,13 +21,22 @@ use reqwest::{
 };
 use serde::Deserialize;
 use sqlx::query;
+use utoipa::ToSchema;
 use uuid::Uuid;
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, ToSchema, Validate)]
 struct UploadReportImageRequest {
     file_extension: String,
 }
 
+#[utoipa::path(
+    tag = "Subjects",
+    params(RequestType<UploadReportImageRequest>),
+    request_body(content = Vec<u8>, description = "The image, in the format given by `data[file_extension]`", content_type = "image/*"),
+    responses(
+        (status = OK, description = "The report with its image", body = ResponseType<OnlineTeachingReports>),
+    ),
+)]
 #[post("/{id}")]
 pub async fn upload_report_image(
     data: Data<AppState>,
@@ -60,8 +69,8 @@ pub async fn upload_report_image(
 
     // Check if the report already has an image
     if class_report.has_image {
-        return Err(Error::InvalidRequest(
-            "Class report already has an image".to_string(),
+        return Err(Error::BrokenRule(
+            ErrorCode::ReportImageExists,
             format!("/subjects/attendance/image/{report_id}"),
         ));
     }
@@ -103,6 +112,7 @@ pub async fn upload_report_image(
     )
     .execute(&mut *conn)
     .await?;
+    drop(conn);
 
     let class_report = OnlineTeachingReports::get_by_id(
         pool,
@@ -1,4 +1,8 @@
 use actix_web::web::{ServiceConfig, scope};
+use mysk_lib::models::online_teaching_reports::requests::{
+    queryable::QueryableOnlineTeachingReports, sortable::SortableOnlineTeachingReports,
+};
+use utoipa::OpenApi;
 
 pub mod create_report;
 pub mod image;
@@ -6,6 +10,21 @@ pub mod modify_report;
 pub mod query_report_details;
 pub mod query_reports;
 
+#[derive(OpenApi)]
+#[openapi(
+    paths(
+        create_report::create_report,
+        modify_report::modify_report,
+        query_report_details::query_report_details,
+        query_reports::query_reports,
+    ),
+    nest(
+        (path = "/image", api = image::ApiDoc),
+    ),
+    components(schemas(QueryableOnlineTeachingReports, SortableOnlineTeachingReports)),
+)]
+pub struct ApiDoc;
+
 pub fn config(cfg: &mut ServiceConfig) {
     cfg.service(scope("/image").configure(image::config))
         .service(create_report::create_report)
@@ -4,11 +4,15 @@ use crate::{
 };
 use actix_web::{
     HttpResponse, Responder, put,
-    web::{Data, Json, Path},
+    web::{Data, Path},
 };
 use chrono::NaiveDate;
 use mysk_lib::{
-    common::{requests::RequestType, response::ResponseType},
+    common::{
+        requests::{Json, RequestType},
+        response::ResponseType,
+        validation::Validate,
+    },
     models::{
         online_teaching_reports::{OnlineTeachingReports, db::DbOnlineTeachingReports},
         traits::GetById as _,
@@ -19,9 +23,10 @@ use mysk_lib::{
 };
 use serde::Deserialize;
 use sqlx::Error as SqlxError;
+use utoipa::ToSchema;
 use uuid::Uuid;
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, ToSchema, Validate)]
 struct UpdateReportRequest {
     subject_id: Option<Uuid>,
     classroom_id: Option<Uuid>,
@@ -34,6 +39,12 @@ struct UpdateReportRequest {
     absent_student_no: Option<String>,
 }
 
+#[utoipa::path(
+    tag = "Subjects",
+    responses(
+        (status = OK, description = "The updated report", body = ResponseType<OnlineTeachingReports>),
+    ),
+)]
 #[put("/{id}")]
 pub async fn modify_report(
     data: Data<AppState>,
@@ -96,6 +107,7 @@ pub async fn modify_report(
         .build()
         .execute(&mut *conn)
         .await?;
+    drop(conn);
 
     let class_report = OnlineTeachingReports::get_by_id(
         pool,
@@ -14,6 +14,13 @@ use mysk_lib::{
 };
 use uuid::Uuid;
 
+#[utoipa::path(
+    tag = "Subjects",
+    params(RequestType),
+    responses(
+        (status = OK, description = "The report", body = ResponseType<OnlineTeachingReports>),
+    ),
+)]
 #[get("/{id}")]
 pub async fn query_report_details(
     data: Data<AppState>,
@@ -24,7 +31,7 @@ pub async fn query_report_details(
         fetch_level,
         descendant_fetch_level,
         ..
-    }: RequestType<OnlineTeachingReports>,
+    }: RequestType,
 ) -> Result<impl Responder> {
     let pool = &data.db;
     let online_teaching_report_id = online_teaching_report_id.into_inner();
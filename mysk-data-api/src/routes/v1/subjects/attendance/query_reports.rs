@@ -5,6 +5,7 @@ use crate::{
 use actix_web::{HttpResponse, Responder, get, web::Data};
 use mysk_lib::{
     common::{
+        PaginationType,
         requests::{EmptyRequestData, RequestType},
         response::ResponseType,
     },
@@ -17,7 +18,62 @@ use mysk_lib::{
     permissions::Authorizer,
     prelude::*,
 };
+use std::borrow::Cow;
+use utoipa::{
+    PartialSchema, ToSchema,
+    openapi::{
+        AllOfBuilder, ArrayBuilder, RefOr, Schema,
+        schema::{ArrayItems, RefBuilder},
+    },
+};
+
+/// Documents the data this route returns, a pair of the reports and their pagination.
+struct ReportsPage;
+
+impl PartialSchema for ReportsPage {
+    fn schema() -> RefOr<Schema> {
+        let reports = ArrayBuilder::new()
+            .items(OnlineTeachingReports::schema())
+            .build();
+        // A prefix item has to be a schema rather than a reference, so it's wrapped in an allOf
+        let pagination = AllOfBuilder::new()
+            .item(RefBuilder::new().ref_location_from_schema_name(PaginationType::name()))
+            .build();
+
+        ArrayBuilder::new()
+            .prefix_items([Schema::Array(reports), Schema::AllOf(pagination)])
+            .items(ArrayItems::False)
+            .description(Some("The reports, followed by their pagination."))
+            .into()
+    }
+}
+
+impl ToSchema for ReportsPage {
+    fn name() -> Cow<'static, str> {
+        Cow::Borrowed("OnlineTeachingReportsPage")
+    }
+
+    fn schemas(schemas: &mut Vec<(String, RefOr<Schema>)>) {
+        schemas.push((
+            OnlineTeachingReports::name().into_owned(),
+            OnlineTeachingReports::schema(),
+        ));
+        OnlineTeachingReports::schemas(schemas);
+        schemas.push((
+            PaginationType::name().into_owned(),
+            PaginationType::schema(),
+        ));
+        PaginationType::schemas(schemas);
+    }
+}
 
+#[utoipa::path(
+    tag = "Subjects",
+    params(RequestType<EmptyRequestData, QueryableOnlineTeachingReports, SortableOnlineTeachingReports>),
+    responses(
+        (status = OK, description = "The matching reports", body = ResponseType<ReportsPage>),
+    ),
+)]
 #[get("")]
 pub async fn query_reports(
     data: Data<AppState>,
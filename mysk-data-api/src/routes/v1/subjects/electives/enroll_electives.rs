@@ -1,25 +1,42 @@
 use crate::{
     AppState,
     extractors::{api_key::ApiKeyHeader, logged_in::LoggedIn, student::LoggedInStudent},
+    metrics::METRICS,
+    middlewares::idempotency::IdempotencyKeyHeader,
 };
 use actix_web::{
     HttpResponse, Responder, post,
-    web::{Data, Json, Path},
+    web::{Data, Path},
 };
 use mysk_lib::{
-    common::{requests::RequestType, response::ResponseType},
+    audit::{AuditContext, AuditEntity, AuditSnapshot},
+    common::{
+        requests::{Json, NoData, RequestType},
+        response::ResponseType,
+    },
     helpers::date::{get_current_academic_year, get_current_semester},
     models::{
         elective_subject::{ElectiveSubject, db::DbElectiveSubject},
+        elective_waitlist::db::DbElectiveWaitlistEntry,
         traits::GetById as _,
     },
     permissions::Authorizer,
     prelude::*,
+    webhooks::{self, WebhookEvent},
 };
+use serde_json::json;
 use sqlx::query;
 use uuid::Uuid;
 
 #[allow(clippy::too_many_lines)]
+#[utoipa::path(
+    tag = "Subjects",
+    params(IdempotencyKeyHeader),
+    request_body = RequestType<Option<NoData>>,
+    responses(
+        (status = OK, description = "The elective the student is now enrolled in", body = ResponseType<ElectiveSubject>),
+    ),
+)]
 #[post("/{id}/enroll")]
 pub async fn enroll_elective_subject(
     data: Data<AppState>,
@@ -43,27 +60,16 @@ pub async fn enroll_elective_subject(
 
     // Checks if the student is "blacklisted" from enrolling in an elective
     if DbElectiveSubject::is_student_blacklisted(&mut transaction, student_id).await? {
-        return Err(Error::InvalidPermission(
-            "Student is blacklisted from enrolling in electives".to_string(),
+        return Err(Error::BrokenRule(
+            ErrorCode::ElectiveBlacklisted,
             format!("/subjects/electives/{elective_subject_session_id}/enroll"),
         ));
     }
 
     // Checks if the current time is within the elective's enrollment period
     if !DbElectiveSubject::is_enrollment_period(&mut transaction, student_id).await? {
-        return Err(Error::InvalidPermission(
-            "The elective enrollment period has ended".to_string(),
-            format!("/subjects/electives/{elective_subject_session_id}/enroll"),
-        ));
-    }
-
-    // Checks if the student has already enrolled in an elective in the current semester
-    if DbElectiveSubject::is_currently_enrolled(&mut transaction, student_id)
-        .await?
-        .is_some()
-    {
-        return Err(Error::InvalidPermission(
-            "Student has already enrolled in an elective this semester".to_string(),
+        return Err(Error::BrokenRule(
+            ErrorCode::ElectiveEnrollmentClosed,
             format!("/subjects/electives/{elective_subject_session_id}/enroll"),
         ));
     }
@@ -79,15 +85,24 @@ pub async fn enroll_elective_subject(
     // References:
     //   - https://www.postgresql.org/docs/14/functions-admin.html#FUNCTIONS-ADVISORY-LOCKS
     //   - https://www.postgresql.org/docs/14/explicit-locking.html#ADVISORY-LOCKS
-    query!(
-        "\
-        SELECT pg_advisory_xact_lock(696976, session_code::int)\
-        FROM elective_subject_sessions WHERE id = $1\
-        ",
-        elective_subject_session_id,
-    )
-    .execute(&mut *transaction)
-    .await?;
+    let lock_timer = METRICS
+        .advisory_lock_wait
+        .with_label_values(&["enroll_electives"])
+        .start_timer();
+    DbElectiveSubject::lock_sessions(&mut transaction, &[elective_subject_session_id]).await?;
+    DbElectiveSubject::lock_students(&mut transaction, &[student_id]).await?;
+    lock_timer.observe_duration();
+
+    // Checks if the student has already enrolled in an elective in the current semester
+    if DbElectiveSubject::is_currently_enrolled(&mut transaction, student_id)
+        .await?
+        .is_some()
+    {
+        return Err(Error::BrokenRule(
+            ErrorCode::ElectiveAlreadyEnrolled,
+            format!("/subjects/electives/{elective_subject_session_id}/enroll"),
+        ));
+    }
 
     // Checks if the elective the student is trying to enroll in is available
     let elective =
@@ -97,8 +112,8 @@ pub async fn enroll_elective_subject(
         .await?
         .contains(&elective_subject_session_id)
     {
-        return Err(Error::InvalidPermission(
-            "Student cannot re-enroll in the same elective".to_string(),
+        return Err(Error::BrokenRule(
+            ErrorCode::ElectiveReenrollment,
             format!("/subjects/electives/{elective_subject_session_id}/enroll"),
         ));
     }
@@ -106,15 +121,15 @@ pub async fn enroll_elective_subject(
     if elective.year != Some(get_current_academic_year(None))
         || elective.semester != Some(get_current_semester(None))
     {
-        return Err(Error::InvalidPermission(
-            "Student cannot enroll in a non-current elective".to_string(),
+        return Err(Error::BrokenRule(
+            ErrorCode::ElectiveNotCurrent,
             format!("/subjects/electives/{elective_subject_session_id}/enroll"),
         ));
     }
 
     if elective.class_size >= elective.cap_size {
-        return Err(Error::InvalidPermission(
-            "The elective is already full".to_string(),
+        return Err(Error::BrokenRule(
+            ErrorCode::ElectiveFull,
             format!("/subjects/electives/{elective_subject_session_id}/enroll"),
         ));
     }
@@ -127,12 +142,19 @@ pub async fn enroll_elective_subject(
     )
     .await?
     {
-        return Err(Error::InvalidPermission(
-            "Student is not eligible to enroll in this elective".to_string(),
+        return Err(Error::BrokenRule(
+            ErrorCode::ElectiveIneligible,
             format!("/subjects/electives/{elective_subject_session_id}/enroll"),
         ));
     }
 
+    let snapshot = AuditSnapshot::take(
+        &mut transaction,
+        AuditEntity::ElectiveEnrollment,
+        &[student_id],
+    )
+    .await?;
+
     query!(
         "\
         INSERT INTO elective_subject_session_enrolled_students\
@@ -143,8 +165,26 @@ pub async fn enroll_elective_subject(
     )
     .execute(&mut *transaction)
     .await?;
+    DbElectiveWaitlistEntry::leave_all(&mut transaction, student_id).await?;
 
+    webhooks::enqueue(
+        &mut transaction,
+        WebhookEvent::ElectiveEnrolled,
+        json!({
+            "student_id": student_id,
+            "elective_subject_session_id": elective.id,
+            "previous_elective_subject_session_id": None::<Uuid>,
+        }),
+    )
+    .await?;
+    AuditContext::new(&user, "POST /v1/subjects/electives/{id}/enroll")
+        .record(&mut transaction, snapshot)
+        .await?;
     transaction.commit().await?;
+    METRICS
+        .elective_enrollments
+        .with_label_values(&["enroll"])
+        .inc();
 
     let elective = ElectiveSubject::get_by_id(
         pool,
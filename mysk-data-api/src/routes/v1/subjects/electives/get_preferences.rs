@@ -0,0 +1,26 @@
+use crate::{
+    AppState,
+    extractors::{api_key::ApiKeyHeader, student::LoggedInStudent},
+};
+use actix_web::{HttpResponse, Responder, get, web::Data};
+use mysk_lib::{allocation, common::response::ResponseType, prelude::*};
+
+#[utoipa::path(
+    tag = "Subjects",
+    responses(
+        (status = OK, description = "The sessions the student would like to be allocated, best first", body = ResponseType<Vec<Uuid>>),
+    ),
+)]
+#[get("/preferences")]
+pub async fn get_preferences(
+    data: Data<AppState>,
+    _: ApiKeyHeader,
+    LoggedInStudent(student_id): LoggedInStudent,
+) -> Result<impl Responder> {
+    let mut conn = data.db.acquire().await?;
+
+    let preferences = allocation::get_preferences(&mut conn, student_id).await?;
+    let response = ResponseType::new(preferences, None);
+
+    Ok(HttpResponse::Ok().json(response))
+}
@@ -7,6 +7,12 @@ use mysk_lib::{
     common::response::ResponseType, models::elective_subject::db::DbElectiveSubject, prelude::*,
 };
 
+#[utoipa::path(
+    tag = "Subjects",
+    responses(
+        (status = OK, description = "The sessions of electives the student has taken before", body = ResponseType<Vec<Uuid>>),
+    ),
+)]
 #[get("/previously-enrolled")]
 pub async fn get_previously_enrolled(
     data: Data<AppState>,
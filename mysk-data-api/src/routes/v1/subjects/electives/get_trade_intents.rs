@@ -0,0 +1,29 @@
+use crate::{
+    AppState,
+    extractors::{api_key::ApiKeyHeader, student::LoggedInStudent},
+};
+use actix_web::{HttpResponse, Responder, get, web::Data};
+use mysk_lib::{
+    common::response::ResponseType, models::elective_trade_cycle::db::DbElectiveTradeCycle,
+    prelude::*,
+};
+
+#[utoipa::path(
+    tag = "Subjects",
+    responses(
+        (status = OK, description = "The sessions the student is willing to move to", body = ResponseType<Vec<Uuid>>),
+    ),
+)]
+#[get("/trade-intents")]
+pub async fn get_trade_intents(
+    data: Data<AppState>,
+    _: ApiKeyHeader,
+    LoggedInStudent(student_id): LoggedInStudent,
+) -> Result<impl Responder> {
+    let mut conn = data.db.acquire().await?;
+
+    let intents = DbElectiveTradeCycle::get_intents(&mut conn, student_id).await?;
+    let response = ResponseType::new(intents, None);
+
+    Ok(HttpResponse::Ok().json(response))
+}
@@ -7,6 +7,12 @@ use mysk_lib::{
     common::response::ResponseType, models::elective_subject::db::DbElectiveSubject, prelude::*,
 };
 
+#[utoipa::path(
+    tag = "Subjects",
+    responses(
+        (status = OK, description = "Whether enrollment is open for the student's grade", body = ResponseType<bool>),
+    ),
+)]
 #[get("/in-enrollment-period")]
 pub async fn in_enrollment_period(
     data: Data<AppState>,
@@ -0,0 +1,117 @@
+use crate::{
+    AppState,
+    extractors::{api_key::ApiKeyHeader, student::LoggedInStudent},
+    metrics::METRICS,
+    middlewares::idempotency::IdempotencyKeyHeader,
+};
+use actix_web::{
+    HttpResponse, Responder, post,
+    web::{Data, Path},
+};
+use mysk_lib::{
+    common::response::ResponseType,
+    helpers::date::{get_current_academic_year, get_current_semester},
+    models::{
+        elective_subject::db::DbElectiveSubject, elective_waitlist::db::DbElectiveWaitlistEntry,
+        traits::GetById as _,
+    },
+    prelude::*,
+};
+use uuid::Uuid;
+
+#[utoipa::path(
+    tag = "Subjects",
+    params(IdempotencyKeyHeader),
+    responses(
+        (status = OK, description = "The student's place on the waitlist", body = ResponseType<DbElectiveWaitlistEntry>),
+    ),
+)]
+#[post("/{id}/waitlist")]
+pub async fn join_waitlist(
+    data: Data<AppState>,
+    _: ApiKeyHeader,
+    LoggedInStudent(student_id): LoggedInStudent,
+    elective_subject_session_id: Path<Uuid>,
+) -> Result<impl Responder> {
+    let mut transaction = data.db.begin().await?;
+    let elective_subject_session_id = elective_subject_session_id.into_inner();
+    let source = format!("/subjects/electives/{elective_subject_session_id}/waitlist");
+
+    // The student must be able to enroll in the elective if it weren't full, see
+    // `enroll_electives.rs`
+    if DbElectiveSubject::is_student_blacklisted(&mut transaction, student_id).await? {
+        return Err(Error::BrokenRule(ErrorCode::ElectiveBlacklisted, source));
+    }
+
+    if !DbElectiveSubject::is_enrollment_period(&mut transaction, student_id).await? {
+        return Err(Error::BrokenRule(
+            ErrorCode::ElectiveEnrollmentClosed,
+            source,
+        ));
+    }
+
+    // Students who have enrolled already should trade or modify their enrollment instead
+    if DbElectiveSubject::is_currently_enrolled(&mut transaction, student_id)
+        .await?
+        .is_some()
+    {
+        return Err(Error::BrokenRule(
+            ErrorCode::ElectiveAlreadyEnrolled,
+            source,
+        ));
+    }
+
+    // Holding the enrollment lock means a seat can't open between checking that the elective is
+    // full and joining its waitlist, which would leave the student waiting for nothing
+    let lock_timer = METRICS
+        .advisory_lock_wait
+        .with_label_values(&["join_waitlist"])
+        .start_timer();
+    DbElectiveSubject::lock_sessions(&mut transaction, &[elective_subject_session_id]).await?;
+    lock_timer.observe_duration();
+
+    let elective =
+        DbElectiveSubject::get_by_id(&mut transaction, elective_subject_session_id).await?;
+
+    if DbElectiveSubject::get_previously_enrolled_electives(&mut transaction, student_id)
+        .await?
+        .contains(&elective_subject_session_id)
+    {
+        return Err(Error::BrokenRule(ErrorCode::ElectiveReenrollment, source));
+    }
+
+    if elective.year != Some(get_current_academic_year(None))
+        || elective.semester != Some(get_current_semester(None))
+    {
+        return Err(Error::BrokenRule(ErrorCode::ElectiveNotCurrent, source));
+    }
+
+    if elective.class_size < elective.cap_size {
+        return Err(Error::BrokenRule(ErrorCode::ElectiveNotFull, source));
+    }
+
+    if !DbElectiveSubject::is_student_eligible(
+        &mut transaction,
+        elective_subject_session_id,
+        student_id,
+    )
+    .await?
+    {
+        return Err(Error::BrokenRule(ErrorCode::ElectiveIneligible, source));
+    }
+
+    let Some(entry) =
+        DbElectiveWaitlistEntry::join(&mut transaction, elective_subject_session_id, student_id)
+            .await?
+    else {
+        return Err(Error::BrokenRule(
+            ErrorCode::ElectiveAlreadyWaitlisted,
+            source,
+        ));
+    };
+    transaction.commit().await?;
+
+    let response = ResponseType::new(entry, None);
+
+    Ok(HttpResponse::Ok().json(response))
+}
@@ -0,0 +1,41 @@
+use crate::{
+    AppState,
+    extractors::{api_key::ApiKeyHeader, student::LoggedInStudent},
+};
+use actix_web::{
+    HttpResponse, Responder, delete,
+    web::{Data, Path},
+};
+use mysk_lib::{
+    common::response::{EmptyResponseData, ResponseType},
+    models::elective_waitlist::db::DbElectiveWaitlistEntry,
+    prelude::*,
+};
+use uuid::Uuid;
+
+#[utoipa::path(
+    tag = "Subjects",
+    responses(
+        (status = OK, description = "The student left the waitlist", body = ResponseType<EmptyResponseData>),
+    ),
+)]
+#[delete("/{id}/waitlist")]
+pub async fn leave_waitlist(
+    data: Data<AppState>,
+    _: ApiKeyHeader,
+    LoggedInStudent(student_id): LoggedInStudent,
+    elective_subject_session_id: Path<Uuid>,
+) -> Result<impl Responder> {
+    let mut conn = data.db.acquire().await?;
+    let elective_subject_session_id = elective_subject_session_id.into_inner();
+
+    if !DbElectiveWaitlistEntry::leave(&mut conn, elective_subject_session_id, student_id).await? {
+        return Err(Error::BrokenRule(
+            ErrorCode::ElectiveNotWaitlisted,
+            format!("/subjects/electives/{elective_subject_session_id}/waitlist"),
+        ));
+    }
+    let response = ResponseType::new(EmptyResponseData {}, None);
+
+    Ok(HttpResponse::Ok().json(response))
+}
@@ -1,19 +1,67 @@
 use actix_web::web::{ServiceConfig, scope};
+use mysk_lib::models::elective_subject::request::{
+    queryable::QueryableElectiveSubject, sortable::SortableElectiveSubject,
+};
+use utoipa::OpenApi;
 
 pub mod enroll_electives;
+pub mod get_preferences;
 pub mod get_previously_enrolled;
+pub mod get_trade_intents;
 pub mod in_enrollment_period;
+pub mod join_waitlist;
+pub mod leave_waitlist;
 pub mod modify_electives;
 pub mod query_elective_details;
 pub mod query_electives;
+pub mod query_waitlist_position;
+pub mod trade_cycles;
 pub mod trade_offers;
+pub mod update_preferences;
+pub mod update_trade_intents;
+pub mod withdraw_electives;
+
+#[derive(OpenApi)]
+#[openapi(
+    paths(
+        get_previously_enrolled::get_previously_enrolled,
+        in_enrollment_period::in_enrollment_period,
+        get_preferences::get_preferences,
+        update_preferences::update_preferences,
+        get_trade_intents::get_trade_intents,
+        update_trade_intents::update_trade_intents,
+        enroll_electives::enroll_elective_subject,
+        modify_electives::modify_elective_subject,
+        withdraw_electives::withdraw_elective_subject,
+        query_elective_details::query_elective_details,
+        query_electives::query_elective_subject,
+        join_waitlist::join_waitlist,
+        query_waitlist_position::query_waitlist_position,
+        leave_waitlist::leave_waitlist,
+    ),
+    nest(
+        (path = "/trade-offers", api = trade_offers::ApiDoc),
+        (path = "/trade-cycles", api = trade_cycles::ApiDoc),
+    ),
+    components(schemas(QueryableElectiveSubject, SortableElectiveSubject)),
+)]
+pub struct ApiDoc;
 
 pub fn config(cfg: &mut ServiceConfig) {
     cfg.service(scope("/trade-offers").configure(trade_offers::config))
+        .service(scope("/trade-cycles").configure(trade_cycles::config))
         .service(get_previously_enrolled::get_previously_enrolled)
         .service(in_enrollment_period::in_enrollment_period)
+        .service(get_preferences::get_preferences)
+        .service(update_preferences::update_preferences)
+        .service(get_trade_intents::get_trade_intents)
+        .service(update_trade_intents::update_trade_intents)
         .service(enroll_electives::enroll_elective_subject)
         .service(modify_electives::modify_elective_subject)
+        .service(withdraw_electives::withdraw_elective_subject)
         .service(query_elective_details::query_elective_details)
-        .service(query_electives::query_elective_subject);
+        .service(query_electives::query_elective_subject)
+        .service(join_waitlist::join_waitlist)
+        .service(query_waitlist_position::query_waitlist_position)
+        .service(leave_waitlist::leave_waitlist);
 }
@@ -1,25 +1,40 @@
 use crate::{
     AppState,
     extractors::{api_key::ApiKeyHeader, logged_in::LoggedIn, student::LoggedInStudent},
+    metrics::METRICS,
 };
 use actix_web::{
     HttpResponse, Responder, put,
-    web::{Data, Json, Path},
+    web::{Data, Path},
 };
 use mysk_lib::{
-    common::{requests::RequestType, response::ResponseType},
+    audit::{AuditContext, AuditEntity, AuditSnapshot},
+    common::{
+        requests::{Json, NoData, RequestType},
+        response::ResponseType,
+    },
     helpers::date::{get_current_academic_year, get_current_semester},
     models::{
         elective_subject::{ElectiveSubject, db::DbElectiveSubject},
+        elective_waitlist::db::DbElectiveWaitlistEntry,
         traits::GetById as _,
     },
     permissions::Authorizer,
     prelude::*,
+    webhooks::{self, WebhookEvent},
 };
+use serde_json::json;
 use sqlx::query;
 use uuid::Uuid;
 
 #[allow(clippy::too_many_lines)]
+#[utoipa::path(
+    tag = "Subjects",
+    request_body = RequestType<Option<NoData>>,
+    responses(
+        (status = OK, description = "The elective the student is now enrolled in", body = ResponseType<ElectiveSubject>),
+    ),
+)]
 #[put("/{id}/enroll")]
 pub async fn modify_elective_subject(
     data: Data<AppState>,
@@ -43,16 +58,16 @@ pub async fn modify_elective_subject(
 
     // Checks if the student is "blacklisted" from enrolling in an elective
     if DbElectiveSubject::is_student_blacklisted(&mut transaction, student_id).await? {
-        return Err(Error::InvalidPermission(
-            "Student is blacklisted from enrolling in electives".to_string(),
+        return Err(Error::BrokenRule(
+            ErrorCode::ElectiveBlacklisted,
             format!("/subjects/electives/{elective_subject_session_id}/enroll"),
         ));
     }
 
     // Checks if the current time is within the elective's enrollment period
     if !DbElectiveSubject::is_enrollment_period(&mut transaction, student_id).await? {
-        return Err(Error::InvalidPermission(
-            "The elective enrollment period has ended".to_string(),
+        return Err(Error::BrokenRule(
+            ErrorCode::ElectiveEnrollmentClosed,
             format!("/subjects/electives/{elective_subject_session_id}/enroll"),
         ));
     }
@@ -61,25 +76,37 @@ pub async fn modify_elective_subject(
     let Some(current_elective_subject_id) =
         DbElectiveSubject::is_currently_enrolled(&mut transaction, student_id).await?
     else {
-        return Err(Error::InvalidPermission(
-            "Student has not enrolled in an elective this semester".to_string(),
+        return Err(Error::BrokenRule(
+            ErrorCode::ElectiveNotEnrolled,
             format!("/subjects/electives/{elective_subject_session_id}/enroll"),
         ));
     };
 
     // Refer to comment in `enroll_electives.rs` for a detailed explanation.
     //
-    // P.S. The numbers "77 69 76" are ASCII code that translates to "M E L"
-    //      (Modify Electives Lock).
-    query!(
-        "\
-        SELECT pg_advisory_xact_lock(776976, session_code::int)\
-        FROM elective_subject_sessions WHERE id = $1\
-        ",
-        elective_subject_session_id,
+    // The elective being left is locked too, as the seat the student frees is handed to the first
+    // student on its waitlist in this transaction.
+    let lock_timer = METRICS
+        .advisory_lock_wait
+        .with_label_values(&["modify_electives"])
+        .start_timer();
+    DbElectiveSubject::lock_sessions(
+        &mut transaction,
+        &[elective_subject_session_id, current_elective_subject_id],
     )
-    .execute(&mut *transaction)
     .await?;
+    DbElectiveSubject::lock_students(&mut transaction, &[student_id]).await?;
+    lock_timer.observe_duration();
+
+    // The student's enrollment may have changed before the locks were taken
+    if DbElectiveSubject::is_currently_enrolled(&mut transaction, student_id).await?
+        != Some(current_elective_subject_id)
+    {
+        return Err(Error::Conflicted(
+            "Enrollment changed while it was being modified".to_string(),
+            format!("/subjects/electives/{elective_subject_session_id}/enroll"),
+        ));
+    }
 
     // Checks if the elective the student is trying to enroll in is available
     let elective =
@@ -89,8 +116,8 @@ pub async fn modify_elective_subject(
         .await?
         .contains(&elective_subject_session_id)
     {
-        return Err(Error::InvalidPermission(
-            "Student cannot re-enroll in the same elective".to_string(),
+        return Err(Error::BrokenRule(
+            ErrorCode::ElectiveReenrollment,
             format!("/subjects/electives/{elective_subject_session_id}/enroll"),
         ));
     }
@@ -98,15 +125,15 @@ pub async fn modify_elective_subject(
     if elective.year != Some(get_current_academic_year(None))
         || elective.semester != Some(get_current_semester(None))
     {
-        return Err(Error::InvalidPermission(
-            "Student cannot enroll in a non-current elective".to_string(),
+        return Err(Error::BrokenRule(
+            ErrorCode::ElectiveNotCurrent,
             format!("/subjects/electives/{elective_subject_session_id}/enroll"),
         ));
     }
 
     if elective.class_size >= elective.cap_size {
-        return Err(Error::InvalidPermission(
-            "The elective is already full".to_string(),
+        return Err(Error::BrokenRule(
+            ErrorCode::ElectiveFull,
             format!("/subjects/electives/{elective_subject_session_id}/enroll"),
         ));
     }
@@ -119,12 +146,19 @@ pub async fn modify_elective_subject(
     )
     .await?
     {
-        return Err(Error::InvalidPermission(
-            "Student is not eligible to enroll in this elective".to_string(),
+        return Err(Error::BrokenRule(
+            ErrorCode::ElectiveIneligible,
             format!("/subjects/electives/{elective_subject_session_id}/enroll"),
         ));
     }
 
+    let mut snapshot = AuditSnapshot::take(
+        &mut transaction,
+        AuditEntity::ElectiveEnrollment,
+        &[student_id],
+    )
+    .await?;
+
     query!(
         "\
         UPDATE elective_subject_session_enrolled_students \
@@ -138,7 +172,37 @@ pub async fn modify_elective_subject(
     .execute(&mut *transaction)
     .await?;
 
+    webhooks::enqueue(
+        &mut transaction,
+        WebhookEvent::ElectiveEnrolled,
+        json!({
+            "student_id": student_id,
+            "elective_subject_session_id": elective.id,
+            "previous_elective_subject_session_id": current_elective_subject_id,
+        }),
+    )
+    .await?;
+
+    let promoted =
+        DbElectiveWaitlistEntry::promote(&mut transaction, current_elective_subject_id).await?;
+    for promoted_student_id in &promoted {
+        snapshot.track(*promoted_student_id);
+    }
+
+    AuditContext::new(&user, "PUT /v1/subjects/electives/{id}/enroll")
+        .record(&mut transaction, snapshot)
+        .await?;
     transaction.commit().await?;
+    METRICS
+        .elective_enrollments
+        .with_label_values(&["modify"])
+        .inc();
+    if !promoted.is_empty() {
+        METRICS
+            .elective_enrollments
+            .with_label_values(&["waitlist"])
+            .inc_by(promoted.len() as u64);
+    }
 
     let elective = ElectiveSubject::get_by_id(
         pool,
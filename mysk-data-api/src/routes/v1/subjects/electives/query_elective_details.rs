@@ -11,6 +11,14 @@ use mysk_lib::{
 };
 use uuid::Uuid;
 
+#[utoipa::path(
+    tag = "Subjects",
+    params(RequestType),
+    responses(
+        (status = OK, description = "The elective session", body = ResponseType<ElectiveSubject>),
+    ),
+    security(("api_key" = [])),
+)]
 #[get("/{id}")]
 pub async fn query_elective_details(
     data: Data<AppState>,
@@ -3,7 +3,7 @@ use actix_web::{HttpResponse, Responder, get, web::Data};
 use mysk_lib::{
     common::{
         requests::{EmptyRequestData, RequestType},
-        response::{MetadataType, ResponseType},
+        response::{MetadataType, ResponseListType, ResponseType},
     },
     models::elective_subject::{
         ElectiveSubject,
@@ -13,6 +13,14 @@ use mysk_lib::{
     prelude::*,
 };
 
+#[utoipa::path(
+    tag = "Subjects",
+    params(RequestType<EmptyRequestData, QueryableElectiveSubject, SortableElectiveSubject>),
+    responses(
+        (status = OK, description = "The matching elective sessions", body = ResponseListType<ElectiveSubject>),
+    ),
+    security(("api_key" = [])),
+)]
 #[get("")]
 pub async fn query_elective_subject(
     data: Data<AppState>,
@@ -0,0 +1,42 @@
+use crate::{
+    AppState,
+    extractors::{api_key::ApiKeyHeader, student::LoggedInStudent},
+};
+use actix_web::{
+    HttpResponse, Responder, get,
+    web::{Data, Path},
+};
+use mysk_lib::{
+    common::response::ResponseType, models::elective_waitlist::db::DbElectiveWaitlistEntry,
+    prelude::*,
+};
+use uuid::Uuid;
+
+#[utoipa::path(
+    tag = "Subjects",
+    responses(
+        (status = OK, description = "The student's place on the waitlist", body = ResponseType<DbElectiveWaitlistEntry>),
+    ),
+)]
+#[get("/{id}/waitlist")]
+pub async fn query_waitlist_position(
+    data: Data<AppState>,
+    _: ApiKeyHeader,
+    LoggedInStudent(student_id): LoggedInStudent,
+    elective_subject_session_id: Path<Uuid>,
+) -> Result<impl Responder> {
+    let mut conn = data.db.acquire().await?;
+    let elective_subject_session_id = elective_subject_session_id.into_inner();
+
+    let Some(entry) =
+        DbElectiveWaitlistEntry::get(&mut conn, elective_subject_session_id, student_id).await?
+    else {
+        return Err(Error::BrokenRule(
+            ErrorCode::ElectiveNotWaitlisted,
+            format!("/subjects/electives/{elective_subject_session_id}/waitlist"),
+        ));
+    };
+    let response = ResponseType::new(entry, None);
+
+    Ok(HttpResponse::Ok().json(response))
+}
@@ -0,0 +1,14 @@
+use actix_web::web::ServiceConfig;
+use utoipa::OpenApi;
+
+pub mod query_cycles;
+pub mod update_cycle;
+
+#[derive(OpenApi)]
+#[openapi(paths(query_cycles::query_trade_cycles, update_cycle::update_trade_cycle))]
+pub struct ApiDoc;
+
+pub fn config(cfg: &mut ServiceConfig) {
+    cfg.service(query_cycles::query_trade_cycles)
+        .service(update_cycle::update_trade_cycle);
+}
@@ -0,0 +1,29 @@
+use crate::{
+    AppState,
+    extractors::{api_key::ApiKeyHeader, student::LoggedInStudent},
+};
+use actix_web::{HttpResponse, Responder, get, web::Data};
+use mysk_lib::{
+    common::response::ResponseType, models::elective_trade_cycle::db::DbElectiveTradeCycle,
+    prelude::*,
+};
+
+#[utoipa::path(
+    tag = "Subjects",
+    responses(
+        (status = OK, description = "The trade cycles the student is a member of, newest first", body = ResponseType<Vec<DbElectiveTradeCycle>>),
+    ),
+)]
+#[get("")]
+pub async fn query_trade_cycles(
+    data: Data<AppState>,
+    _: ApiKeyHeader,
+    LoggedInStudent(student_id): LoggedInStudent,
+) -> Result<impl Responder> {
+    let mut conn = data.db.acquire().await?;
+
+    let cycles = DbElectiveTradeCycle::get_by_student(&mut conn, student_id).await?;
+    let response = ResponseType::new(cycles, None);
+
+    Ok(HttpResponse::Ok().json(response))
+}
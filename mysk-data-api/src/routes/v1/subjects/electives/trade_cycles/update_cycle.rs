@@ -0,0 +1,297 @@
+use crate::{
+    AppState,
+    extractors::{api_key::ApiKeyHeader, logged_in::LoggedIn, student::LoggedInStudent},
+    metrics::METRICS,
+};
+use actix_web::{
+    HttpResponse, Responder, put,
+    web::{Data, Path},
+};
+use mysk_lib::{
+    audit::{AuditContext, AuditEntity, AuditSnapshot},
+    common::{
+        requests::{Json, RequestType},
+        response::ResponseType,
+        validation::Validate,
+    },
+    models::{
+        elective_subject::db::DbElectiveSubject, elective_trade_cycle::db::DbElectiveTradeCycle,
+        enums::SubmissionStatus,
+    },
+    prelude::*,
+    webhooks::{self, WebhookEvent},
+};
+use serde::Deserialize;
+use serde_json::json;
+use sqlx::{PgConnection, query};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+#[derive(Debug, Deserialize, ToSchema, Validate)]
+struct UpdatableElectiveTradeCycle {
+    pub status: SubmissionStatus,
+}
+
+#[allow(clippy::too_many_lines)]
+#[utoipa::path(
+    tag = "Subjects",
+    responses(
+        (status = OK, description = "The updated trade cycle", body = ResponseType<DbElectiveTradeCycle>),
+    ),
+)]
+#[put("/{id}")]
+pub async fn update_trade_cycle(
+    data: Data<AppState>,
+    _: ApiKeyHeader,
+    LoggedIn(user): LoggedIn,
+    LoggedInStudent(client_student_id): LoggedInStudent,
+    trade_cycle_id: Path<Uuid>,
+    Json(RequestType {
+        data: request_data, ..
+    }): Json<RequestType<UpdatableElectiveTradeCycle>>,
+) -> Result<impl Responder> {
+    let mut transaction = data.db.begin().await?;
+    let trade_cycle_id = trade_cycle_id.into_inner();
+    let source = format!("/subjects/electives/trade-cycles/{trade_cycle_id}");
+    if matches!(request_data.status, SubmissionStatus::Pending) {
+        return Err(Error::InvalidRequest(
+            "Status must be either `approved` or `declined`".to_string(),
+            source,
+        ));
+    }
+
+    // Checks if the student is "blacklisted" from enrolling in an elective
+    if DbElectiveSubject::is_student_blacklisted(&mut transaction, client_student_id).await? {
+        return Err(Error::BrokenRule(ErrorCode::ElectiveBlacklisted, source));
+    }
+
+    // Checks if the current time is within the elective's enrollment period
+    if !DbElectiveSubject::is_enrollment_period(&mut transaction, client_student_id).await? {
+        return Err(Error::BrokenRule(
+            ErrorCode::ElectiveEnrollmentClosed,
+            source,
+        ));
+    }
+
+    // Members answering at the same time must see each other's answers, so that the last approval
+    // always carries out the cycle
+    query!(
+        "SELECT id FROM elective_subject_trade_cycles WHERE id = $1 FOR UPDATE",
+        trade_cycle_id,
+    )
+    .fetch_optional(&mut *transaction)
+    .await?
+    .ok_or(Error::EntityNotFound(
+        "Trade cycle not found".to_string(),
+        source.clone(),
+    ))?;
+    let cycle = DbElectiveTradeCycle::get(&mut transaction, trade_cycle_id)
+        .await?
+        .ok_or(Error::EntityNotFound(
+            "Trade cycle not found".to_string(),
+            source.clone(),
+        ))?;
+
+    if !cycle
+        .members
+        .iter()
+        .any(|member| member.student_id == client_student_id)
+    {
+        return Err(Error::InvalidPermission(
+            "Insufficient permissions to perform this action".to_string(),
+            source,
+        ));
+    }
+    if !matches!(cycle.status, SubmissionStatus::Pending) {
+        return Err(Error::BrokenRule(ErrorCode::TradeCycleResolved, source));
+    }
+
+    DbElectiveTradeCycle::respond(
+        &mut transaction,
+        trade_cycle_id,
+        client_student_id,
+        request_data.status,
+    )
+    .await?;
+
+    let mut moved_students = 0;
+    if matches!(request_data.status, SubmissionStatus::Declined) {
+        // The student no longer wants to trade, and the others may be matched again without them
+        DbElectiveTradeCycle::clear_intents(&mut transaction, &[client_student_id]).await?;
+        DbElectiveTradeCycle::match_intents(&mut transaction).await?;
+    } else if cycle.members.iter().all(|member| {
+        member.student_id == client_student_id
+            || matches!(member.status, SubmissionStatus::Approved)
+    }) {
+        let student_ids = cycle
+            .members
+            .iter()
+            .map(|member| member.student_id)
+            .collect::<Vec<_>>();
+        let from_session_ids = cycle
+            .members
+            .iter()
+            .map(|member| member.from_elective_subject_session_id)
+            .collect::<Vec<_>>();
+        let to_session_ids = cycle
+            .members
+            .iter()
+            .map(|member| member.to_elective_subject_session_id)
+            .collect::<Vec<_>>();
+
+        // Refer to comment in `enroll_electives.rs` for a detailed explanation.
+        let lock_timer = METRICS
+            .advisory_lock_wait
+            .with_label_values(&["trade_cycle"])
+            .start_timer();
+        DbElectiveSubject::lock_sessions(&mut transaction, &from_session_ids).await?;
+        DbElectiveSubject::lock_students(&mut transaction, &student_ids).await?;
+        lock_timer.observe_duration();
+
+        // The members may have changed their enrollment or classroom since the cycle was found
+        let mut is_outdated = false;
+        for member in &cycle.members {
+            let is_eligible = match DbElectiveSubject::is_student_eligible(
+                &mut transaction,
+                member.to_elective_subject_session_id,
+                member.student_id,
+            )
+            .await
+            {
+                Ok(is_eligible) => is_eligible,
+                Err(Error::BrokenRule(ErrorCode::ElectiveNoClassroom, _)) => false,
+                Err(err) => return Err(err),
+            };
+            if !is_eligible
+                || DbElectiveSubject::is_currently_enrolled(&mut transaction, member.student_id)
+                    .await?
+                    != Some(member.from_elective_subject_session_id)
+                || DbElectiveSubject::is_student_blacklisted(&mut transaction, member.student_id)
+                    .await?
+            {
+                is_outdated = true;
+                break;
+            }
+        }
+        if is_outdated {
+            decline_outdated(&mut transaction, trade_cycle_id).await?;
+            transaction.commit().await?;
+
+            return Err(Error::BrokenRule(ErrorCode::TradeCycleOutdated, source));
+        }
+
+        // Trade offers can't be carried out once the members have moved
+        let declined_offer_ids = query!(
+            "\
+            SELECT id FROM elective_subject_trade_offers \
+            WHERE status = $1 AND (sender_id = ANY($2) OR receiver_id = ANY($2)) \
+            FOR UPDATE\
+            ",
+            SubmissionStatus::Pending as SubmissionStatus,
+            &student_ids,
+        )
+        .fetch_all(&mut *transaction)
+        .await?
+        .into_iter()
+        .map(|offer| offer.id)
+        .collect::<Vec<_>>();
+
+        let audit = AuditContext::new(&user, "PUT /v1/subjects/electives/trade-cycles/{id}");
+        let offer_snapshot = AuditSnapshot::take(
+            &mut transaction,
+            AuditEntity::ElectiveTradeOffer,
+            &declined_offer_ids,
+        )
+        .await?;
+        let enrollment_snapshot = AuditSnapshot::take(
+            &mut transaction,
+            AuditEntity::ElectiveEnrollment,
+            &student_ids,
+        )
+        .await?;
+
+        query!(
+            "UPDATE elective_subject_trade_offers SET status = $1 WHERE id = ANY($2)",
+            SubmissionStatus::Declined as SubmissionStatus,
+            &declined_offer_ids,
+        )
+        .execute(&mut *transaction)
+        .await?;
+
+        // Every session loses as many members as it gains, so no cap can be exceeded
+        let moved = query!(
+            "\
+            UPDATE elective_subject_session_enrolled_students AS esses \
+            SET updated_at = now(), elective_subject_session_id = m.to_id \
+            FROM unnest($1::uuid[], $2::uuid[], $3::uuid[]) AS m(student_id, from_id, to_id) \
+            WHERE esses.student_id = m.student_id AND esses.elective_subject_session_id = m.from_id\
+            ",
+            &student_ids,
+            &from_session_ids,
+            &to_session_ids,
+        )
+        .execute(&mut *transaction)
+        .await?;
+        // Only moving every member keeps the sessions within their caps
+        if moved.rows_affected() != student_ids.len() as u64 {
+            transaction.rollback().await?;
+            let mut transaction = data.db.begin().await?;
+            decline_outdated(&mut transaction, trade_cycle_id).await?;
+            transaction.commit().await?;
+
+            return Err(Error::BrokenRule(ErrorCode::TradeCycleOutdated, source));
+        }
+        DbElectiveTradeCycle::clear_intents(&mut transaction, &student_ids).await?;
+        DbElectiveTradeCycle::set_status(
+            &mut transaction,
+            trade_cycle_id,
+            SubmissionStatus::Approved,
+        )
+        .await?;
+
+        for member in &cycle.members {
+            webhooks::enqueue(
+                &mut transaction,
+                WebhookEvent::ElectiveEnrolled,
+                json!({
+                    "student_id": member.student_id,
+                    "elective_subject_session_id": member.to_elective_subject_session_id,
+                    "previous_elective_subject_session_id":
+                        member.from_elective_subject_session_id,
+                }),
+            )
+            .await?;
+        }
+
+        audit.record(&mut transaction, offer_snapshot).await?;
+        audit.record(&mut transaction, enrollment_snapshot).await?;
+        moved_students = student_ids.len();
+    }
+
+    let cycle = DbElectiveTradeCycle::get(&mut transaction, trade_cycle_id)
+        .await?
+        .ok_or(Error::EntityNotFound(
+            "Trade cycle not found".to_string(),
+            source,
+        ))?;
+    transaction.commit().await?;
+    if moved_students > 0 {
+        METRICS
+            .elective_enrollments
+            .with_label_values(&["trade_cycle"])
+            .inc_by(moved_students as u64);
+    }
+
+    let response = ResponseType::new(cycle, None);
+
+    Ok(HttpResponse::Ok().json(response))
+}
+
+/// Declines a cycle whose members have changed their enrollment or classroom since it was found.
+async fn decline_outdated(conn: &mut PgConnection, trade_cycle_id: Uuid) -> Result<()> {
+    DbElectiveTradeCycle::set_status(conn, trade_cycle_id, SubmissionStatus::Declined).await?;
+    // The other members may still be matched in a cycle which is up to date
+    DbElectiveTradeCycle::match_intents(conn).await?;
+
+    Ok(())
+}
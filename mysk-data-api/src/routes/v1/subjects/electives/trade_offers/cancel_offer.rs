@@ -0,0 +1,121 @@
+use crate::{
+    AppState,
+    extractors::{api_key::ApiKeyHeader, logged_in::LoggedIn, student::LoggedInStudent},
+    metrics::METRICS,
+};
+use actix_web::{
+    HttpResponse, Responder, post,
+    web::{Data, Path},
+};
+use mysk_lib::{
+    audit::{AuditContext, AuditEntity, AuditSnapshot},
+    common::{requests::RequestType, response::ResponseType},
+    models::{
+        elective_trade_offer::{ElectiveTradeOffer, db::DbElectiveTradeOffer},
+        enums::{SubmissionStatus, TradeOfferDeclineReason},
+        traits::GetById,
+    },
+    permissions::Authorizer,
+    prelude::*,
+};
+use sqlx::query;
+use uuid::Uuid;
+
+#[utoipa::path(
+    tag = "Subjects",
+    params(RequestType),
+    responses(
+        (status = OK, description = "The cancelled trade offer", body = ResponseType<ElectiveTradeOffer>),
+    ),
+)]
+#[post("/{id}/cancel")]
+pub async fn cancel_trade_offer(
+    data: Data<AppState>,
+    _: ApiKeyHeader,
+    LoggedIn(user): LoggedIn,
+    LoggedInStudent(client_student_id): LoggedInStudent,
+    trade_offer_id: Path<Uuid>,
+    RequestType {
+        fetch_level,
+        descendant_fetch_level,
+        ..
+    }: RequestType,
+) -> Result<impl Responder> {
+    let pool = &data.db;
+    let mut transaction = data.db.begin().await?;
+    let trade_offer_id = trade_offer_id.into_inner();
+    let authorizer = Authorizer::new(
+        &user,
+        format!("/subjects/electives/trade-offers/{trade_offer_id}/cancel"),
+    );
+
+    // Lock the trade offer, so that it isn't answered or swept while being cancelled
+    query!(
+        "SELECT id FROM elective_subject_trade_offers WHERE id = $1 FOR UPDATE",
+        trade_offer_id,
+    )
+    .fetch_optional(&mut *transaction)
+    .await?;
+    let trade_offer = DbElectiveTradeOffer::get_by_id(&mut transaction, trade_offer_id).await?;
+
+    if client_student_id != trade_offer.sender_id {
+        return Err(Error::BrokenRule(
+            ErrorCode::TradeOfferNotSender,
+            format!("/subjects/electives/trade-offers/{trade_offer_id}/cancel"),
+        ));
+    }
+
+    // Check if trade offer is already approved or declined
+    let resolved = match trade_offer.status {
+        SubmissionStatus::Approved => Some(ErrorCode::TradeOfferAlreadyApproved),
+        SubmissionStatus::Declined => Some(ErrorCode::TradeOfferAlreadyDeclined),
+        SubmissionStatus::Pending => None,
+    };
+    if let Some(code) = resolved {
+        return Err(Error::BrokenRule(
+            code,
+            format!("/subjects/electives/trade-offers/{trade_offer_id}/cancel"),
+        ));
+    }
+
+    let audit = AuditContext::new(
+        &user,
+        "POST /v1/subjects/electives/trade-offers/{id}/cancel",
+    );
+    let snapshot = AuditSnapshot::take(
+        &mut transaction,
+        AuditEntity::ElectiveTradeOffer,
+        &[trade_offer_id],
+    )
+    .await?;
+
+    query!(
+        "\
+        UPDATE elective_subject_trade_offers SET status = $1, decline_reason = $2 WHERE id = $3\
+        ",
+        SubmissionStatus::Declined as SubmissionStatus,
+        TradeOfferDeclineReason::Cancelled as TradeOfferDeclineReason,
+        trade_offer_id,
+    )
+    .execute(&mut *transaction)
+    .await?;
+
+    audit.record(&mut transaction, snapshot).await?;
+    transaction.commit().await?;
+    METRICS
+        .elective_trade_offers
+        .with_label_values(&[TradeOfferDeclineReason::Cancelled.to_string().as_str()])
+        .inc();
+
+    let elective_trade_offer = ElectiveTradeOffer::get_by_id(
+        pool,
+        trade_offer_id,
+        fetch_level,
+        descendant_fetch_level,
+        &authorizer,
+    )
+    .await?;
+    let response = ResponseType::new(elective_trade_offer, None);
+
+    Ok(HttpResponse::Ok().json(response))
+}
@@ -1,13 +1,16 @@
 use crate::{
     AppState,
     extractors::{api_key::ApiKeyHeader, logged_in::LoggedIn, student::LoggedInStudent},
+    metrics::METRICS,
+    middlewares::idempotency::IdempotencyKeyHeader,
 };
-use actix_web::{
-    HttpResponse, Responder, post,
-    web::{Data, Json},
-};
+use actix_web::{HttpResponse, Responder, post, web::Data};
 use mysk_lib::{
-    common::{requests::RequestType, response::ResponseType},
+    common::{
+        requests::{Json, RequestType},
+        response::ResponseType,
+        validation::Validate,
+    },
     models::{
         elective_subject::db::DbElectiveSubject, elective_trade_offer::ElectiveTradeOffer,
         enums::SubmissionStatus, traits::GetById,
@@ -17,14 +20,23 @@ use mysk_lib::{
 };
 use serde::Deserialize;
 use sqlx::query;
+use utoipa::ToSchema;
 use uuid::Uuid;
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, ToSchema, Validate)]
 struct ElectiveTradeOfferRequest {
+    #[validate(not_nil)]
     pub receiver_id: Uuid,
 }
 
 #[allow(clippy::too_many_lines)]
+#[utoipa::path(
+    tag = "Subjects",
+    params(IdempotencyKeyHeader),
+    responses(
+        (status = OK, description = "The new trade offer", body = ResponseType<ElectiveTradeOffer>),
+    ),
+)]
 #[post("")]
 pub async fn create_trade_offer(
     data: Data<AppState>,
@@ -45,16 +57,16 @@ pub async fn create_trade_offer(
 
     // Checks if the student is "blacklisted" from enrolling in an elective
     if DbElectiveSubject::is_student_blacklisted(&mut transaction, client_student_id).await? {
-        return Err(Error::InvalidPermission(
-            "Student is blacklisted from enrolling in electives".to_string(),
+        return Err(Error::BrokenRule(
+            ErrorCode::ElectiveBlacklisted,
             "/subjects/electives/trade-offers".to_string(),
         ));
     }
 
     // Check if the current time is within the elective's enrollment period
     if !DbElectiveSubject::is_enrollment_period(&mut transaction, client_student_id).await? {
-        return Err(Error::InvalidPermission(
-            "The elective enrollment period has ended".to_string(),
+        return Err(Error::BrokenRule(
+            ErrorCode::ElectiveEnrollmentClosed,
             "/subjects/electives/trade-offers".to_string(),
         ));
     }
@@ -63,8 +75,8 @@ pub async fn create_trade_offer(
     let Some(client_elective_subject_id) =
         DbElectiveSubject::is_currently_enrolled(&mut transaction, client_student_id).await?
     else {
-        return Err(Error::InvalidPermission(
-            "Student has not enrolled in an elective this semester".to_string(),
+        return Err(Error::BrokenRule(
+            ErrorCode::ElectiveNotEnrolled,
             "/subjects/electives/trade-offers".to_string(),
         ));
     };
@@ -73,8 +85,8 @@ pub async fn create_trade_offer(
     let Some(other_elective_subject_id) =
         DbElectiveSubject::is_currently_enrolled(&mut transaction, other_student_id).await?
     else {
-        return Err(Error::InvalidPermission(
-            "Receiving student has not enrolled in an elective this semester".to_string(),
+        return Err(Error::BrokenRule(
+            ErrorCode::TradeOfferReceiverNotEnrolled,
             "/subjects/electives/trade-offers".to_string(),
         ));
     };
@@ -92,8 +104,8 @@ pub async fn create_trade_offer(
     )
     .await?
     {
-        return Err(Error::InvalidPermission(
-            "Student is not eligible to enroll in this elective".to_string(),
+        return Err(Error::BrokenRule(
+            ErrorCode::ElectiveIneligible,
             "/subjects/electives/trade-offers".to_string(),
         ));
     }
@@ -110,8 +122,8 @@ pub async fn create_trade_offer(
     )
     .await?
     {
-        return Err(Error::InvalidPermission(
-            "Receiving student is not eligible to enroll in this elective".to_string(),
+        return Err(Error::BrokenRule(
+            ErrorCode::TradeOfferReceiverIneligible,
             "/subjects/electives/trade-offers".to_string(),
         ));
     }
@@ -120,8 +132,8 @@ pub async fn create_trade_offer(
     if (client_elective_subject.id == other_elective_subject.id)
         && (client_elective_subject.session_code == other_elective_subject.session_code)
     {
-        return Err(Error::InvalidRequest(
-            "Both the sender and receiver has the same elective subjects".to_string(),
+        return Err(Error::BrokenRule(
+            ErrorCode::TradeOfferSameElective,
             "/subjects/electives/trade-offers".to_string(),
         ));
     }
@@ -147,8 +159,8 @@ pub async fn create_trade_offer(
     .exists
     .unwrap_or(false);
     if trade_offer_already_exists {
-        return Err(Error::InvalidRequest(
-            "Trade offer with the receiving student already exists".to_string(),
+        return Err(Error::BrokenRule(
+            ErrorCode::TradeOfferExists,
             "/subjects/electives/trade-offers".to_string(),
         ));
     }
@@ -167,8 +179,8 @@ pub async fn create_trade_offer(
     .count
     .unwrap_or(0);
     if pending_trade_offers_count >= 3 {
-        return Err(Error::InvalidPermission(
-            "Student has reached the maximum number of pending trade offers".to_string(),
+        return Err(Error::BrokenRule(
+            ErrorCode::TradeOfferQuotaExceeded,
             "/subjects/electives/trade-offers".to_string(),
         ));
     }
@@ -187,8 +199,8 @@ pub async fn create_trade_offer(
     .count
     .unwrap_or(0);
     if pending_trade_offers_count >= 3 {
-        return Err(Error::InvalidPermission(
-            "Receiving student has reached the maximum number of pending trade offers".to_string(),
+        return Err(Error::BrokenRule(
+            ErrorCode::TradeOfferReceiverQuotaExceeded,
             "/subjects/electives/trade-offers".to_string(),
         ));
     }
@@ -211,6 +223,10 @@ pub async fn create_trade_offer(
     .id;
 
     transaction.commit().await?;
+    METRICS
+        .elective_trade_offers
+        .with_label_values(&["pending"])
+        .inc();
 
     let elective_trade_offer = ElectiveTradeOffer::get_by_id(
         pool,
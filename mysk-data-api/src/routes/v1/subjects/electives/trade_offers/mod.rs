@@ -1,11 +1,29 @@
 use actix_web::web::ServiceConfig;
+use mysk_lib::models::elective_trade_offer::request::{
+    queryable::QueryableElectiveTradeOffer, sortable::SortableElectiveTradeOffer,
+};
+use utoipa::OpenApi;
 
+pub mod cancel_offer;
 pub mod create_offer;
 pub mod query_offers;
 pub mod update_offer;
 
+#[derive(OpenApi)]
+#[openapi(
+    paths(
+        cancel_offer::cancel_trade_offer,
+        create_offer::create_trade_offer,
+        query_offers::query_trade_offers,
+        update_offer::update_trade_offer,
+    ),
+    components(schemas(QueryableElectiveTradeOffer, SortableElectiveTradeOffer))
+)]
+pub struct ApiDoc;
+
 pub fn config(cfg: &mut ServiceConfig) {
-    cfg.service(create_offer::create_trade_offer)
+    cfg.service(cancel_offer::cancel_trade_offer)
+        .service(create_offer::create_trade_offer)
         .service(query_offers::query_trade_offers)
         .service(update_offer::update_trade_offer);
 }
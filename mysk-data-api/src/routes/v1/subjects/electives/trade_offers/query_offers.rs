@@ -6,7 +6,7 @@ use actix_web::{HttpResponse, Responder, get, web::Data};
 use mysk_lib::{
     common::{
         requests::{EmptyRequestData, RequestType},
-        response::{MetadataType, ResponseType},
+        response::{MetadataType, ResponseListType, ResponseType},
     },
     models::elective_trade_offer::{
         ElectiveTradeOffer,
@@ -16,6 +16,13 @@ use mysk_lib::{
     prelude::*,
 };
 
+#[utoipa::path(
+    tag = "Subjects",
+    params(RequestType<EmptyRequestData, QueryableElectiveTradeOffer, SortableElectiveTradeOffer>),
+    responses(
+        (status = OK, description = "The matching trade offers", body = ResponseListType<ElectiveTradeOffer>),
+    ),
+)]
 #[get("")]
 pub async fn query_trade_offers(
     data: Data<AppState>,
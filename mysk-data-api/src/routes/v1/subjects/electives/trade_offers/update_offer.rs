@@ -1,13 +1,20 @@
 use crate::{
     AppState,
     extractors::{api_key::ApiKeyHeader, logged_in::LoggedIn, student::LoggedInStudent},
+    metrics::METRICS,
 };
 use actix_web::{
     HttpResponse, Responder, put,
-    web::{Data, Json, Path},
+    web::{Data, Path},
 };
+use chrono::Utc;
 use mysk_lib::{
-    common::{requests::RequestType, response::ResponseType},
+    audit::{AuditContext, AuditEntity, AuditSnapshot},
+    common::{
+        requests::{Json, RequestType},
+        response::ResponseType,
+        validation::Validate,
+    },
     helpers::date::{get_current_academic_year, get_current_semester},
     models::{
         elective_subject::db::DbElectiveSubject,
@@ -17,17 +24,26 @@ use mysk_lib::{
     },
     permissions::Authorizer,
     prelude::*,
+    webhooks::{self, WebhookEvent},
 };
 use serde::Deserialize;
+use serde_json::json;
 use sqlx::query;
+use utoipa::ToSchema;
 use uuid::Uuid;
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, ToSchema, Validate)]
 struct UpdatableElectiveOffer {
     pub status: SubmissionStatus,
 }
 
 #[allow(clippy::too_many_lines)]
+#[utoipa::path(
+    tag = "Subjects",
+    responses(
+        (status = OK, description = "The updated trade offer", body = ResponseType<ElectiveTradeOffer>),
+    ),
+)]
 #[put("/{id}")]
 pub async fn update_trade_offer(
     data: Data<AppState>,
@@ -61,29 +77,67 @@ pub async fn update_trade_offer(
 
     // Checks if the student is "blacklisted" from enrolling in an elective
     if DbElectiveSubject::is_student_blacklisted(&mut transaction, client_student_id).await? {
-        return Err(Error::InvalidPermission(
-            "Student is blacklisted from enrolling in electives".to_string(),
+        return Err(Error::BrokenRule(
+            ErrorCode::ElectiveBlacklisted,
             format!("/subjects/electives/trade-offers/{trade_offer_id}"),
         ));
     }
 
     // Check if the current time is within the elective's enrollment period
     if !DbElectiveSubject::is_enrollment_period(&mut transaction, client_student_id).await? {
-        return Err(Error::InvalidPermission(
-            "The elective enrollment period has ended".to_string(),
+        return Err(Error::BrokenRule(
+            ErrorCode::ElectiveEnrollmentClosed,
             format!("/subjects/electives/trade-offers/{trade_offer_id}"),
         ));
     }
 
+    // Approving swaps the students' enrollments, so their sessions and the students are locked
+    // before the trade offer, in the order enrolling and withdrawing take them. Refer to comment in
+    // `enroll_electives.rs` for a detailed explanation.
+    if matches!(trade_offer_status, SubmissionStatus::Approved) {
+        let trade_offer = DbElectiveTradeOffer::get_by_id(&mut transaction, trade_offer_id).await?;
+        let lock_timer = METRICS
+            .advisory_lock_wait
+            .with_label_values(&["update_trade_offer"])
+            .start_timer();
+        DbElectiveSubject::lock_sessions(
+            &mut transaction,
+            &[
+                trade_offer.sender_elective_subject_session_id,
+                trade_offer.receiver_elective_subject_session_id,
+            ],
+        )
+        .await?;
+        DbElectiveSubject::lock_students(
+            &mut transaction,
+            &[trade_offer.sender_id, trade_offer.receiver_id],
+        )
+        .await?;
+        lock_timer.observe_duration();
+    }
+
+    // Lock the trade offer, so that it isn't cancelled or swept while being answered
+    query!(
+        "SELECT id FROM elective_subject_trade_offers WHERE id = $1 FOR UPDATE",
+        trade_offer_id,
+    )
+    .fetch_optional(&mut *transaction)
+    .await?;
     let trade_offer = DbElectiveTradeOffer::get_by_id(&mut transaction, trade_offer_id).await?;
 
-    // Check if trade offer is already approved or declined
-    if matches!(
-        trade_offer.status,
-        SubmissionStatus::Approved | SubmissionStatus::Declined,
-    ) {
-        return Err(Error::InvalidPermission(
-            format!("Trade offer has already been {}", trade_offer.status),
+    // Check if trade offer is already approved, declined or expired
+    let resolved = match trade_offer.status {
+        SubmissionStatus::Approved => Some(ErrorCode::TradeOfferAlreadyApproved),
+        SubmissionStatus::Declined => Some(ErrorCode::TradeOfferAlreadyDeclined),
+        // Not yet declined by the sweeper
+        SubmissionStatus::Pending if trade_offer.expires_at <= Utc::now() => {
+            Some(ErrorCode::TradeOfferExpired)
+        }
+        SubmissionStatus::Pending => None,
+    };
+    if let Some(code) = resolved {
+        return Err(Error::BrokenRule(
+            code,
             format!("/subjects/electives/trade-offers/{trade_offer_id}"),
         ));
     }
@@ -91,8 +145,8 @@ pub async fn update_trade_offer(
     let (updated_status, other_student_id) = if client_student_id == trade_offer.sender_id {
         // Disallow approved status if the client is a sending student
         if matches!(trade_offer_status, SubmissionStatus::Approved) {
-            return Err(Error::InvalidPermission(
-                "Student is not allowed to approve own trade offer".to_string(),
+            return Err(Error::BrokenRule(
+                ErrorCode::TradeOfferOwnApproval,
                 format!("/subjects/electives/trade-offers/{trade_offer_id}"),
             ));
         }
@@ -109,21 +163,52 @@ pub async fn update_trade_offer(
         ));
     };
 
-    if matches!(updated_status, SubmissionStatus::Approved) {
-        // Set the status of all the other trade offers of the sending and receiving students to
-        // "declined"
+    let approved = matches!(updated_status, SubmissionStatus::Approved);
+
+    // The other pending trade offers of the sending and receiving students, which are declined
+    // once this one is approved
+    let declined_offer_ids = if approved {
         query!(
             "\
-            UPDATE elective_subject_trade_offers SET status = $1 \
-            WHERE id != $2 AND status = $3 AND\
-                (sender_id = $4 OR sender_id = $5 OR receiver_id = $4 OR receiver_id = $5)\
+            SELECT id FROM elective_subject_trade_offers \
+            WHERE id != $1 AND status = $2 AND\
+                (sender_id = $3 OR sender_id = $4 OR receiver_id = $3 OR receiver_id = $4) \
+            FOR UPDATE\
             ",
-            SubmissionStatus::Declined as SubmissionStatus,
             trade_offer_id,
             SubmissionStatus::Pending as SubmissionStatus,
             client_student_id,
             other_student_id,
         )
+        .fetch_all(&mut *transaction)
+        .await?
+        .into_iter()
+        .map(|offer| offer.id)
+        .collect()
+    } else {
+        Vec::new()
+    };
+
+    let audit = AuditContext::new(&user, "PUT /v1/subjects/electives/trade-offers/{id}");
+    let offer_snapshot = AuditSnapshot::take(
+        &mut transaction,
+        AuditEntity::ElectiveTradeOffer,
+        &[&[trade_offer_id], &declined_offer_ids[..]].concat(),
+    )
+    .await?;
+    let enrollment_snapshot = AuditSnapshot::take(
+        &mut transaction,
+        AuditEntity::ElectiveEnrollment,
+        &[client_student_id, other_student_id],
+    )
+    .await?;
+
+    if approved {
+        query!(
+            "UPDATE elective_subject_trade_offers SET status = $1 WHERE id = ANY($2)",
+            SubmissionStatus::Declined as SubmissionStatus,
+            &declined_offer_ids,
+        )
         .execute(&mut *transaction)
         .await?;
 
@@ -155,6 +240,21 @@ pub async fn update_trade_offer(
         )
         .execute(&mut *transaction)
         .await?;
+
+        webhooks::enqueue(
+            &mut transaction,
+            WebhookEvent::ElectiveTradeOfferApproved,
+            json!({
+                "trade_offer_id": trade_offer_id,
+                "sender_id": trade_offer.sender_id,
+                "receiver_id": trade_offer.receiver_id,
+                "sender_elective_subject_session_id": trade_offer.sender_elective_subject_session_id,
+                "receiver_elective_subject_session_id":
+                    trade_offer.receiver_elective_subject_session_id,
+                "declined_trade_offer_ids": declined_offer_ids,
+            }),
+        )
+        .await?;
     }
 
     // Accept or decline the trade offer
@@ -166,7 +266,13 @@ pub async fn update_trade_offer(
     .execute(&mut *transaction)
     .await?;
 
+    audit.record(&mut transaction, offer_snapshot).await?;
+    audit.record(&mut transaction, enrollment_snapshot).await?;
     transaction.commit().await?;
+    METRICS
+        .elective_trade_offers
+        .with_label_values(&[updated_status.to_string().as_str()])
+        .inc();
 
     let elective_trade_offer = ElectiveTradeOffer::get_by_id(
         pool,
@@ -0,0 +1,77 @@
+use crate::{
+    AppState,
+    extractors::{api_key::ApiKeyHeader, student::LoggedInStudent},
+};
+use actix_web::{HttpResponse, Responder, put, web::Data};
+use mysk_lib::{
+    allocation,
+    common::{
+        requests::{Json, RequestType},
+        response::ResponseType,
+        validation::Validate,
+    },
+    helpers::date::{get_current_academic_year, get_current_semester},
+    prelude::*,
+};
+use serde::Deserialize;
+use sqlx::query;
+use std::collections::HashSet;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+#[derive(Debug, Deserialize, ToSchema, Validate)]
+struct UpdateElectivePreferencesRequest {
+    /// Sessions of this semester, best first. Leave empty to clear the student's preferences.
+    pub elective_subject_session_ids: Vec<Uuid>,
+}
+
+#[utoipa::path(
+    tag = "Subjects",
+    responses(
+        (status = OK, description = "The sessions the student would like to be allocated, best first", body = ResponseType<Vec<Uuid>>),
+    ),
+)]
+#[put("/preferences")]
+pub async fn update_preferences(
+    data: Data<AppState>,
+    _: ApiKeyHeader,
+    LoggedInStudent(student_id): LoggedInStudent,
+    Json(RequestType {
+        data: request_data, ..
+    }): Json<RequestType<UpdateElectivePreferencesRequest>>,
+) -> Result<impl Responder> {
+    let mut transaction = data.db.begin().await?;
+    let session_ids = request_data.elective_subject_session_ids;
+
+    if session_ids.iter().collect::<HashSet<_>>().len() != session_ids.len() {
+        return Err(Error::InvalidRequest(
+            "Each elective may only be ranked once".to_string(),
+            "/subjects/electives/preferences".to_string(),
+        ));
+    }
+
+    let current_sessions = query!(
+        "\
+        SELECT COUNT(*) AS \"count!\" FROM elective_subject_sessions \
+        WHERE id = ANY($1) AND year = $2 AND semester = $3\
+        ",
+        &session_ids,
+        get_current_academic_year(None),
+        get_current_semester(None),
+    )
+    .fetch_one(&mut *transaction)
+    .await?;
+    if usize::try_from(current_sessions.count).ok() != Some(session_ids.len()) {
+        return Err(Error::BrokenRule(
+            ErrorCode::ElectiveNotCurrent,
+            "/subjects/electives/preferences".to_string(),
+        ));
+    }
+
+    allocation::set_preferences(&mut transaction, student_id, &session_ids).await?;
+    transaction.commit().await?;
+
+    let response = ResponseType::new(session_ids, None);
+
+    Ok(HttpResponse::Ok().json(response))
+}
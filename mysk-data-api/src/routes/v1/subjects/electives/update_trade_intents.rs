@@ -0,0 +1,122 @@
+use crate::{
+    AppState,
+    extractors::{api_key::ApiKeyHeader, student::LoggedInStudent},
+};
+use actix_web::{HttpResponse, Responder, put, web::Data};
+use mysk_lib::{
+    common::{
+        requests::{Json, RequestType},
+        response::ResponseType,
+        validation::Validate,
+    },
+    helpers::date::{get_current_academic_year, get_current_semester},
+    models::{
+        elective_subject::db::DbElectiveSubject, elective_trade_cycle::db::DbElectiveTradeCycle,
+        traits::GetById as _,
+    },
+    prelude::*,
+};
+use serde::Deserialize;
+use std::collections::HashSet;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+#[derive(Debug, Deserialize, ToSchema, Validate)]
+struct UpdateTradeIntentsRequest {
+    /// Sessions of this semester. Leave empty to stop trading.
+    pub elective_subject_session_ids: Vec<Uuid>,
+}
+
+#[utoipa::path(
+    tag = "Subjects",
+    responses(
+        (status = OK, description = "The sessions the student is willing to move to", body = ResponseType<Vec<Uuid>>),
+    ),
+)]
+#[put("/trade-intents")]
+pub async fn update_trade_intents(
+    data: Data<AppState>,
+    _: ApiKeyHeader,
+    LoggedInStudent(student_id): LoggedInStudent,
+    Json(RequestType {
+        data: request_data, ..
+    }): Json<RequestType<UpdateTradeIntentsRequest>>,
+) -> Result<impl Responder> {
+    let mut transaction = data.db.begin().await?;
+    let session_ids = request_data
+        .elective_subject_session_ids
+        .into_iter()
+        .collect::<HashSet<_>>()
+        .into_iter()
+        .collect::<Vec<_>>();
+
+    // Checks if the student is "blacklisted" from enrolling in an elective
+    if DbElectiveSubject::is_student_blacklisted(&mut transaction, student_id).await? {
+        return Err(Error::BrokenRule(
+            ErrorCode::ElectiveBlacklisted,
+            "/subjects/electives/trade-intents".to_string(),
+        ));
+    }
+
+    // Checks if the current time is within the elective's enrollment period
+    if !DbElectiveSubject::is_enrollment_period(&mut transaction, student_id).await? {
+        return Err(Error::BrokenRule(
+            ErrorCode::ElectiveEnrollmentClosed,
+            "/subjects/electives/trade-intents".to_string(),
+        ));
+    }
+
+    // Only students with a seat to give up can trade
+    let Some(current_elective_subject_id) =
+        DbElectiveSubject::is_currently_enrolled(&mut transaction, student_id).await?
+    else {
+        return Err(Error::BrokenRule(
+            ErrorCode::ElectiveNotEnrolled,
+            "/subjects/electives/trade-intents".to_string(),
+        ));
+    };
+
+    let previously_enrolled =
+        DbElectiveSubject::get_previously_enrolled_electives(&mut transaction, student_id).await?;
+    for session_id in &session_ids {
+        if *session_id == current_elective_subject_id {
+            return Err(Error::BrokenRule(
+                ErrorCode::TradeIntentOwnElective,
+                "/subjects/electives/trade-intents".to_string(),
+            ));
+        }
+        if previously_enrolled.contains(session_id) {
+            return Err(Error::BrokenRule(
+                ErrorCode::ElectiveReenrollment,
+                "/subjects/electives/trade-intents".to_string(),
+            ));
+        }
+
+        let elective = DbElectiveSubject::get_by_id(&mut transaction, *session_id).await?;
+        if elective.year != Some(get_current_academic_year(None))
+            || elective.semester != Some(get_current_semester(None))
+        {
+            return Err(Error::BrokenRule(
+                ErrorCode::ElectiveNotCurrent,
+                "/subjects/electives/trade-intents".to_string(),
+            ));
+        }
+        if !DbElectiveSubject::is_student_eligible(&mut transaction, *session_id, student_id)
+            .await?
+        {
+            return Err(Error::BrokenRule(
+                ErrorCode::ElectiveIneligible,
+                "/subjects/electives/trade-intents".to_string(),
+            ));
+        }
+    }
+
+    DbElectiveTradeCycle::set_intents(&mut transaction, student_id, &session_ids).await?;
+    DbElectiveTradeCycle::match_intents(&mut transaction).await?;
+    let intents = DbElectiveTradeCycle::get_intents(&mut transaction, student_id).await?;
+    transaction.commit().await?;
+
+    let response = ResponseType::new(intents, None);
+
+    Ok(HttpResponse::Ok().json(response))
+}
@@ -0,0 +1,185 @@
+use crate::{
+    AppState,
+    extractors::{api_key::ApiKeyHeader, logged_in::LoggedIn, student::LoggedInStudent},
+    metrics::METRICS,
+};
+use actix_web::{
+    HttpResponse, Responder, delete,
+    web::{Data, Path},
+};
+use mysk_lib::{
+    audit::{AuditContext, AuditEntity, AuditSnapshot},
+    common::response::{EmptyResponseData, ResponseType},
+    models::{
+        elective_subject::db::DbElectiveSubject, elective_waitlist::db::DbElectiveWaitlistEntry,
+        enums::SubmissionStatus,
+    },
+    prelude::*,
+    webhooks::{self, WebhookEvent},
+};
+use serde_json::json;
+use sqlx::query;
+use uuid::Uuid;
+
+#[allow(clippy::too_many_lines)]
+#[utoipa::path(
+    tag = "Subjects",
+    responses(
+        (status = OK, description = "The student withdrew from the elective", body = ResponseType<EmptyResponseData>),
+    ),
+)]
+#[delete("/{id}/enroll")]
+pub async fn withdraw_elective_subject(
+    data: Data<AppState>,
+    _: ApiKeyHeader,
+    LoggedIn(user): LoggedIn,
+    LoggedInStudent(student_id): LoggedInStudent,
+    elective_subject_session_id: Path<Uuid>,
+) -> Result<impl Responder> {
+    let mut transaction = data.db.begin().await?;
+    let elective_subject_session_id = elective_subject_session_id.into_inner();
+
+    // Checks if the student is "blacklisted" from enrolling in an elective
+    if DbElectiveSubject::is_student_blacklisted(&mut transaction, student_id).await? {
+        return Err(Error::BrokenRule(
+            ErrorCode::ElectiveBlacklisted,
+            format!("/subjects/electives/{elective_subject_session_id}/enroll"),
+        ));
+    }
+
+    // Checks if the current time is within the elective's enrollment period
+    if !DbElectiveSubject::is_enrollment_period(&mut transaction, student_id).await? {
+        return Err(Error::BrokenRule(
+            ErrorCode::ElectiveEnrollmentClosed,
+            format!("/subjects/electives/{elective_subject_session_id}/enroll"),
+        ));
+    }
+
+    // Refer to comment in `enroll_electives.rs` for a detailed explanation. The seat the student
+    // frees is handed to the first student on the waitlist in this transaction.
+    let lock_timer = METRICS
+        .advisory_lock_wait
+        .with_label_values(&["withdraw_electives"])
+        .start_timer();
+    DbElectiveSubject::lock_sessions(&mut transaction, &[elective_subject_session_id]).await?;
+    DbElectiveSubject::lock_students(&mut transaction, &[student_id]).await?;
+    lock_timer.observe_duration();
+
+    // Trade offers can't be carried out without the student's enrollment
+    let declined_offer_ids = query!(
+        "\
+        SELECT id FROM elective_subject_trade_offers \
+        WHERE status = $1 AND (sender_id = $2 OR receiver_id = $2) \
+        FOR UPDATE\
+        ",
+        SubmissionStatus::Pending as SubmissionStatus,
+        student_id,
+    )
+    .fetch_all(&mut *transaction)
+    .await?
+    .into_iter()
+    .map(|offer| offer.id)
+    .collect::<Vec<_>>();
+
+    // Checks if the student is enrolled in the elective they are withdrawing from
+    match DbElectiveSubject::is_currently_enrolled(&mut transaction, student_id).await? {
+        Some(current_elective_subject_id)
+            if current_elective_subject_id == elective_subject_session_id => {}
+        Some(_) => {
+            return Err(Error::BrokenRule(
+                ErrorCode::ElectiveEnrolledElsewhere,
+                format!("/subjects/electives/{elective_subject_session_id}/enroll"),
+            ));
+        }
+        None => {
+            return Err(Error::BrokenRule(
+                ErrorCode::ElectiveNotEnrolled,
+                format!("/subjects/electives/{elective_subject_session_id}/enroll"),
+            ));
+        }
+    }
+
+    let audit = AuditContext::new(&user, "DELETE /v1/subjects/electives/{id}/enroll");
+    let offer_snapshot = AuditSnapshot::take(
+        &mut transaction,
+        AuditEntity::ElectiveTradeOffer,
+        &declined_offer_ids,
+    )
+    .await?;
+    let mut enrollment_snapshot = AuditSnapshot::take(
+        &mut transaction,
+        AuditEntity::ElectiveEnrollment,
+        &[student_id],
+    )
+    .await?;
+
+    query!(
+        "UPDATE elective_subject_trade_offers SET status = $1 WHERE id = ANY($2)",
+        SubmissionStatus::Declined as SubmissionStatus,
+        &declined_offer_ids,
+    )
+    .execute(&mut *transaction)
+    .await?;
+
+    let deleted = query!(
+        "\
+        DELETE FROM elective_subject_session_enrolled_students \
+        WHERE student_id = $1 AND elective_subject_session_id = $2\
+        ",
+        student_id,
+        elective_subject_session_id,
+    )
+    .execute(&mut *transaction)
+    .await?;
+    if deleted.rows_affected() != 1 {
+        return Err(Error::BrokenRule(
+            ErrorCode::ElectiveNotEnrolled,
+            format!("/subjects/electives/{elective_subject_session_id}/enroll"),
+        ));
+    }
+    query!(
+        "\
+        INSERT INTO elective_subject_session_withdrawn_students \
+        (student_id, elective_subject_session_id) VALUES ($1, $2)\
+        ",
+        student_id,
+        elective_subject_session_id,
+    )
+    .execute(&mut *transaction)
+    .await?;
+
+    webhooks::enqueue(
+        &mut transaction,
+        WebhookEvent::ElectiveWithdrawn,
+        json!({
+            "student_id": student_id,
+            "elective_subject_session_id": elective_subject_session_id,
+            "declined_trade_offer_ids": declined_offer_ids,
+        }),
+    )
+    .await?;
+
+    let promoted =
+        DbElectiveWaitlistEntry::promote(&mut transaction, elective_subject_session_id).await?;
+    for promoted_student_id in &promoted {
+        enrollment_snapshot.track(*promoted_student_id);
+    }
+
+    audit.record(&mut transaction, offer_snapshot).await?;
+    audit.record(&mut transaction, enrollment_snapshot).await?;
+    transaction.commit().await?;
+    METRICS
+        .elective_enrollments
+        .with_label_values(&["withdraw"])
+        .inc();
+    if !promoted.is_empty() {
+        METRICS
+            .elective_enrollments
+            .with_label_values(&["waitlist"])
+            .inc_by(promoted.len() as u64);
+    }
+
+    let response = ResponseType::new(EmptyResponseData {}, None);
+
+    Ok(HttpResponse::Ok().json(response))
+}
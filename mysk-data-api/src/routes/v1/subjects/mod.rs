@@ -1,8 +1,18 @@
 use actix_web::web::{ServiceConfig, scope};
+use utoipa::OpenApi;
 
 pub mod attendance;
 pub mod electives;
 
+#[derive(OpenApi)]
+#[openapi(
+    nest(
+        (path = "/attendance", api = attendance::ApiDoc),
+        (path = "/electives", api = electives::ApiDoc),
+    ),
+)]
+pub struct ApiDoc;
+
 pub fn config(cfg: &mut ServiceConfig) {
     cfg.service(scope("/attendance").configure(attendance::config))
         .service(scope("/electives").configure(electives::config))
@@ -4,10 +4,15 @@ use crate::{
 };
 use actix_web::{
     HttpResponse, Responder, post,
-    web::{Data, Json, Path},
+    web::{Data, Path},
 };
 use mysk_lib::{
-    common::{requests::RequestType, response::ResponseType, string::MultiLangString},
+    common::{
+        requests::{Json, RequestType},
+        response::ResponseType,
+        string::MultiLangString,
+        validation::Validate,
+    },
     models::{
         contact::{Contact, db::DbContact},
         enums::ContactType,
@@ -19,15 +24,23 @@ use mysk_lib::{
 };
 use serde::Deserialize;
 use sqlx::query;
+use utoipa::ToSchema;
 use uuid::Uuid;
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, ToSchema, Validate)]
 struct TeacherContactRequest {
     name: MultiLangString,
     r#type: ContactType,
+    #[validate(contact_type = r#type)]
     value: String,
 }
 
+#[utoipa::path(
+    tag = "Teachers",
+    responses(
+        (status = OK, description = "The new contact", body = ResponseType<Contact>),
+    ),
+)]
 #[post("/{id}/contacts")]
 pub async fn create_teacher_contacts(
     data: Data<AppState>,
@@ -54,12 +67,13 @@ pub async fn create_teacher_contacts(
     for contact_id in existing_contacts {
         let contact = DbContact::get_by_id(&mut conn, contact_id).await?;
         if contact.r#type == teacher_contact.r#type && contact.value == teacher_contact.value {
-            return Err(Error::InvalidRequest(
-                "Contact with the same value already exists".to_string(),
+            return Err(Error::BrokenRule(
+                ErrorCode::ContactExists,
                 format!("/teachers/{teacher_id}/contacts"),
             ));
         }
     }
+    drop(conn);
 
     let mut transaction = pool.begin().await?;
 
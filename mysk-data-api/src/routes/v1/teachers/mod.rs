@@ -1,10 +1,24 @@
 use actix_web::web::ServiceConfig;
+use mysk_lib::models::teacher::request::{queryable::QueryableTeacher, sortable::SortableTeacher};
+use utoipa::OpenApi;
 
 pub mod create_teacher_contacts;
 pub mod modify_teacher;
 pub mod query_teacher_details;
 pub mod query_teachers;
 
+#[derive(OpenApi)]
+#[openapi(
+    paths(
+        create_teacher_contacts::create_teacher_contacts,
+        modify_teacher::modify_teacher,
+        query_teacher_details::query_teacher_details,
+        query_teachers::query_teachers,
+    ),
+    components(schemas(QueryableTeacher, SortableTeacher))
+)]
+pub struct ApiDoc;
+
 pub fn config(cfg: &mut ServiceConfig) {
     cfg.service(create_teacher_contacts::create_teacher_contacts)
         .service(modify_teacher::modify_teacher)
@@ -4,14 +4,21 @@ use crate::{
 };
 use actix_web::{
     HttpResponse, Responder, put,
-    web::{Data, Json, Path},
+    web::{Data, Path},
 };
 use chrono::NaiveDate;
 use mysk_lib::{
-    common::{requests::RequestType, response::ResponseType, string::FlexibleMultiLangString},
+    audit::{AuditContext, AuditEntity, AuditSnapshot},
+    common::{
+        requests::{Json, RequestType},
+        response::ResponseType,
+        string::FlexibleMultiLangString,
+        validation::Validate,
+    },
     helpers::date::get_current_academic_year,
     models::{
         enums::ShirtSize,
+        person::db::DbPerson,
         teacher::{Teacher, db::DbTeacher},
         traits::GetById as _,
     },
@@ -21,33 +28,46 @@ use mysk_lib::{
 };
 use serde::Deserialize;
 use sqlx::query;
+use utoipa::ToSchema;
 use uuid::Uuid;
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, ToSchema, Validate)]
 struct UpdateTeacherRequest {
+    #[validate(nested)]
     person: Option<UpdatePersonInfo>,
     teacher: Option<UpdateTeacherInfo>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, ToSchema, Validate)]
+#[schema(as = UpdateTeacherPersonInfo)]
 struct UpdatePersonInfo {
+    #[validate(not_empty)]
     prefix: Option<FlexibleMultiLangString>,
+    #[validate(not_empty)]
     first_name: Option<FlexibleMultiLangString>,
+    #[validate(not_empty)]
     last_name: Option<FlexibleMultiLangString>,
     middle_name: Option<FlexibleMultiLangString>,
     nickname: Option<FlexibleMultiLangString>,
+    #[validate(past)]
     birthdate: Option<NaiveDate>,
     allergies: Option<Vec<String>>,
     shirt_size: Option<ShirtSize>,
     pants_size: Option<String>,
 }
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, ToSchema)]
 struct UpdateTeacherInfo {
     subject_group_id: Option<i64>,
     advisor_at: Option<i64>,
 }
 
 #[allow(clippy::too_many_lines)]
+#[utoipa::path(
+    tag = "Teachers",
+    responses(
+        (status = OK, description = "The updated teacher", body = ResponseType<Teacher>),
+    ),
+)]
 #[put("/{id}")]
 pub async fn modify_teacher(
     data: Data<AppState>,
@@ -65,6 +85,7 @@ pub async fn modify_teacher(
     let mut conn = data.db.acquire().await?;
     let teacher_id = teacher_id.into_inner();
     let authorizer = Authorizer::new(&user, format!("teachers/{teacher_id}"));
+    let audit = AuditContext::new(&user, "PUT /v1/teachers/{id}");
 
     let db_teacher = DbTeacher::get_by_id(&mut conn, teacher_id).await?;
     let person_id = db_teacher
@@ -74,10 +95,17 @@ pub async fn modify_teacher(
     authorizer
         .authorize_teacher(&db_teacher, &mut conn, ActionType::Update)
         .await?;
+    drop(conn);
 
     // NOTE: Teacher-related updates
     if let Some(tu) = update_data.teacher {
         let mut teacher_transaction = pool.begin().await?;
+        let snapshot = AuditSnapshot::take(
+            &mut teacher_transaction,
+            AuditEntity::Teacher,
+            &[teacher_id],
+        )
+        .await?;
         let current_academic_year = get_current_academic_year(None);
 
         // Update subject group
@@ -86,7 +114,7 @@ pub async fn modify_teacher(
                 "SELECT id FROM subject_groups WHERE id = $1",
                 subject_group_id
             )
-            .fetch_one(&mut *conn)
+            .fetch_one(&mut *teacher_transaction)
             .await?;
 
             let current_subject_group = db_teacher.subject_group_id;
@@ -109,11 +137,11 @@ pub async fn modify_teacher(
                 class_advisor_at,
                 current_academic_year,
             )
-            .fetch_one(&mut *conn)
+            .fetch_one(&mut *teacher_transaction)
             .await?;
 
             let existing_advisor_at = DbTeacher::get_teacher_advisor_at(
-                &mut conn,
+                &mut teacher_transaction,
                 teacher_id,
                 Some(current_academic_year),
             )
@@ -151,12 +179,16 @@ pub async fn modify_teacher(
                 }
             }
         }
+
+        audit.record(&mut teacher_transaction, snapshot).await?;
         teacher_transaction.commit().await?;
     }
 
     // NOTE: Person-related updates
     if let Some(pu) = update_data.person {
         let mut person_transaction = pool.begin().await?;
+        let snapshot =
+            AuditSnapshot::take(&mut person_transaction, AuditEntity::Person, &[person_id]).await?;
 
         // Update allergies on a separate table `person_allergies`
         if let Some(allergies) = pu.allergies {
@@ -197,7 +229,9 @@ pub async fn modify_teacher(
             .execute(&mut *person_transaction)
             .await?;
 
+        audit.record(&mut person_transaction, snapshot).await?;
         person_transaction.commit().await?;
+        DbPerson::evict_cached(&[person_id]).await;
     }
 
     let teacher = Teacher::get_by_id(
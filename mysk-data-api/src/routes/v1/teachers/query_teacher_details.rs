@@ -14,6 +14,13 @@ use mysk_lib::{
 };
 use uuid::Uuid;
 
+#[utoipa::path(
+    tag = "Teachers",
+    params(RequestType),
+    responses(
+        (status = OK, description = "The teacher", body = ResponseType<Teacher>),
+    ),
+)]
 #[get("/{id}")]
 pub async fn query_teacher_details(
     data: Data<AppState>,
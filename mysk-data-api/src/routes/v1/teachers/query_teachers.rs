@@ -6,7 +6,7 @@ use actix_web::{HttpResponse, Responder, get, web::Data};
 use mysk_lib::{
     common::{
         requests::{EmptyRequestData, RequestType},
-        response::{MetadataType, ResponseType},
+        response::{MetadataType, ResponseListType, ResponseType},
     },
     models::teacher::{
         Teacher,
@@ -16,6 +16,13 @@ use mysk_lib::{
     prelude::*,
 };
 
+#[utoipa::path(
+    tag = "Teachers",
+    params(RequestType<EmptyRequestData, QueryableTeacher, SortableTeacher>),
+    responses(
+        (status = OK, description = "The matching teachers", body = ResponseListType<Teacher>),
+    ),
+)]
 #[get("")]
 pub async fn query_teachers(
     data: Data<AppState>,
@@ -0,0 +1,80 @@
+use actix_web::dev::ServerHandle;
+use mysk_lib::common::config::{Config, ReloadableConfig};
+use parking_lot::RwLock;
+use std::{io, sync::Arc};
+use tokio::signal::unix::{Signal, SignalKind, signal};
+use tracing_subscriber::{EnvFilter, Registry, reload};
+
+/// A handle to swap the `tracing` filter of the running subscriber.
+pub type LogFilterHandle = reload::Handle<EnvFilter, Registry>;
+
+/// Stops the server gracefully on SIGTERM or SIGINT, and reloads the [`ReloadableConfig`] on
+/// SIGHUP.
+pub struct SignalHandler {
+    sigterm: Signal,
+    sigint: Signal,
+    sighup: Signal,
+    reloadable: Arc<RwLock<ReloadableConfig>>,
+    log_filter: LogFilterHandle,
+}
+
+impl SignalHandler {
+    pub fn new(
+        reloadable: Arc<RwLock<ReloadableConfig>>,
+        log_filter: LogFilterHandle,
+    ) -> io::Result<Self> {
+        Ok(Self {
+            sigterm: signal(SignalKind::terminate())?,
+            sigint: signal(SignalKind::interrupt())?,
+            sighup: signal(SignalKind::hangup())?,
+            reloadable,
+            log_filter,
+        })
+    }
+
+    pub async fn run(mut self, server: ServerHandle) {
+        loop {
+            tokio::select! {
+                _ = self.sigterm.recv() => {
+                    tracing::info!("Received SIGTERM, draining in-flight requests");
+                    break;
+                }
+                _ = self.sigint.recv() => {
+                    tracing::info!("Received SIGINT, draining in-flight requests");
+                    break;
+                }
+                _ = self.sighup.recv() => self.reload(),
+            }
+        }
+
+        // Stops accepting connections and waits for in-flight requests up to the shutdown timeout
+        server.stop(true).await;
+    }
+
+    /// Re-reads the configuration and applies its reloadable parts. The current configuration is
+    /// kept if the new one is invalid.
+    fn reload(&self) {
+        let reloadable = match Config::load() {
+            Ok(config) => config.reloadable(),
+            Err(err) => {
+                tracing::error!("Failed to reload the configuration: {err:#}");
+                return;
+            }
+        };
+        let log_filter = match EnvFilter::builder().parse(&reloadable.log_filter) {
+            Ok(log_filter) => log_filter,
+            Err(err) => {
+                tracing::error!("Failed to reload the configuration: LOG_FILTER is invalid: {err}");
+                return;
+            }
+        };
+
+        if let Err(err) = self.log_filter.reload(log_filter) {
+            tracing::error!("Failed to reload the log filter: {err}");
+            return;
+        }
+        *self.reloadable.write() = reloadable;
+
+        tracing::info!("Reloaded the configuration");
+    }
+}
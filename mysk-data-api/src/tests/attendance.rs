@@ -0,0 +1,161 @@
+use crate::{
+    background::BackgroundTasks,
+    jaturamitr_sync::JaturamitrSync,
+    tests::{TestApp, TestUser},
+};
+use actix_web::{body::MessageBody, http::StatusCode, test};
+use mysk_lib::cache::GlobalCache;
+use serde_json::Value;
+use sqlx::{query, query_scalar};
+use std::{future, pin::pin, sync::Arc, time::Duration};
+use tokio::time::timeout;
+use uuid::{Uuid, uuid};
+
+const CLASSROOM_101_ID: Uuid = uuid!("00000000-0000-0000-0000-00000000e101");
+
+async fn create_practice_period(app: &TestApp) -> Uuid {
+    let practice_period_id = query_scalar(
+        "\
+        INSERT INTO cheer_practice_periods (date, start_time, end_time) \
+        VALUES (CURRENT_DATE, '07:00', '08:00') RETURNING id\
+        ",
+    )
+    .fetch_one(app.pool())
+    .await
+    .unwrap();
+    query(
+        "\
+        INSERT INTO cheer_practice_period_classrooms (practice_period_id, classroom_id) \
+        VALUES ($1, $2)\
+        ",
+    )
+    .bind(practice_period_id)
+    .bind(CLASSROOM_101_ID)
+    .execute(app.pool())
+    .await
+    .unwrap();
+
+    practice_period_id
+}
+
+#[actix_web::test]
+#[ignore = "needs a Postgres server at TEST_DATABASE_URL"]
+async fn streams_attendances_as_they_are_checked() {
+    let app = TestApp::spawn().await;
+    let service = app.service().await;
+    let practice_period_id = create_practice_period(&app).await;
+
+    let req = app
+        .login(TestUser::Admin)
+        .await
+        .authorize(test::TestRequest::get().uri(&format!(
+            "/v1/attendance/cheer/periods/{practice_period_id}/stream?classroom_id={CLASSROOM_101_ID}"
+        )))
+        .to_request();
+    let res = test::call_service(&service, req).await;
+    assert_eq!(res.status(), StatusCode::OK);
+    let mut body = pin!(res.into_body());
+    let mut next_chunk = async || {
+        let chunk = future::poll_fn(|cx| body.as_mut().poll_next(cx)).await;
+        let Some(Ok(chunk)) = chunk else {
+            panic!("The stream ended");
+        };
+        String::from_utf8(chunk.to_vec()).unwrap()
+    };
+    assert_eq!(next_chunk().await, ": open\n\n");
+
+    // The feed may still be starting to listen, so the check is repeated until it is streamed
+    for _ in 0..20 {
+        query(
+            "\
+            UPDATE cheer_practice_attendances SET checker_id = $1, presence = 'late' \
+            WHERE practice_period_id = $2 AND student_id = $3\
+            ",
+        )
+        .bind(TestUser::Teacher.user_id())
+        .bind(practice_period_id)
+        .bind(TestUser::StudentA.student_id())
+        .execute(app.pool())
+        .await
+        .unwrap();
+
+        let Ok(chunk) = timeout(Duration::from_millis(500), next_chunk()).await else {
+            continue;
+        };
+        let data = chunk
+            .strip_prefix("event: attendance\ndata: ")
+            .and_then(|chunk| chunk.strip_suffix("\n\n"))
+            .unwrap();
+        let event: Value = serde_json::from_str(data).unwrap();
+        assert_eq!(
+            event["student_id"],
+            TestUser::StudentA.student_id().to_string()
+        );
+        assert_eq!(event["classroom_id"], CLASSROOM_101_ID.to_string());
+        assert_eq!(event["checker_id"], TestUser::Teacher.user_id().to_string());
+        assert_eq!(event["presence"], "late");
+
+        return;
+    }
+    panic!("The checked attendance was never streamed");
+}
+
+#[actix_web::test]
+#[ignore = "needs a Postgres server at TEST_DATABASE_URL"]
+async fn only_staff_can_watch_attendances() {
+    let app = TestApp::spawn().await;
+    let service = app.service().await;
+    let practice_period_id = create_practice_period(&app).await;
+
+    let req = app
+        .login(TestUser::StudentA)
+        .await
+        .authorize(test::TestRequest::get().uri(&format!(
+            "/v1/attendance/cheer/periods/{practice_period_id}/stream"
+        )))
+        .to_request();
+    let res = test::call_service(&service, req).await;
+
+    assert_eq!(res.status(), StatusCode::FORBIDDEN);
+}
+
+#[actix_web::test]
+#[ignore = "needs a Postgres server at TEST_DATABASE_URL"]
+async fn jaturamitr_periods_are_synced_across_instances() {
+    let app = TestApp::spawn().await;
+    let practice_period_id = create_practice_period(&app).await;
+    let set_jaturamitr = async |is_jaturamitr: bool| {
+        query("UPDATE cheer_practice_periods SET is_jaturamitr = $1 WHERE id = $2")
+            .bind(is_jaturamitr)
+            .bind(practice_period_id)
+            .execute(app.pool())
+            .await
+            .unwrap();
+    };
+
+    // The cache of another instance, which only loads the periods once it is listening
+    let cache = GlobalCache::new();
+    let mut background_tasks = BackgroundTasks::new();
+    let wait_until_synced = async |is_jaturamitr: bool| {
+        timeout(Duration::from_secs(5), async {
+            while cache.contains_jaturamitr_period(practice_period_id) != is_jaturamitr {
+                tokio::time::sleep(Duration::from_millis(50)).await;
+            }
+        })
+        .await
+        .expect("The cache was never synced");
+    };
+    set_jaturamitr(true).await;
+    JaturamitrSync::spawn(
+        app.pool().clone(),
+        Arc::clone(&cache),
+        &mut background_tasks,
+    );
+    wait_until_synced(true).await;
+
+    // From here on, the cache only learns of changes through notifications
+    set_jaturamitr(false).await;
+    wait_until_synced(false).await;
+    set_jaturamitr(true).await;
+    wait_until_synced(true).await;
+}
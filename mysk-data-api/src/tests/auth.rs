@@ -0,0 +1,96 @@
+use crate::tests::{Credentials, TestApp, TestUser, fixtures::ASTRONOMY_SESSION_ID};
+use actix_web::{http::StatusCode, test};
+use serde_json::{Value, json};
+use sqlx::query_as;
+use uuid::Uuid;
+
+#[actix_web::test]
+#[ignore = "needs a Postgres server at TEST_DATABASE_URL"]
+async fn rejects_requests_without_an_api_key() {
+    let app = TestApp::spawn().await;
+    let service = app.service().await;
+
+    let req = test::TestRequest::get()
+        .uri("/v1/admin/error-logs")
+        .to_request();
+    let res = test::call_service(&service, req).await;
+
+    assert_eq!(res.status(), StatusCode::UNAUTHORIZED);
+}
+
+#[actix_web::test]
+#[ignore = "needs a Postgres server at TEST_DATABASE_URL"]
+async fn admin_routes_require_an_admin() {
+    let app = TestApp::spawn().await;
+    let service = app.service().await;
+
+    for (user, status) in [
+        (TestUser::StudentA, StatusCode::FORBIDDEN),
+        (TestUser::Teacher, StatusCode::FORBIDDEN),
+        (TestUser::Admin, StatusCode::OK),
+    ] {
+        let credentials = app.login(user).await;
+        let req = credentials
+            .authorize(test::TestRequest::get().uri("/v1/admin/error-logs"))
+            .to_request();
+        let res = test::call_service(&service, req).await;
+
+        assert_eq!(res.status(), status, "{user:?}");
+    }
+}
+
+#[actix_web::test]
+#[ignore = "needs a Postgres server at TEST_DATABASE_URL"]
+async fn changes_made_while_impersonating_record_the_admin() {
+    let app = TestApp::spawn().await;
+    let service = app.service().await;
+    let admin = app.login(TestUser::Admin).await;
+
+    let req = admin
+        .authorize(test::TestRequest::post().uri("/v1/admin/impersonate"))
+        .set_json(json!({ "data": { "user_id": TestUser::StudentA.user_id() } }))
+        .to_request();
+    let body: Value = test::call_and_read_body_json(&service, req).await;
+    let impersonation = Credentials {
+        api_key: admin.api_key.clone(),
+        token: body["data"]["access_token"].as_str().unwrap().to_string(),
+    };
+
+    let req = impersonation
+        .authorize(test::TestRequest::post().uri(&format!(
+            "/v1/subjects/electives/{ASTRONOMY_SESSION_ID}/enroll"
+        )))
+        .set_json(json!({}))
+        .to_request();
+    let res = test::call_service(&service, req).await;
+    assert_eq!(res.status(), StatusCode::OK);
+
+    let (actor_id, impersonator_id): (Option<Uuid>, Option<Uuid>) = query_as(
+        "SELECT actor_id, impersonator_id FROM api_logging.audit_logs WHERE entity_id = $1",
+    )
+    .bind(TestUser::StudentA.student_id())
+    .fetch_one(app.pool())
+    .await
+    .unwrap();
+    assert_eq!(actor_id, Some(TestUser::StudentA.user_id()));
+    assert_eq!(impersonator_id, Some(TestUser::Admin.user_id()));
+}
+
+#[actix_web::test]
+#[ignore = "needs a Postgres server at TEST_DATABASE_URL"]
+async fn admins_cannot_be_impersonated() {
+    let app = TestApp::spawn().await;
+    let service = app.service().await;
+
+    let req = app
+        .login(TestUser::Admin)
+        .await
+        .authorize(test::TestRequest::post().uri("/v1/admin/impersonate"))
+        .set_json(json!({ "data": { "user_id": TestUser::Admin.user_id() } }))
+        .to_request();
+    let res = test::call_service(&service, req).await;
+
+    assert_eq!(res.status(), StatusCode::FORBIDDEN);
+    let body: Value = test::read_body_json(res).await;
+    assert_eq!(body["error"]["error_code"], "impersonate_admin");
+}
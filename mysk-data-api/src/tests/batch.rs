@@ -0,0 +1,256 @@
+use crate::{
+    metrics::METRICS,
+    tests::{
+        TestApp, TestUser,
+        fixtures::{ASTRONOMY_SESSION_ID, MARINE_BIOLOGY_SESSION_ID},
+    },
+};
+use actix_web::{http::StatusCode, test};
+use serde_json::{Value, json};
+use sqlx::{PgPool, query_scalar};
+use uuid::Uuid;
+
+async fn enrollment_count(pool: &PgPool, student_id: Uuid) -> i64 {
+    query_scalar(
+        "SELECT COUNT(*) FROM elective_subject_session_enrolled_students WHERE student_id = $1",
+    )
+    .bind(student_id)
+    .fetch_one(pool)
+    .await
+    .unwrap()
+}
+
+#[actix_web::test]
+#[ignore = "needs a Postgres server at TEST_DATABASE_URL"]
+async fn runs_each_operation_with_the_batch_credentials() {
+    let app = TestApp::spawn().await;
+    let service = app.service().await;
+
+    let req = app
+        .login(TestUser::StudentA)
+        .await
+        .authorize(test::TestRequest::post().uri("/v1/batch").set_json(json!({
+            "data": {
+                "operations": [
+                    { "method": "GET", "path": "/auth/user" },
+                    {
+                        "method": "POST",
+                        "path": format!("/v1/subjects/electives/{ASTRONOMY_SESSION_ID}/enroll"),
+                        "body": {},
+                    },
+                    { "method": "GET", "path": "/v1/admin/error-logs" },
+                ],
+            },
+        })))
+        .to_request();
+    let res = test::call_service(&service, req).await;
+    assert_eq!(res.status(), StatusCode::OK);
+
+    let body: Value = test::read_body_json(res).await;
+    let results = body["data"].as_array().unwrap();
+    let statuses: Vec<_> = results.iter().map(|result| &result["status"]).collect();
+    assert_eq!(statuses, [200, 200, 403]);
+    assert_eq!(
+        results[0]["body"]["data"]["id"],
+        TestUser::StudentA.user_id().to_string(),
+    );
+    assert_eq!(
+        results[1]["body"]["data"]["id"],
+        ASTRONOMY_SESSION_ID.to_string(),
+    );
+}
+
+#[actix_web::test]
+#[ignore = "needs a Postgres server at TEST_DATABASE_URL"]
+async fn rejects_nested_batches() {
+    let app = TestApp::spawn().await;
+    let service = app.service().await;
+
+    let req = app
+        .login(TestUser::StudentA)
+        .await
+        .authorize(test::TestRequest::post().uri("/v1/batch").set_json(json!({
+            "data": {
+                "operations": [
+                    { "method": "POST", "path": "/v1/batch", "body": { "data": { "operations": [] } } },
+                ],
+            },
+        })))
+        .to_request();
+    let body: Value = test::call_and_read_body_json(&service, req).await;
+
+    assert_eq!(body["data"][0]["status"], 400);
+}
+
+#[actix_web::test]
+#[ignore = "needs a Postgres server at TEST_DATABASE_URL"]
+async fn operations_go_through_the_middlewares() {
+    let app = TestApp::spawn().await;
+    let service = app.service().await;
+    // No other test lists the clubs
+    let club_requests =
+        METRICS
+            .http_request_duration
+            .with_label_values(&["GET", "/v1/clubs", "200"]);
+    let requested_before = club_requests.get_sample_count();
+
+    let req = app
+        .login(TestUser::StudentA)
+        .await
+        .authorize(test::TestRequest::post().uri("/v1/batch").set_json(json!({
+            "data": {
+                "operations": [{ "method": "GET", "path": "/v1/clubs/?fetch_level=id_only" }],
+            },
+        })))
+        .to_request();
+    let body: Value = test::call_and_read_body_json(&service, req).await;
+
+    // The trailing slash is trimmed and the operation recorded in the metrics
+    assert_eq!(body["data"][0]["status"], 200);
+    assert_eq!(club_requests.get_sample_count(), requested_before + 1);
+}
+
+#[actix_web::test]
+#[ignore = "needs a Postgres server at TEST_DATABASE_URL"]
+async fn atomic_batches_commit_once_every_operation_succeeds() {
+    let app = TestApp::spawn().await;
+    let service = app.service().await;
+    let student_id = TestUser::StudentA.student_id();
+
+    let req = app
+        .login(TestUser::StudentA)
+        .await
+        .authorize(test::TestRequest::post().uri("/v1/batch").set_json(json!({
+            "data": {
+                "operations": [
+                    {
+                        "method": "POST",
+                        "path": format!("/v1/subjects/electives/{ASTRONOMY_SESSION_ID}/enroll"),
+                        "body": {},
+                    },
+                    {
+                        "method": "GET",
+                        "path": format!("/v1/subjects/electives/{ASTRONOMY_SESSION_ID}?fetch_level=detailed"),
+                    },
+                ],
+                "atomic": true,
+            },
+        })))
+        .to_request();
+    let body: Value = test::call_and_read_body_json(&service, req).await;
+
+    let results = body["data"].as_array().unwrap();
+    let statuses: Vec<_> = results.iter().map(|result| &result["status"]).collect();
+    assert_eq!(statuses, [200, 200]);
+    // Reads see the writes of the operations before them
+    assert!(
+        results[1]["body"]["data"]["students"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .any(|student| student["id"] == student_id.to_string()),
+    );
+    assert_eq!(enrollment_count(app.pool(), student_id).await, 1);
+}
+
+#[actix_web::test]
+#[ignore = "needs a Postgres server at TEST_DATABASE_URL"]
+async fn atomic_batches_roll_back_when_an_operation_fails() {
+    let app = TestApp::spawn().await;
+    let service = app.service().await;
+
+    let req = app
+        .login(TestUser::StudentA)
+        .await
+        .authorize(test::TestRequest::post().uri("/v1/batch").set_json(json!({
+            "data": {
+                "operations": [
+                    {
+                        "method": "POST",
+                        "path": format!("/v1/subjects/electives/{ASTRONOMY_SESSION_ID}/enroll"),
+                        "body": {},
+                    },
+                    // The student is already enrolled by then
+                    {
+                        "method": "POST",
+                        "path": format!("/v1/subjects/electives/{MARINE_BIOLOGY_SESSION_ID}/enroll"),
+                        "body": {},
+                    },
+                    { "method": "GET", "path": "/auth/user" },
+                ],
+                "atomic": true,
+            },
+        })))
+        .to_request();
+    let body: Value = test::call_and_read_body_json(&service, req).await;
+
+    let results = body["data"].as_array().unwrap();
+    assert_eq!(results.len(), 2);
+    assert_eq!(results[0]["status"], 200);
+    assert_eq!(results[1]["status"], 403);
+    assert_eq!(
+        enrollment_count(app.pool(), TestUser::StudentA.student_id()).await,
+        0,
+    );
+}
+
+#[actix_web::test]
+#[ignore = "needs a Postgres server at TEST_DATABASE_URL"]
+async fn atomic_batches_only_update_caches_once_committed() {
+    let app = TestApp::spawn().await;
+    let service = app.service().await;
+    let practice_period_id: Uuid = query_scalar(
+        "\
+        INSERT INTO cheer_practice_periods (date, start_time, end_time) \
+        VALUES (CURRENT_DATE, '07:00', '08:00') RETURNING id\
+        ",
+    )
+    .fetch_one(app.pool())
+    .await
+    .unwrap();
+    let admin = app.login(TestUser::Admin).await;
+    let set_jaturamitr = |then: Value| {
+        admin
+            .authorize(test::TestRequest::post().uri("/v1/batch").set_json(json!({
+                "data": {
+                    "operations": [
+                        {
+                            "method": "PUT",
+                            "path": format!(
+                                "/v1/attendance/cheer/periods/{practice_period_id}/jaturamitr"
+                            ),
+                            "body": { "data": { "is_jaturamitr": true } },
+                        },
+                        then,
+                    ],
+                    "atomic": true,
+                },
+            })))
+            .to_request()
+    };
+
+    let body: Value = test::call_and_read_body_json(
+        &service,
+        set_jaturamitr(json!({ "method": "GET", "path": "/v1/nowhere" })),
+    )
+    .await;
+    assert_eq!(body["data"][0]["status"], 200);
+    assert_eq!(body["data"][1]["status"], 404);
+    assert!(
+        !app.state
+            .cache
+            .contains_jaturamitr_period(practice_period_id)
+    );
+
+    let body: Value = test::call_and_read_body_json(
+        &service,
+        set_jaturamitr(json!({ "method": "GET", "path": "/auth/user" })),
+    )
+    .await;
+    assert_eq!(body["data"][1]["status"], 200);
+    assert!(
+        app.state
+            .cache
+            .contains_jaturamitr_period(practice_period_id)
+    );
+}
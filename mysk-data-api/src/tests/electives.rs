@@ -0,0 +1,817 @@
+use crate::{
+    tests::{
+        Credentials, TestApp, TestUser,
+        fixtures::{ASTRONOMY_SESSION_ID, MARINE_BIOLOGY_SESSION_ID, ROBOTICS_SESSION_ID},
+    },
+    trade_offer_sweeper::TradeOfferSweeper,
+};
+use actix_web::{http::StatusCode, test};
+use mysk_lib::models::enums::{SubmissionStatus, TradeOfferDeclineReason};
+use serde_json::{Value, json};
+use sqlx::{PgPool, query, query_scalar};
+use uuid::Uuid;
+
+async fn enroll(pool: &PgPool, student_id: Uuid, session_id: Uuid) {
+    query(
+        "\
+        INSERT INTO elective_subject_session_enrolled_students \
+        (student_id, elective_subject_session_id) VALUES ($1, $2)\
+        ",
+    )
+    .bind(student_id)
+    .bind(session_id)
+    .execute(pool)
+    .await
+    .unwrap();
+}
+
+async fn enrolled_session(pool: &PgPool, student_id: Uuid) -> Uuid {
+    query_scalar(
+        "\
+        SELECT elective_subject_session_id FROM elective_subject_session_enrolled_students \
+        WHERE student_id = $1\
+        ",
+    )
+    .bind(student_id)
+    .fetch_one(pool)
+    .await
+    .unwrap()
+}
+
+async fn create_trade_offer(pool: &PgPool, sender: TestUser, receiver: TestUser) -> Uuid {
+    query_scalar(
+        "\
+        INSERT INTO elective_subject_trade_offers (\
+            sender_id, receiver_id, sender_elective_subject_session_id, \
+            receiver_elective_subject_session_id\
+        ) \
+        SELECT $1, $2, sender.elective_subject_session_id, receiver.elective_subject_session_id \
+        FROM elective_subject_session_enrolled_students AS sender, \
+            elective_subject_session_enrolled_students AS receiver \
+        WHERE sender.student_id = $1 AND receiver.student_id = $2 \
+        RETURNING id\
+        ",
+    )
+    .bind(sender.student_id())
+    .bind(receiver.student_id())
+    .fetch_one(pool)
+    .await
+    .unwrap()
+}
+
+async fn trade_offer_status(pool: &PgPool, trade_offer_id: Uuid) -> SubmissionStatus {
+    query_scalar("SELECT status FROM elective_subject_trade_offers WHERE id = $1")
+        .bind(trade_offer_id)
+        .fetch_one(pool)
+        .await
+        .unwrap()
+}
+
+async fn trade_offer_decline_reason(
+    pool: &PgPool,
+    trade_offer_id: Uuid,
+) -> Option<TradeOfferDeclineReason> {
+    query_scalar("SELECT decline_reason FROM elective_subject_trade_offers WHERE id = $1")
+        .bind(trade_offer_id)
+        .fetch_one(pool)
+        .await
+        .unwrap()
+}
+
+#[actix_web::test]
+#[ignore = "needs a Postgres server at TEST_DATABASE_URL"]
+async fn concurrent_enrollments_respect_the_cap_size() {
+    let app = TestApp::spawn().await;
+    let service = app.service().await;
+    let (student_a, student_b) = (
+        app.login(TestUser::StudentA).await,
+        app.login(TestUser::StudentB).await,
+    );
+
+    // Both students race for the only seat
+    let enroll = |credentials: &Credentials| {
+        let req = credentials
+            .authorize(
+                test::TestRequest::post()
+                    .uri(&format!(
+                        "/v1/subjects/electives/{ROBOTICS_SESSION_ID}/enroll"
+                    ))
+                    .set_json(json!({})),
+            )
+            .to_request();
+
+        test::call_service(&service, req)
+    };
+    let (res_a, res_b) = tokio::join!(enroll(&student_a), enroll(&student_b));
+
+    let mut statuses = [res_a.status(), res_b.status()];
+    statuses.sort();
+    assert_eq!(statuses, [StatusCode::OK, StatusCode::FORBIDDEN]);
+
+    let class_size: i64 = query_scalar(
+        "\
+        SELECT COUNT(*) FROM elective_subject_session_enrolled_students \
+        WHERE elective_subject_session_id = $1\
+        ",
+    )
+    .bind(ROBOTICS_SESSION_ID)
+    .fetch_one(app.pool())
+    .await
+    .unwrap();
+    assert_eq!(class_size, 1);
+}
+
+#[actix_web::test]
+#[ignore = "needs a Postgres server at TEST_DATABASE_URL"]
+async fn approving_a_trade_offer_swaps_sessions() {
+    let app = TestApp::spawn().await;
+    let pool = app.pool();
+    let service = app.service().await;
+
+    enroll(pool, TestUser::StudentA.student_id(), ASTRONOMY_SESSION_ID).await;
+    enroll(
+        pool,
+        TestUser::StudentB.student_id(),
+        MARINE_BIOLOGY_SESSION_ID,
+    )
+    .await;
+    enroll(pool, TestUser::StudentC.student_id(), ASTRONOMY_SESSION_ID).await;
+    let approved_offer_id = create_trade_offer(pool, TestUser::StudentA, TestUser::StudentB).await;
+    let competing_offer_id = create_trade_offer(pool, TestUser::StudentC, TestUser::StudentB).await;
+
+    let req = app
+        .login(TestUser::StudentB)
+        .await
+        .authorize(
+            test::TestRequest::put()
+                .uri(&format!(
+                    "/v1/subjects/electives/trade-offers/{approved_offer_id}"
+                ))
+                .set_json(json!({ "data": { "status": "approved" } })),
+        )
+        .to_request();
+    let res = test::call_service(&service, req).await;
+    assert_eq!(res.status(), StatusCode::OK);
+
+    assert_eq!(
+        enrolled_session(pool, TestUser::StudentA.student_id()).await,
+        MARINE_BIOLOGY_SESSION_ID,
+    );
+    assert_eq!(
+        enrolled_session(pool, TestUser::StudentB.student_id()).await,
+        ASTRONOMY_SESSION_ID,
+    );
+    assert_eq!(
+        enrolled_session(pool, TestUser::StudentC.student_id()).await,
+        ASTRONOMY_SESSION_ID,
+    );
+    assert!(matches!(
+        trade_offer_status(pool, approved_offer_id).await,
+        SubmissionStatus::Approved,
+    ));
+    assert!(matches!(
+        trade_offer_status(pool, competing_offer_id).await,
+        SubmissionStatus::Declined,
+    ));
+}
+
+#[actix_web::test]
+#[ignore = "needs a Postgres server at TEST_DATABASE_URL"]
+async fn senders_cannot_approve_their_own_trade_offer() {
+    let app = TestApp::spawn().await;
+    let pool = app.pool();
+    let service = app.service().await;
+
+    enroll(pool, TestUser::StudentA.student_id(), ASTRONOMY_SESSION_ID).await;
+    enroll(
+        pool,
+        TestUser::StudentB.student_id(),
+        MARINE_BIOLOGY_SESSION_ID,
+    )
+    .await;
+    let trade_offer_id = create_trade_offer(pool, TestUser::StudentA, TestUser::StudentB).await;
+
+    let req = app
+        .login(TestUser::StudentA)
+        .await
+        .authorize(
+            test::TestRequest::put()
+                .uri(&format!(
+                    "/v1/subjects/electives/trade-offers/{trade_offer_id}"
+                ))
+                .set_json(json!({ "data": { "status": "approved" } })),
+        )
+        .to_request();
+    let res = test::call_service(&service, req).await;
+
+    assert_eq!(res.status(), StatusCode::FORBIDDEN);
+    let body: Value = test::read_body_json(res).await;
+    assert_eq!(body["error"]["error_type"], "invalid_permission");
+    assert_eq!(body["error"]["error_code"], "trade_offer_own_approval");
+    assert!(matches!(
+        trade_offer_status(pool, trade_offer_id).await,
+        SubmissionStatus::Pending,
+    ));
+    assert_eq!(
+        enrolled_session(pool, TestUser::StudentA.student_id()).await,
+        ASTRONOMY_SESSION_ID,
+    );
+}
+
+#[actix_web::test]
+#[ignore = "needs a Postgres server at TEST_DATABASE_URL"]
+async fn waitlisted_students_take_seats_that_open_up() {
+    let app = TestApp::spawn().await;
+    let pool = app.pool();
+    let service = app.service().await;
+
+    enroll(pool, TestUser::StudentA.student_id(), ROBOTICS_SESSION_ID).await;
+
+    let req = app
+        .login(TestUser::StudentB)
+        .await
+        .authorize(
+            test::TestRequest::post()
+                .uri(&format!(
+                    "/v1/subjects/electives/{ROBOTICS_SESSION_ID}/waitlist"
+                ))
+                .set_json(json!({})),
+        )
+        .to_request();
+    let res = test::call_service(&service, req).await;
+    assert_eq!(res.status(), StatusCode::OK);
+    let body: Value = test::read_body_json(res).await;
+    assert_eq!(body["data"]["position"], 1);
+
+    // Student A leaves the only seat for another elective
+    let req = app
+        .login(TestUser::StudentA)
+        .await
+        .authorize(
+            test::TestRequest::put()
+                .uri(&format!(
+                    "/v1/subjects/electives/{ASTRONOMY_SESSION_ID}/enroll"
+                ))
+                .set_json(json!({})),
+        )
+        .to_request();
+    let res = test::call_service(&service, req).await;
+    assert_eq!(res.status(), StatusCode::OK);
+
+    assert_eq!(
+        enrolled_session(pool, TestUser::StudentB.student_id()).await,
+        ROBOTICS_SESSION_ID,
+    );
+    let waitlist_size: i64 =
+        query_scalar("SELECT COUNT(*) FROM elective_subject_session_waitlisted_students")
+            .fetch_one(pool)
+            .await
+            .unwrap();
+    assert_eq!(waitlist_size, 0);
+}
+
+#[actix_web::test]
+#[ignore = "needs a Postgres server at TEST_DATABASE_URL"]
+async fn withdrawing_declines_pending_trade_offers() {
+    let app = TestApp::spawn().await;
+    let pool = app.pool();
+    let service = app.service().await;
+
+    enroll(pool, TestUser::StudentA.student_id(), ASTRONOMY_SESSION_ID).await;
+    enroll(
+        pool,
+        TestUser::StudentB.student_id(),
+        MARINE_BIOLOGY_SESSION_ID,
+    )
+    .await;
+    let trade_offer_id = create_trade_offer(pool, TestUser::StudentA, TestUser::StudentB).await;
+
+    let req = app
+        .login(TestUser::StudentA)
+        .await
+        .authorize(test::TestRequest::delete().uri(&format!(
+            "/v1/subjects/electives/{ASTRONOMY_SESSION_ID}/enroll"
+        )))
+        .to_request();
+    let res = test::call_service(&service, req).await;
+    assert_eq!(res.status(), StatusCode::OK);
+
+    let is_enrolled: bool = query_scalar(
+        "SELECT EXISTS (SELECT FROM elective_subject_session_enrolled_students WHERE student_id = $1)",
+    )
+    .bind(TestUser::StudentA.student_id())
+    .fetch_one(pool)
+    .await
+    .unwrap();
+    assert!(!is_enrolled);
+    assert!(matches!(
+        trade_offer_status(pool, trade_offer_id).await,
+        SubmissionStatus::Declined,
+    ));
+}
+
+#[actix_web::test]
+#[ignore = "needs a Postgres server at TEST_DATABASE_URL"]
+async fn withdrawing_gives_the_seat_to_the_waitlist() {
+    let app = TestApp::spawn().await;
+    let pool = app.pool();
+    let service = app.service().await;
+
+    enroll(pool, TestUser::StudentA.student_id(), ROBOTICS_SESSION_ID).await;
+
+    let req = app
+        .login(TestUser::StudentB)
+        .await
+        .authorize(
+            test::TestRequest::post()
+                .uri(&format!(
+                    "/v1/subjects/electives/{ROBOTICS_SESSION_ID}/waitlist"
+                ))
+                .set_json(json!({})),
+        )
+        .to_request();
+    let res = test::call_service(&service, req).await;
+    assert_eq!(res.status(), StatusCode::OK);
+
+    let req = app
+        .login(TestUser::StudentA)
+        .await
+        .authorize(test::TestRequest::delete().uri(&format!(
+            "/v1/subjects/electives/{ROBOTICS_SESSION_ID}/enroll"
+        )))
+        .to_request();
+    let res = test::call_service(&service, req).await;
+    assert_eq!(res.status(), StatusCode::OK);
+
+    assert_eq!(
+        enrolled_session(pool, TestUser::StudentB.student_id()).await,
+        ROBOTICS_SESSION_ID,
+    );
+    let waitlist_size: i64 =
+        query_scalar("SELECT COUNT(*) FROM elective_subject_session_waitlisted_students")
+            .fetch_one(pool)
+            .await
+            .unwrap();
+    assert_eq!(waitlist_size, 0);
+}
+
+#[actix_web::test]
+#[ignore = "needs a Postgres server at TEST_DATABASE_URL"]
+async fn students_can_enroll_again_after_withdrawing() {
+    let app = TestApp::spawn().await;
+    let pool = app.pool();
+    let service = app.service().await;
+
+    enroll(pool, TestUser::StudentA.student_id(), ASTRONOMY_SESSION_ID).await;
+    let student = app.login(TestUser::StudentA).await;
+
+    let req = student
+        .authorize(test::TestRequest::delete().uri(&format!(
+            "/v1/subjects/electives/{ASTRONOMY_SESSION_ID}/enroll"
+        )))
+        .to_request();
+    let res = test::call_service(&service, req).await;
+    assert_eq!(res.status(), StatusCode::OK);
+
+    // A withdrawal isn't a previous enrollment, so the same session can be taken again
+    let req = student
+        .authorize(
+            test::TestRequest::post()
+                .uri(&format!(
+                    "/v1/subjects/electives/{ASTRONOMY_SESSION_ID}/enroll"
+                ))
+                .set_json(json!({})),
+        )
+        .to_request();
+    let res = test::call_service(&service, req).await;
+    assert_eq!(res.status(), StatusCode::OK);
+
+    assert_eq!(
+        enrolled_session(pool, TestUser::StudentA.student_id()).await,
+        ASTRONOMY_SESSION_ID,
+    );
+}
+
+#[actix_web::test]
+#[ignore = "needs a Postgres server at TEST_DATABASE_URL"]
+async fn allocation_runs_are_reproducible_from_their_seed() {
+    let app = TestApp::spawn().await;
+    let pool = app.pool();
+    let service = app.service().await;
+
+    // Both students want the only seat in robotics most
+    for student in [TestUser::StudentA, TestUser::StudentB] {
+        let req = app
+            .login(student)
+            .await
+            .authorize(
+                test::TestRequest::put()
+                    .uri("/v1/subjects/electives/preferences")
+                    .set_json(json!({
+                        "data": {
+                            "elective_subject_session_ids": [
+                                ROBOTICS_SESSION_ID,
+                                MARINE_BIOLOGY_SESSION_ID,
+                            ],
+                        },
+                    })),
+            )
+            .to_request();
+        let res = test::call_service(&service, req).await;
+        assert_eq!(res.status(), StatusCode::OK);
+    }
+
+    let admin = app.login(TestUser::Admin).await;
+    let allocate = |dry_run: bool| {
+        let req = admin
+            .authorize(
+                test::TestRequest::post()
+                    .uri("/v1/admin/electives/allocate")
+                    .set_json(json!({ "data": { "seed": 42, "dry_run": dry_run } })),
+            )
+            .to_request();
+
+        test::call_service(&service, req)
+    };
+
+    let res = allocate(true).await;
+    assert_eq!(res.status(), StatusCode::OK);
+    let dry_run: Value = test::read_body_json(res).await;
+    let randomized_count = || {
+        query_scalar::<_, i64>(
+            "SELECT COUNT(*) FROM elective_subject_session_enrolled_students WHERE is_randomized",
+        )
+        .fetch_one(pool)
+    };
+    assert_eq!(randomized_count().await.unwrap(), 0);
+
+    let res = allocate(false).await;
+    assert_eq!(res.status(), StatusCode::OK);
+    let run: Value = test::read_body_json(res).await;
+    assert_eq!(run["data"]["allocations"], dry_run["data"]["allocations"]);
+
+    let allocations = run["data"]["allocations"].as_array().unwrap();
+    assert_eq!(
+        randomized_count().await.unwrap(),
+        i64::try_from(allocations.len()).unwrap(),
+    );
+    let robotics_students = allocations
+        .iter()
+        .filter(|a| a["elective_subject_session_id"] == json!(ROBOTICS_SESSION_ID))
+        .count();
+    assert_eq!(robotics_students, 1);
+    for student in [TestUser::StudentA, TestUser::StudentB] {
+        let session_id = enrolled_session(pool, student.student_id()).await;
+        assert!([ROBOTICS_SESSION_ID, MARINE_BIOLOGY_SESSION_ID].contains(&session_id));
+    }
+}
+
+#[actix_web::test]
+#[ignore = "needs a Postgres server at TEST_DATABASE_URL"]
+async fn allocation_seeds_give_a_fixed_assignment() {
+    let app = TestApp::spawn().await;
+    let service = app.service().await;
+
+    let req = app
+        .login(TestUser::Admin)
+        .await
+        .authorize(
+            test::TestRequest::post()
+                .uri("/v1/admin/electives/allocate")
+                .set_json(json!({ "data": { "seed": 42, "dry_run": true } })),
+        )
+        .to_request();
+    let res = test::call_service(&service, req).await;
+    assert_eq!(res.status(), StatusCode::OK);
+    let body: Value = test::read_body_json(res).await;
+
+    // Pinned so that a change in the RNG, which would break reproducing past runs, fails here
+    assert_eq!(
+        body["data"]["allocations"],
+        json!([
+            {
+                "student_id": TestUser::StudentC.student_id(),
+                "elective_subject_session_id": ROBOTICS_SESSION_ID,
+                "preference_rank": null,
+            },
+            {
+                "student_id": TestUser::StudentB.student_id(),
+                "elective_subject_session_id": ASTRONOMY_SESSION_ID,
+                "preference_rank": null,
+            },
+            {
+                "student_id": TestUser::StudentA.student_id(),
+                "elective_subject_session_id": MARINE_BIOLOGY_SESSION_ID,
+                "preference_rank": null,
+            },
+        ]),
+    );
+}
+
+#[actix_web::test]
+#[ignore = "needs a Postgres server at TEST_DATABASE_URL"]
+async fn trade_cycles_move_every_member_once_all_approve() {
+    let app = TestApp::spawn().await;
+    let pool = app.pool();
+    let service = app.service().await;
+
+    // No two students want each other's sessions, but the three of them form a cycle
+    let moves = [
+        (
+            TestUser::StudentA,
+            ASTRONOMY_SESSION_ID,
+            MARINE_BIOLOGY_SESSION_ID,
+        ),
+        (
+            TestUser::StudentB,
+            MARINE_BIOLOGY_SESSION_ID,
+            ROBOTICS_SESSION_ID,
+        ),
+        (
+            TestUser::StudentC,
+            ROBOTICS_SESSION_ID,
+            ASTRONOMY_SESSION_ID,
+        ),
+    ];
+    for (student, from_session_id, _) in moves {
+        enroll(pool, student.student_id(), from_session_id).await;
+    }
+    for (student, _, to_session_id) in moves {
+        let req = app
+            .login(student)
+            .await
+            .authorize(
+                test::TestRequest::put()
+                    .uri("/v1/subjects/electives/trade-intents")
+                    .set_json(json!({
+                        "data": { "elective_subject_session_ids": [to_session_id] },
+                    })),
+            )
+            .to_request();
+        let res = test::call_service(&service, req).await;
+        assert_eq!(res.status(), StatusCode::OK);
+    }
+
+    let trade_cycle_id: Uuid = query_scalar("SELECT id FROM elective_subject_trade_cycles")
+        .fetch_one(pool)
+        .await
+        .unwrap();
+    for (student, from_session_id, _) in moves {
+        // Nobody moves until everyone has approved
+        assert_eq!(
+            enrolled_session(pool, student.student_id()).await,
+            from_session_id,
+        );
+
+        let req = app
+            .login(student)
+            .await
+            .authorize(
+                test::TestRequest::put()
+                    .uri(&format!(
+                        "/v1/subjects/electives/trade-cycles/{trade_cycle_id}"
+                    ))
+                    .set_json(json!({ "data": { "status": "approved" } })),
+            )
+            .to_request();
+        let res = test::call_service(&service, req).await;
+        assert_eq!(res.status(), StatusCode::OK);
+    }
+
+    for (student, _, to_session_id) in moves {
+        assert_eq!(
+            enrolled_session(pool, student.student_id()).await,
+            to_session_id,
+        );
+    }
+}
+
+#[actix_web::test]
+#[ignore = "needs a Postgres server at TEST_DATABASE_URL"]
+async fn trade_cycles_are_declined_once_a_member_moves() {
+    let app = TestApp::spawn().await;
+    let pool = app.pool();
+    let service = app.service().await;
+
+    let moves = [
+        (
+            TestUser::StudentA,
+            ASTRONOMY_SESSION_ID,
+            MARINE_BIOLOGY_SESSION_ID,
+        ),
+        (
+            TestUser::StudentB,
+            MARINE_BIOLOGY_SESSION_ID,
+            ROBOTICS_SESSION_ID,
+        ),
+        (
+            TestUser::StudentC,
+            ROBOTICS_SESSION_ID,
+            ASTRONOMY_SESSION_ID,
+        ),
+    ];
+    for (student, from_session_id, _) in moves {
+        enroll(pool, student.student_id(), from_session_id).await;
+    }
+    for (student, _, to_session_id) in moves {
+        let req = app
+            .login(student)
+            .await
+            .authorize(
+                test::TestRequest::put()
+                    .uri("/v1/subjects/electives/trade-intents")
+                    .set_json(json!({
+                        "data": { "elective_subject_session_ids": [to_session_id] },
+                    })),
+            )
+            .to_request();
+        let res = test::call_service(&service, req).await;
+        assert_eq!(res.status(), StatusCode::OK);
+    }
+
+    let trade_cycle_id: Uuid = query_scalar("SELECT id FROM elective_subject_trade_cycles")
+        .fetch_one(pool)
+        .await
+        .unwrap();
+    let (app, service) = (&app, &service);
+    let approve = |student: TestUser| async move {
+        let req = app
+            .login(student)
+            .await
+            .authorize(
+                test::TestRequest::put()
+                    .uri(&format!(
+                        "/v1/subjects/electives/trade-cycles/{trade_cycle_id}"
+                    ))
+                    .set_json(json!({ "data": { "status": "approved" } })),
+            )
+            .to_request();
+
+        test::call_service(service, req).await
+    };
+    assert_eq!(approve(TestUser::StudentA).await.status(), StatusCode::OK);
+    assert_eq!(approve(TestUser::StudentB).await.status(), StatusCode::OK);
+
+    // Student C leaves the session they would have given up
+    query("DELETE FROM elective_subject_session_enrolled_students WHERE student_id = $1")
+        .bind(TestUser::StudentC.student_id())
+        .execute(pool)
+        .await
+        .unwrap();
+
+    let res = approve(TestUser::StudentC).await;
+    assert_eq!(res.status(), StatusCode::CONFLICT);
+    let body: Value = test::read_body_json(res).await;
+    assert_eq!(body["error"]["error_code"], "trade_cycle_outdated");
+
+    let status: SubmissionStatus =
+        query_scalar("SELECT status FROM elective_subject_trade_cycles WHERE id = $1")
+            .bind(trade_cycle_id)
+            .fetch_one(pool)
+            .await
+            .unwrap();
+    assert!(matches!(status, SubmissionStatus::Declined));
+    for (student, from_session_id, _) in &moves[..2] {
+        assert_eq!(
+            enrolled_session(pool, student.student_id()).await,
+            *from_session_id,
+        );
+    }
+}
+
+#[actix_web::test]
+#[ignore = "needs a Postgres server at TEST_DATABASE_URL"]
+async fn only_senders_can_cancel_their_trade_offer() {
+    let app = TestApp::spawn().await;
+    let pool = app.pool();
+    let service = app.service().await;
+
+    enroll(pool, TestUser::StudentA.student_id(), ASTRONOMY_SESSION_ID).await;
+    enroll(
+        pool,
+        TestUser::StudentB.student_id(),
+        MARINE_BIOLOGY_SESSION_ID,
+    )
+    .await;
+    let trade_offer_id = create_trade_offer(pool, TestUser::StudentA, TestUser::StudentB).await;
+    let req = app
+        .login(TestUser::StudentB)
+        .await
+        .authorize(test::TestRequest::post().uri(&format!(
+            "/v1/subjects/electives/trade-offers/{trade_offer_id}/cancel"
+        )))
+        .to_request();
+    let res = test::call_service(&service, req).await;
+    assert_eq!(res.status(), StatusCode::FORBIDDEN);
+    let body: Value = test::read_body_json(res).await;
+    assert_eq!(body["error"]["error_code"], "trade_offer_not_sender");
+
+    let req = app
+        .login(TestUser::StudentA)
+        .await
+        .authorize(test::TestRequest::post().uri(&format!(
+            "/v1/subjects/electives/trade-offers/{trade_offer_id}/cancel"
+        )))
+        .to_request();
+    let res = test::call_service(&service, req).await;
+    assert_eq!(res.status(), StatusCode::OK);
+    assert!(matches!(
+        trade_offer_status(pool, trade_offer_id).await,
+        SubmissionStatus::Declined,
+    ));
+    assert!(matches!(
+        trade_offer_decline_reason(pool, trade_offer_id).await,
+        Some(TradeOfferDeclineReason::Cancelled),
+    ));
+}
+
+#[actix_web::test]
+#[ignore = "needs a Postgres server at TEST_DATABASE_URL"]
+async fn expired_trade_offers_are_declined() {
+    let app = TestApp::spawn().await;
+    let pool = app.pool();
+    let service = app.service().await;
+
+    enroll(pool, TestUser::StudentA.student_id(), ASTRONOMY_SESSION_ID).await;
+    enroll(
+        pool,
+        TestUser::StudentB.student_id(),
+        MARINE_BIOLOGY_SESSION_ID,
+    )
+    .await;
+    let trade_offer_id = create_trade_offer(pool, TestUser::StudentA, TestUser::StudentB).await;
+    query(
+        "\
+        UPDATE elective_subject_trade_offers SET expires_at = now() - interval '1 minute' \
+        WHERE id = $1\
+        ",
+    )
+    .bind(trade_offer_id)
+    .execute(pool)
+    .await
+    .unwrap();
+
+    // Answering an offer the sweeper has yet to reach
+    let req = app
+        .login(TestUser::StudentB)
+        .await
+        .authorize(
+            test::TestRequest::put()
+                .uri(&format!(
+                    "/v1/subjects/electives/trade-offers/{trade_offer_id}"
+                ))
+                .set_json(json!({ "data": { "status": "approved" } })),
+        )
+        .to_request();
+    let res = test::call_service(&service, req).await;
+    assert_eq!(res.status(), StatusCode::FORBIDDEN);
+    let body: Value = test::read_body_json(res).await;
+    assert_eq!(body["error"]["error_code"], "trade_offer_expired");
+
+    TradeOfferSweeper::sweep(pool).await.unwrap();
+    assert!(matches!(
+        trade_offer_status(pool, trade_offer_id).await,
+        SubmissionStatus::Declined,
+    ));
+    assert!(matches!(
+        trade_offer_decline_reason(pool, trade_offer_id).await,
+        Some(TradeOfferDeclineReason::Expired),
+    ));
+}
+
+#[actix_web::test]
+#[ignore = "needs a Postgres server at TEST_DATABASE_URL"]
+async fn closing_the_enrollment_period_declines_pending_trade_offers() {
+    let app = TestApp::spawn().await;
+    let pool = app.pool();
+
+    enroll(pool, TestUser::StudentA.student_id(), ASTRONOMY_SESSION_ID).await;
+    enroll(
+        pool,
+        TestUser::StudentB.student_id(),
+        MARINE_BIOLOGY_SESSION_ID,
+    )
+    .await;
+    let trade_offer_id = create_trade_offer(pool, TestUser::StudentA, TestUser::StudentB).await;
+
+    // Nothing is declined while the enrollment period is open
+    TradeOfferSweeper::sweep(pool).await.unwrap();
+    assert!(matches!(
+        trade_offer_status(pool, trade_offer_id).await,
+        SubmissionStatus::Pending,
+    ));
+
+    query("UPDATE elective_subject_enrollment_periods SET end_time = now() - interval '1 minute'")
+        .execute(pool)
+        .await
+        .unwrap();
+    TradeOfferSweeper::sweep(pool).await.unwrap();
+    assert!(matches!(
+        trade_offer_status(pool, trade_offer_id).await,
+        SubmissionStatus::Declined,
+    ));
+    assert!(matches!(
+        trade_offer_decline_reason(pool, trade_offer_id).await,
+        Some(TradeOfferDeclineReason::EnrollmentClosed),
+    ));
+}
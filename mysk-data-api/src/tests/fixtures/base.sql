@@ -0,0 +1,114 @@
+-- Users of every role, a classroom of students and three elective sessions open for enrollment in
+-- the current semester. The IDs are mirrored by `tests::TestUser` and `tests::fixtures`.
+
+INSERT INTO users (id, email, role, is_admin, onboarded) VALUES
+    ('00000000-0000-0000-0000-00000000a001', 'admin@sk.ac.th', 'management', TRUE, TRUE),
+    ('00000000-0000-0000-0000-00000000a002', 'teacher@sk.ac.th', 'teacher', FALSE, TRUE),
+    ('00000000-0000-0000-0000-00000000a011', 'student.a@student.sk.ac.th', 'student', FALSE, TRUE),
+    ('00000000-0000-0000-0000-00000000a012', 'student.b@student.sk.ac.th', 'student', FALSE, TRUE),
+    ('00000000-0000-0000-0000-00000000a013', 'student.c@student.sk.ac.th', 'student', FALSE, TRUE);
+
+INSERT INTO people (id, prefix_th, first_name_th, last_name_th, sex) VALUES
+    ('00000000-0000-0000-0000-00000000b002', 'ครู', 'ทดสอบ', 'ครู', 'female'),
+    ('00000000-0000-0000-0000-00000000b011', 'นาย', 'ทดสอบ', 'หนึ่ง', 'male'),
+    ('00000000-0000-0000-0000-00000000b012', 'นาย', 'ทดสอบ', 'สอง', 'male'),
+    ('00000000-0000-0000-0000-00000000b013', 'นางสาว', 'ทดสอบ', 'สาม', 'female');
+
+INSERT INTO subject_groups (id, name_th, name_en) VALUES (1, 'วิทยาศาสตร์', 'Science');
+
+INSERT INTO teachers (id, teacher_id, subject_group_id, person_id, user_id) VALUES (
+    '00000000-0000-0000-0000-00000000d002',
+    'T001',
+    1,
+    '00000000-0000-0000-0000-00000000b002',
+    '00000000-0000-0000-0000-00000000a002'
+);
+
+INSERT INTO students (id, student_id, person_id, user_id) VALUES
+    (
+        '00000000-0000-0000-0000-00000000c011',
+        '10001',
+        '00000000-0000-0000-0000-00000000b011',
+        '00000000-0000-0000-0000-00000000a011'
+    ),
+    (
+        '00000000-0000-0000-0000-00000000c012',
+        '10002',
+        '00000000-0000-0000-0000-00000000b012',
+        '00000000-0000-0000-0000-00000000a012'
+    ),
+    (
+        '00000000-0000-0000-0000-00000000c013',
+        '10003',
+        '00000000-0000-0000-0000-00000000b013',
+        '00000000-0000-0000-0000-00000000a013'
+    );
+
+INSERT INTO classrooms (id, number, year) VALUES (
+    '00000000-0000-0000-0000-00000000e101',
+    101,
+    get_current_academic_year((now() AT TIME ZONE 'UTC')::date)
+);
+
+INSERT INTO classroom_students (classroom_id, student_id, class_no) VALUES
+    ('00000000-0000-0000-0000-00000000e101', '00000000-0000-0000-0000-00000000c011', 1),
+    ('00000000-0000-0000-0000-00000000e101', '00000000-0000-0000-0000-00000000c012', 2),
+    ('00000000-0000-0000-0000-00000000e101', '00000000-0000-0000-0000-00000000c013', 3);
+
+INSERT INTO classroom_advisors (classroom_id, teacher_id) VALUES (
+    '00000000-0000-0000-0000-00000000e101',
+    '00000000-0000-0000-0000-00000000d002'
+);
+
+INSERT INTO subjects (id, name_th, name_en, code_th, code_en, type, credit, subject_group_id) VALUES
+    (
+        '00000000-0000-0000-0000-00000000f001',
+        'ดาราศาสตร์',
+        'Astronomy',
+        'ว20201',
+        'SC20201',
+        'elective',
+        1,
+        1
+    ),
+    (
+        '00000000-0000-0000-0000-00000000f002',
+        'ชีววิทยาทางทะเล',
+        'Marine Biology',
+        'ว20202',
+        'SC20202',
+        'elective',
+        1,
+        1
+    ),
+    (
+        '00000000-0000-0000-0000-00000000f003',
+        'หุ่นยนต์',
+        'Robotics',
+        'ว20203',
+        'SC20203',
+        'elective',
+        1,
+        1
+    );
+
+INSERT INTO elective_subject_sessions (id, subject_id, cap_size, room, year, semester, session_code)
+SELECT
+    session.id::uuid,
+    session.subject_id::uuid,
+    session.cap_size,
+    session.room,
+    get_current_academic_year((now() AT TIME ZONE 'UTC')::date),
+    CASE WHEN EXTRACT(MONTH FROM now() AT TIME ZONE 'UTC') BETWEEN 4 AND 9 THEN 1 ELSE 2 END,
+    session.session_code
+FROM (VALUES
+    ('00000000-0000-0000-0000-00000000f101', '00000000-0000-0000-0000-00000000f001', 30, '1101', '1'),
+    ('00000000-0000-0000-0000-00000000f102', '00000000-0000-0000-0000-00000000f002', 30, '1102', '2'),
+    ('00000000-0000-0000-0000-00000000f103', '00000000-0000-0000-0000-00000000f003', 1, '1103', '3')
+) AS session (id, subject_id, cap_size, room, session_code);
+
+INSERT INTO elective_subject_session_classrooms (elective_subject_session_id, classroom_id)
+SELECT ess.id, '00000000-0000-0000-0000-00000000e101' FROM elective_subject_sessions AS ess;
+
+INSERT INTO elective_subject_enrollment_periods (start_time, end_time)
+VALUES (now() - INTERVAL '1 day', now() + INTERVAL '1 day');
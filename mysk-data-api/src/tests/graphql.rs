@@ -0,0 +1,134 @@
+use crate::tests::{TestApp, TestUser};
+use actix_web::test;
+use serde_json::{Value, json};
+use sqlx::query;
+
+#[actix_web::test]
+#[ignore = "needs a Postgres server at TEST_DATABASE_URL"]
+async fn resolves_nested_fields_in_batches() {
+    let app = TestApp::spawn().await;
+    let service = app.service().await;
+
+    let req = app
+        .login(TestUser::StudentA)
+        .await
+        .authorize(
+            test::TestRequest::post()
+                .uri("/v1/graphql")
+                .set_json(json!({
+                    "query": "query ($id: UUID!) { \
+                        student(id: $id) { \
+                            classNo \
+                            classroom { \
+                                number \
+                                students { id studentId person { firstName { th } } } \
+                                classAdvisors { id } \
+                            } \
+                        } \
+                    }",
+                    "variables": { "id": TestUser::StudentA.student_id() },
+                })),
+        )
+        .to_request();
+    let body: Value = test::call_and_read_body_json(&service, req).await;
+
+    assert_eq!(body["errors"], Value::Null, "{body}");
+    let student = &body["data"]["student"];
+    assert_eq!(student["classNo"], 1);
+    assert_eq!(student["classroom"]["number"], 101);
+    let student_ids: Vec<_> = student["classroom"]["students"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|student| student["id"].as_str().unwrap().to_string())
+        .collect();
+    assert_eq!(
+        student_ids,
+        [TestUser::StudentA, TestUser::StudentB, TestUser::StudentC]
+            .map(|student| student.student_id().to_string()),
+    );
+    assert_eq!(
+        student["classroom"]["classAdvisors"][0]["id"],
+        TestUser::Teacher.meta_id().unwrap().to_string(),
+    );
+}
+
+#[actix_web::test]
+#[ignore = "needs a Postgres server at TEST_DATABASE_URL"]
+async fn denies_fields_above_the_permitted_fetch_level() {
+    let app = TestApp::spawn().await;
+    let service = app.service().await;
+
+    // Students can only read the default fields of their classmates
+    query("DELETE FROM classroom_students WHERE student_id = $1")
+        .bind(TestUser::StudentC.student_id())
+        .execute(app.pool())
+        .await
+        .unwrap();
+
+    let req = app
+        .login(TestUser::StudentA)
+        .await
+        .authorize(
+            test::TestRequest::post()
+                .uri("/v1/graphql")
+                .set_json(json!({
+                    "query": "query ($id: UUID!) { student(id: $id) { id person { id } } }",
+                    "variables": { "id": TestUser::StudentC.student_id() },
+                })),
+        )
+        .to_request();
+    let body: Value = test::call_and_read_body_json(&service, req).await;
+
+    let student = &body["data"]["student"];
+    assert_eq!(student["id"], TestUser::StudentC.student_id().to_string());
+    assert_eq!(student["person"], Value::Null);
+    assert_eq!(body["errors"][0]["path"], json!(["student", "person"]));
+    assert_eq!(body["errors"][0]["extensions"]["code"], 403);
+}
+
+#[actix_web::test]
+#[ignore = "needs a Postgres server at TEST_DATABASE_URL"]
+async fn counts_the_fields_of_lists_once_per_row() {
+    let app = TestApp::spawn().await;
+    let service = app.service().await;
+    let admin = app.login(TestUser::Admin).await;
+    let list_students = |size: u32| {
+        admin
+            .authorize(
+                test::TestRequest::post()
+                    .uri("/v1/graphql")
+                    .set_json(json!({
+                        "query": "query ($size: Int!) { \
+                            students(size: $size) { \
+                                id studentId classNo \
+                                person { \
+                                    id birthdate sex allergies shirtSize \
+                                    prefix { th en } \
+                                    firstName { th en } \
+                                    lastName { th en } \
+                                    nickname { th en } \
+                                } \
+                            } \
+                        }",
+                        "variables": { "size": size },
+                    })),
+            )
+            .to_request()
+    };
+
+    let body: Value = test::call_and_read_body_json(&service, list_students(5)).await;
+    assert_eq!(body["errors"], Value::Null, "{body}");
+    assert!(!body["data"]["students"].as_array().unwrap().is_empty());
+
+    // Clamped to the largest page, whose rows select too many fields between them
+    let body: Value = test::call_and_read_body_json(&service, list_students(1_000_000)).await;
+    assert_eq!(body["data"], Value::Null);
+    assert!(
+        body["errors"][0]["message"]
+            .as_str()
+            .unwrap()
+            .contains("too complex"),
+        "{body}",
+    );
+}
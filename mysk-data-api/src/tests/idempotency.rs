@@ -0,0 +1,49 @@
+use crate::tests::{
+    TestApp, TestUser,
+    fixtures::{ASTRONOMY_SESSION_ID, MARINE_BIOLOGY_SESSION_ID},
+};
+use actix_web::{http::StatusCode, test};
+use serde_json::json;
+use sqlx::query_scalar;
+use uuid::Uuid;
+
+#[actix_web::test]
+#[ignore = "needs a Postgres server at TEST_DATABASE_URL"]
+async fn retries_with_the_same_key_replay_the_first_response() {
+    let app = TestApp::spawn().await;
+    let service = app.service().await;
+    let student = app.login(TestUser::StudentA).await;
+    let enroll = |session_id: Uuid| {
+        student
+            .authorize(
+                test::TestRequest::post()
+                    .uri(&format!("/v1/subjects/electives/{session_id}/enroll"))
+                    .insert_header(("Idempotency-Key", "enroll-once"))
+                    .set_json(json!({})),
+            )
+            .to_request()
+    };
+
+    let res = test::call_service(&service, enroll(ASTRONOMY_SESSION_ID)).await;
+    assert_eq!(res.status(), StatusCode::OK);
+    assert!(!res.headers().contains_key("Idempotent-Replayed"));
+    let first_body = test::read_body(res).await;
+
+    // Enrolling again would fail as the student is already enrolled
+    let res = test::call_service(&service, enroll(ASTRONOMY_SESSION_ID)).await;
+    assert_eq!(res.status(), StatusCode::OK);
+    assert_eq!(res.headers().get("Idempotent-Replayed").unwrap(), "true");
+    assert_eq!(test::read_body(res).await, first_body);
+
+    let res = test::call_service(&service, enroll(MARINE_BIOLOGY_SESSION_ID)).await;
+    assert_eq!(res.status(), StatusCode::CONFLICT);
+
+    let enrollments: i64 = query_scalar(
+        "SELECT COUNT(*) FROM elective_subject_session_enrolled_students WHERE student_id = $1",
+    )
+    .bind(TestUser::StudentA.student_id())
+    .fetch_one(app.pool())
+    .await
+    .unwrap();
+    assert_eq!(enrollments, 1);
+}
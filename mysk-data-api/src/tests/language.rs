@@ -0,0 +1,76 @@
+use crate::tests::{TestApp, TestUser, fixtures::ASTRONOMY_SESSION_ID};
+use actix_web::{http::header, test};
+use serde_json::{Value, json};
+use sqlx::query;
+
+#[actix_web::test]
+#[ignore = "needs a Postgres server at TEST_DATABASE_URL"]
+async fn names_collapse_to_the_requested_language() {
+    let app = TestApp::spawn().await;
+    let service = app.service().await;
+    let student = app.login(TestUser::StudentA).await;
+    let get_elective = |uri: &str, accept_language: Option<&str>| {
+        let mut req = test::TestRequest::get().uri(uri);
+        if let Some(accept_language) = accept_language {
+            req = req.insert_header(("Accept-Language", accept_language));
+        }
+
+        student.authorize(req).to_request()
+    };
+
+    let uri = format!("/v1/subjects/electives/{ASTRONOMY_SESSION_ID}?fetch_level=default");
+    let multi_lang = json!({ "en-US": "Astronomy", "th": "ดาราศาสตร์" });
+    let res: Value = test::call_and_read_body_json(&service, get_elective(&uri, None)).await;
+    assert_eq!(res["data"]["name"], multi_lang);
+
+    // Browsers always send the header, so it is only honoured when asked to
+    let res: Value =
+        test::call_and_read_body_json(&service, get_elective(&uri, Some("th-TH,en;q=0.5"))).await;
+    assert_eq!(res["data"]["name"], multi_lang);
+
+    let res = test::call_service(
+        &service,
+        get_elective(&format!("{uri}&lang=auto"), Some("th-TH,en;q=0.5")),
+    )
+    .await;
+    assert!(
+        res.headers()
+            .get_all(header::VARY)
+            .any(|vary| vary.to_str().unwrap().contains("accept-language"))
+    );
+    let res: Value = test::read_body_json(res).await;
+    assert_eq!(res["data"]["name"], json!("ดาราศาสตร์"));
+
+    let res: Value = test::call_and_read_body_json(
+        &service,
+        get_elective(&format!("{uri}&lang=en-US"), Some("th")),
+    )
+    .await;
+    assert_eq!(res["data"]["name"], json!("Astronomy"));
+}
+
+#[actix_web::test]
+#[ignore = "needs a Postgres server at TEST_DATABASE_URL"]
+async fn error_details_are_translated() {
+    let app = TestApp::spawn().await;
+    let service = app.service().await;
+    query("UPDATE elective_subject_sessions SET cap_size = 0 WHERE id = $1")
+        .bind(ASTRONOMY_SESSION_ID)
+        .execute(app.pool())
+        .await
+        .unwrap();
+
+    let req = app
+        .login(TestUser::StudentA)
+        .await
+        .authorize(
+            test::TestRequest::post()
+                .uri(&format!(
+                    "/v1/subjects/electives/{ASTRONOMY_SESSION_ID}/enroll?lang=th"
+                ))
+                .set_json(json!({})),
+        )
+        .to_request();
+    let res: Value = test::call_and_read_body_json(&service, req).await;
+    assert_eq!(res["error"]["detail"], json!("วิชาเลือกนี้เต็มแล้ว"));
+}
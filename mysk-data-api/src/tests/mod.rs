@@ -0,0 +1,279 @@
+//! End-to-end tests of the routes against a throwaway database.
+//!
+//! Every [`TestApp`] creates its own database on the Postgres server at `TEST_DATABASE_URL`, runs
+//! the migrations and loads `fixtures/base.sql` into it, then drops it once the test is done. These
+//! tests are ignored by default; run them with `cargo test -- --ignored`. Never point
+//! `TEST_DATABASE_URL` at a server holding real data.
+
+use crate::{
+    AppState, app, attendance_feed::AttendanceFeed, background::BackgroundTasks,
+    middlewares::error_log::ErrorLogSink, webhook_dispatcher::WebhookDispatcher,
+};
+use actix_http::Request;
+use actix_web::{
+    Error as ActixError,
+    body::MessageBody,
+    dev::{Service, ServiceResponse},
+    http::header::AUTHORIZATION,
+    test::{self, TestRequest},
+    web::Data,
+};
+use chrono::{Duration, Utc};
+use jsonwebtoken::{EncodingKey, Header};
+use mysk_lib::{
+    auth::{key::ApiKey, oauth::TokenClaims},
+    cache::GlobalCache,
+    common::config::Config,
+    migrations::MIGRATOR,
+};
+use parking_lot::{Mutex, RwLock};
+use sqlx::{
+    Connection as _, Executor as _, PgConnection, PgPool,
+    postgres::{PgConnectOptions, PgSslMode},
+    raw_sql,
+};
+use std::{collections::HashSet, env, net::IpAddr, sync::Arc, thread};
+use uuid::{Uuid, uuid};
+
+mod attendance;
+mod auth;
+mod batch;
+mod electives;
+mod graphql;
+mod idempotency;
+mod language;
+mod openapi;
+mod validation;
+mod webhooks;
+
+/// The IDs of the rows in `fixtures/base.sql`.
+pub mod fixtures {
+    use uuid::{Uuid, uuid};
+
+    /// Open to every student, with room for 30.
+    pub const ASTRONOMY_SESSION_ID: Uuid = uuid!("00000000-0000-0000-0000-00000000f101");
+    /// Open to every student, with room for 30.
+    pub const MARINE_BIOLOGY_SESSION_ID: Uuid = uuid!("00000000-0000-0000-0000-00000000f102");
+    /// Open to every student, with room for only one.
+    pub const ROBOTICS_SESSION_ID: Uuid = uuid!("00000000-0000-0000-0000-00000000f103");
+}
+
+/// A user of every role in `fixtures/base.sql`.
+#[derive(Clone, Copy, Debug)]
+pub enum TestUser {
+    /// A `management` user with `is_admin`.
+    Admin,
+    /// The advisor of classroom 101.
+    Teacher,
+    /// Three students of classroom 101, none enrolled in an elective.
+    StudentA,
+    StudentB,
+    StudentC,
+}
+
+impl TestUser {
+    pub fn user_id(self) -> Uuid {
+        match self {
+            TestUser::Admin => uuid!("00000000-0000-0000-0000-00000000a001"),
+            TestUser::Teacher => uuid!("00000000-0000-0000-0000-00000000a002"),
+            TestUser::StudentA => uuid!("00000000-0000-0000-0000-00000000a011"),
+            TestUser::StudentB => uuid!("00000000-0000-0000-0000-00000000a012"),
+            TestUser::StudentC => uuid!("00000000-0000-0000-0000-00000000a013"),
+        }
+    }
+
+    /// The ID of the student or teacher, if any.
+    pub fn meta_id(self) -> Option<Uuid> {
+        match self {
+            TestUser::Admin => None,
+            TestUser::Teacher => Some(uuid!("00000000-0000-0000-0000-00000000d002")),
+            TestUser::StudentA => Some(uuid!("00000000-0000-0000-0000-00000000c011")),
+            TestUser::StudentB => Some(uuid!("00000000-0000-0000-0000-00000000c012")),
+            TestUser::StudentC => Some(uuid!("00000000-0000-0000-0000-00000000c013")),
+        }
+    }
+
+    pub fn student_id(self) -> Uuid {
+        match self {
+            TestUser::StudentA | TestUser::StudentB | TestUser::StudentC => self.meta_id().unwrap(),
+            _ => panic!("{self:?} is not a student"),
+        }
+    }
+}
+
+/// The API key and JWT of a [`TestUser`].
+pub struct Credentials {
+    pub api_key: String,
+    pub token: String,
+}
+
+impl Credentials {
+    /// Authenticates the request with both the API key and the JWT.
+    pub fn authorize(&self, req: TestRequest) -> TestRequest {
+        req.insert_header(("X-Api-Key", self.api_key.as_str()))
+            .insert_header((AUTHORIZATION, format!("Bearer {}", self.token)))
+    }
+}
+
+/// The application state backed by a throwaway, seeded database.
+pub struct TestApp {
+    pub state: Data<AppState>,
+    // Stops the background tasks once dropped
+    _background_tasks: BackgroundTasks,
+    // Dropped last so the pool is closed before the database
+    _database: TestDatabase,
+}
+
+impl TestApp {
+    /// Creates a fresh database.
+    ///
+    /// # Panics
+    /// If `TEST_DATABASE_URL` isn't set, so that running the tests without a database fails.
+    pub async fn spawn() -> TestApp {
+        let url = env::var("TEST_DATABASE_URL")
+            .expect("TEST_DATABASE_URL should point to a Postgres server to create databases on");
+        let options = url
+            .parse::<PgConnectOptions>()
+            .expect("TEST_DATABASE_URL should be a valid connection string");
+
+        let database = TestDatabase::create(&options).await;
+        let pool = PgPool::connect_with(options.database(&database.name))
+            .await
+            .expect("Failed to connect to the test database");
+        MIGRATOR.run(&pool).await.expect("Failed to run migrations");
+        raw_sql(include_str!("fixtures/base.sql"))
+            .execute(&pool)
+            .await
+            .expect("Failed to load fixtures");
+
+        let cache = GlobalCache::new()
+            .populate_cache(&pool)
+            .await
+            .expect("Failed to populate the cache");
+        let config = test_config(&url);
+        let reloadable_config = Arc::new(RwLock::new(config.reloadable()));
+        let mut background_tasks = BackgroundTasks::new();
+        let state = Data::new(AppState {
+            db: pool.clone(),
+            oauth_states: Mutex::new(HashSet::new()),
+            env: config,
+            reloadable_config,
+            cache,
+            error_log: ErrorLogSink::spawn(pool.clone(), &mut background_tasks),
+            attendance_feed: AttendanceFeed::spawn(pool.clone(), &mut background_tasks),
+        });
+        WebhookDispatcher::spawn(pool, &mut background_tasks);
+
+        TestApp {
+            state,
+            _background_tasks: background_tasks,
+            _database: database,
+        }
+    }
+
+    /// Initialises the application as it is served.
+    pub async fn service(
+        &self,
+    ) -> impl Service<Request, Response = ServiceResponse<impl MessageBody>, Error = ActixError>
+    {
+        test::init_service(app(self.state.clone())).await
+    }
+
+    /// Mints a new API key and JWT for the user.
+    pub async fn login(&self, user: TestUser) -> Credentials {
+        let mut conn = self.state.db.acquire().await.unwrap();
+        let api_key = ApiKey::create(&mut conn, user.user_id(), None)
+            .await
+            .expect("Failed to create an API key");
+
+        let now = Utc::now();
+        let claims = TokenClaims {
+            sub: user.user_id(),
+            mta: user.meta_id(),
+            imp: None,
+            iat: usize::try_from(now.timestamp()).unwrap(),
+            exp: usize::try_from((now + Duration::hours(1)).timestamp()).unwrap(),
+        };
+        let token = jsonwebtoken::encode(
+            &Header::default(),
+            &claims,
+            &EncodingKey::from_secret(self.state.env.token_secret.as_bytes()),
+        )
+        .unwrap();
+
+        Credentials { api_key, token }
+    }
+
+    pub fn pool(&self) -> &PgPool {
+        &self.state.db
+    }
+}
+
+fn test_config(database_url: &str) -> Config {
+    Config {
+        database_url: database_url.to_string(),
+        database_max_connections: 4,
+        database_ssl_mode: PgSslMode::Prefer,
+        google_oauth_client_id: String::new(),
+        google_oauth_client_secret: String::new(),
+        host: IpAddr::from([127, 0, 0, 1]),
+        port: 0,
+        root_uri: "http://localhost".to_string(),
+        supabase_secret_key: String::new(),
+        supabase_uri: "http://localhost".to_string(),
+        token_max_age: 60,
+        token_secret: "test-secret".to_string(),
+        cors_allowed_origins: Vec::new(),
+        log_filter: String::new(),
+        json_limit: 2 * 1024 * 1024,
+        shutdown_timeout: 0,
+    }
+}
+
+/// A database which is dropped along with this value, even if the test panics.
+struct TestDatabase {
+    name: String,
+    server: PgConnectOptions,
+}
+
+impl TestDatabase {
+    async fn create(server: &PgConnectOptions) -> TestDatabase {
+        let name = format!("mysk_test_{}", Uuid::new_v4().simple());
+        let mut conn = PgConnection::connect_with(server)
+            .await
+            .expect("Failed to connect to TEST_DATABASE_URL");
+        conn.execute(format!(r#"CREATE DATABASE "{name}""#).as_str())
+            .await
+            .expect("Failed to create the test database");
+
+        TestDatabase {
+            name,
+            server: server.clone(),
+        }
+    }
+}
+
+impl Drop for TestDatabase {
+    fn drop(&mut self) {
+        let name = self.name.clone();
+        let server = self.server.clone();
+
+        // The test's runtime may be shutting down, so the database is dropped from another one
+        let dropped = thread::spawn(move || {
+            tokio::runtime::Builder::new_current_thread()
+                .enable_all()
+                .build()
+                .unwrap()
+                .block_on(async {
+                    let mut conn = PgConnection::connect_with(&server).await?;
+                    conn.execute(format!(r#"DROP DATABASE "{name}" WITH (FORCE)"#).as_str())
+                        .await
+                })
+        })
+        .join();
+
+        if !matches!(dropped, Ok(Ok(_))) {
+            eprintln!("Failed to drop the test database {}", self.name);
+        }
+    }
+}
@@ -0,0 +1,64 @@
+use crate::{routes::ApiDoc, tests::TestApp};
+use actix_web::{
+    http::StatusCode,
+    test::{TestRequest, call_service, read_body_json},
+};
+use serde_json::Value;
+use std::{env, fs};
+use utoipa::OpenApi as _;
+
+const SNAPSHOT_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/openapi.json");
+
+/// Fails when `openapi.json` no longer matches the routes. Run with `UPDATE_OPENAPI_SNAPSHOT=1` to
+/// rewrite it, then review the diff.
+#[test]
+fn snapshot_is_up_to_date() {
+    let document = ApiDoc::openapi().to_pretty_json().unwrap() + "\n";
+
+    if env::var_os("UPDATE_OPENAPI_SNAPSHOT").is_some() {
+        fs::write(SNAPSHOT_PATH, &document).unwrap();
+        return;
+    }
+
+    let snapshot = fs::read_to_string(SNAPSHOT_PATH).unwrap_or_default();
+    assert!(
+        snapshot == document,
+        "openapi.json is out of date, rerun the tests with UPDATE_OPENAPI_SNAPSHOT=1"
+    );
+}
+
+#[test]
+fn every_reference_resolves() {
+    fn check(document: &Value, value: &Value) {
+        match value {
+            Value::Object(object) => {
+                if let Some(Value::String(reference)) = object.get("$ref") {
+                    let target = reference
+                        .strip_prefix('#')
+                        .and_then(|pointer| document.pointer(pointer));
+                    assert!(target.is_some(), "{reference} doesn't resolve");
+                }
+                object.values().for_each(|value| check(document, value));
+            }
+            Value::Array(array) => array.iter().for_each(|value| check(document, value)),
+            _ => (),
+        }
+    }
+
+    let document = serde_json::to_value(ApiDoc::openapi()).unwrap();
+    check(&document, &document);
+}
+
+#[actix_web::test]
+#[ignore = "needs a Postgres server at TEST_DATABASE_URL"]
+async fn serves_the_document() {
+    let app = TestApp::spawn().await;
+    let service = app.service().await;
+
+    let req = TestRequest::get().uri("/openapi.json").to_request();
+    let res = call_service(&service, req).await;
+    assert_eq!(res.status(), StatusCode::OK);
+
+    let body: Value = read_body_json(res).await;
+    assert_eq!(body, serde_json::to_value(ApiDoc::openapi()).unwrap());
+}
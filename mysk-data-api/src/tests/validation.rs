@@ -0,0 +1,53 @@
+use crate::tests::{TestApp, TestUser};
+use actix_web::{http::StatusCode, test};
+use serde_json::{Value, json};
+use sqlx::query_scalar;
+
+#[actix_web::test]
+#[ignore = "needs a Postgres server at TEST_DATABASE_URL"]
+async fn every_invalid_field_is_reported() {
+    let app = TestApp::spawn().await;
+    let service = app.service().await;
+    let student_id = TestUser::StudentA.student_id();
+
+    let req = app
+        .login(TestUser::StudentA)
+        .await
+        .authorize(
+            test::TestRequest::put()
+                .uri(&format!("/v1/students/{student_id}"))
+                .set_json(json!({
+                    "data": {
+                        "person": {
+                            "first_name": { "th": " " },
+                            "last_name": { "th": "ใจดี" },
+                            "birthdate": "2999-01-01",
+                        },
+                    },
+                })),
+        )
+        .to_request();
+    let res = test::call_service(&service, req).await;
+
+    assert_eq!(res.status(), StatusCode::BAD_REQUEST);
+    let body: Value = test::read_body_json(res).await;
+    assert_eq!(body["error"]["error_code"], "invalid_fields");
+    assert_eq!(
+        body["error"]["fields"],
+        json!([
+            { "field": "data.person.first_name", "reason": "must not be empty" },
+            { "field": "data.person.birthdate", "reason": "must not be in the future" },
+        ]),
+    );
+
+    // Nothing is stored
+    let last_name = query_scalar::<_, String>(
+        "SELECT p.last_name_th FROM people AS p JOIN students AS s ON s.person_id = p.id \
+        WHERE s.id = $1",
+    )
+    .bind(student_id)
+    .fetch_one(app.pool())
+    .await
+    .unwrap();
+    assert_ne!(last_name, "ใจดี");
+}
@@ -0,0 +1,132 @@
+use crate::{
+    tests::{TestApp, TestUser, fixtures::ASTRONOMY_SESSION_ID},
+    webhook_dispatcher::sign,
+};
+use actix_web::{
+    App, HttpRequest, HttpResponse, HttpServer,
+    http::StatusCode,
+    test,
+    web::{self, Bytes},
+};
+use serde_json::{Value, json};
+use sqlx::query_scalar;
+use std::time::Duration;
+use tokio::{sync::mpsc, time::timeout};
+
+/// A request received by [`spawn_receiver`], as its `X-MySK-*` headers and body.
+struct ReceivedWebhook {
+    event: String,
+    timestamp: String,
+    signature: String,
+    body: String,
+}
+
+/// Starts a server standing in for another school system, returning its URL and the requests it
+/// receives.
+fn spawn_receiver() -> (String, mpsc::UnboundedReceiver<ReceivedWebhook>) {
+    let (sender, receiver) = mpsc::unbounded_channel();
+    let server = HttpServer::new(move || {
+        let sender = sender.clone();
+        App::new().default_service(web::to(move |req: HttpRequest, body: Bytes| {
+            let header = |name| {
+                req.headers()
+                    .get(name)
+                    .and_then(|value| value.to_str().ok())
+                    .unwrap_or_default()
+                    .to_string()
+            };
+            _ = sender.send(ReceivedWebhook {
+                event: header("X-MySK-Event"),
+                timestamp: header("X-MySK-Timestamp"),
+                signature: header("X-MySK-Signature"),
+                body: String::from_utf8_lossy(&body).into_owned(),
+            });
+
+            async { HttpResponse::NoContent().finish() }
+        }))
+    })
+    .workers(1)
+    .disable_signals()
+    .bind(("127.0.0.1", 0))
+    .unwrap();
+    let url = format!("http://{}/hooks", server.addrs()[0]);
+    actix_web::rt::spawn(server.run());
+
+    (url, receiver)
+}
+
+#[actix_web::test]
+#[ignore = "needs a Postgres server at TEST_DATABASE_URL"]
+async fn enrollments_are_sent_to_subscribed_endpoints() {
+    let app = TestApp::spawn().await;
+    let service = app.service().await;
+    let (url, mut receiver) = spawn_receiver();
+
+    let req = app
+        .login(TestUser::Admin)
+        .await
+        .authorize(
+            test::TestRequest::post()
+                .uri("/v1/admin/webhooks")
+                .set_json(json!({ "data": { "url": url, "events": ["elective_enrolled"] } })),
+        )
+        .to_request();
+    let res: Value = test::call_and_read_body_json(&service, req).await;
+    let secret = res["data"]["secret"].as_str().unwrap().to_string();
+
+    let req = app
+        .login(TestUser::StudentA)
+        .await
+        .authorize(
+            test::TestRequest::post()
+                .uri(&format!(
+                    "/v1/subjects/electives/{ASTRONOMY_SESSION_ID}/enroll"
+                ))
+                .set_json(json!({})),
+        )
+        .to_request();
+    assert_eq!(
+        test::call_service(&service, req).await.status(),
+        StatusCode::OK
+    );
+
+    // The dispatcher polls the outbox every few seconds
+    let webhook = timeout(Duration::from_secs(20), receiver.recv())
+        .await
+        .expect("The webhook should be sent")
+        .unwrap();
+    assert_eq!(webhook.event, "elective_enrolled");
+    assert_eq!(
+        webhook.signature,
+        format!(
+            "sha256={}",
+            sign(&secret, &webhook.timestamp, &webhook.body)
+        ),
+    );
+    let body: Value = serde_json::from_str(&webhook.body).unwrap();
+    assert_eq!(
+        body["data"]["student_id"],
+        json!(TestUser::StudentA.student_id()),
+    );
+    assert_eq!(
+        body["data"]["elective_subject_session_id"],
+        json!(ASTRONOMY_SESSION_ID),
+    );
+
+    // The attempt is logged once the dispatcher hears back
+    let mut status_code = None;
+    for _ in 0..20 {
+        status_code = query_scalar::<_, Option<i32>>(
+            "SELECT status_code FROM api_logging.webhook_deliveries",
+        )
+        .fetch_optional(app.pool())
+        .await
+        .unwrap()
+        .flatten();
+        if status_code.is_some() {
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(100)).await;
+    }
+    assert_eq!(status_code, Some(204));
+}
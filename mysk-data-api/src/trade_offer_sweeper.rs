@@ -0,0 +1,61 @@
+use crate::{
+    background::{BackgroundTasks, StopSignal},
+    metrics::METRICS,
+};
+use mysk_lib::{
+    models::{elective_trade_offer::db::DbElectiveTradeOffer, enums::TradeOfferDeclineReason},
+    prelude::*,
+};
+use sqlx::PgPool;
+use std::time::Duration;
+
+/// How often pending trade offers are checked for whether they can still be approved.
+const POLL_INTERVAL: Duration = Duration::from_mins(1);
+
+/// The background task declining the pending trade offers in `elective_subject_trade_offers` which
+/// have expired or whose students' enrollment period has closed, setting their `decline_reason`.
+///
+/// Offers are claimed with `SKIP LOCKED`, so any number of instances can run a sweeper, and an offer
+/// being approved or cancelled at the same time is left for the next sweep.
+pub struct TradeOfferSweeper;
+
+impl TradeOfferSweeper {
+    pub fn spawn(pool: PgPool, tasks: &mut BackgroundTasks) {
+        tasks.spawn(|stop| Self::run(pool, stop));
+    }
+
+    async fn run(pool: PgPool, mut stop: StopSignal) {
+        loop {
+            if let Err(err) = Self::sweep(&pool).await {
+                tracing::error!("Failed to sweep trade offers: {err}");
+            }
+            tokio::select! {
+                () = stop.stopped() => break,
+                () = tokio::time::sleep(POLL_INTERVAL) => (),
+            }
+        }
+    }
+
+    /// Declines every pending offer which can no longer be approved.
+    pub(crate) async fn sweep(pool: &PgPool) -> Result<()> {
+        let mut transaction = pool.begin().await?;
+        let expired = DbElectiveTradeOffer::decline_expired(&mut transaction).await?;
+        let closed = DbElectiveTradeOffer::decline_closed(&mut transaction).await?;
+        transaction.commit().await?;
+
+        for (reason, declined) in [
+            (TradeOfferDeclineReason::Expired, expired.len()),
+            (TradeOfferDeclineReason::EnrollmentClosed, closed.len()),
+        ] {
+            if declined > 0 {
+                tracing::info!("Declined {declined} trade offers ({reason})");
+                METRICS
+                    .elective_trade_offers
+                    .with_label_values(&[reason.to_string().as_str()])
+                    .inc_by(declined as u64);
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,234 @@
+use crate::{
+    background::{BackgroundTasks, StopSignal},
+    metrics::METRICS,
+};
+use chrono::{DateTime, TimeDelta, Utc};
+use futures::future;
+use hmac::{Hmac, Mac as _};
+use mysk_lib::{prelude::*, webhooks::WebhookEvent};
+use reqwest::Client;
+use serde_json::{Value, json};
+use sha2::Sha256;
+use sqlx::{PgPool, query};
+use std::time::{Duration, Instant};
+use uuid::Uuid;
+
+/// How often the outbox is checked for events due to be sent.
+const POLL_INTERVAL: Duration = Duration::from_secs(5);
+/// The maximum number of events sent at once.
+const BATCH_SIZE: i64 = 32;
+/// How long an endpoint has to respond before the attempt fails.
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+/// How long a claimed event is hidden from other instances while it is being sent. Must be longer
+/// than [`REQUEST_TIMEOUT`].
+const LEASE: TimeDelta = TimeDelta::seconds(60);
+/// The delay before the first retry, doubled after every failed attempt.
+const BASE_RETRY_DELAY: TimeDelta = TimeDelta::seconds(30);
+/// The number of attempts after which an event is abandoned, about 2 hours after it was raised.
+const MAX_ATTEMPTS: i32 = 9;
+
+/// An event claimed from `webhook_outbox`, along with where to send it.
+struct Delivery {
+    id: Uuid,
+    created_at: DateTime<Utc>,
+    endpoint_id: Uuid,
+    event: WebhookEvent,
+    payload: Value,
+    attempts: i32,
+    url: String,
+    secret: String,
+}
+
+/// The background task sending the events in `webhook_outbox` to their endpoints.
+///
+/// Every payload is signed with the secret of its endpoint: `X-MySK-Signature` holds
+/// `sha256=` followed by the hex-encoded HMAC-SHA256 of `X-MySK-Timestamp`, a `.` and the body.
+/// Failed attempts are retried with an exponential backoff, and each one is recorded in
+/// `api_logging.webhook_deliveries`. Events are claimed with `SKIP LOCKED`, so any number of
+/// instances can run a dispatcher without sending an event twice at once.
+pub struct WebhookDispatcher;
+
+impl WebhookDispatcher {
+    pub fn spawn(pool: PgPool, tasks: &mut BackgroundTasks) {
+        let client = Client::builder()
+            .timeout(REQUEST_TIMEOUT)
+            .user_agent(concat!("MySK-Webhooks/", env!("CARGO_PKG_VERSION")))
+            .build()
+            .expect("Irrecoverable error, failed to build the webhook client");
+        tasks.spawn(|stop| Self::run(pool, client, stop));
+    }
+
+    /// Sends batches of due events until stopped, finishing the batch being sent first.
+    async fn run(pool: PgPool, client: Client, mut stop: StopSignal) {
+        while !stop.is_stopped() {
+            match Self::dispatch_due(&pool, &client).await {
+                // There may be more waiting
+                Ok(sent) if sent == BATCH_SIZE => continue,
+                Ok(_) => (),
+                Err(err) => tracing::error!("Failed to dispatch webhooks: {err}"),
+            }
+            tokio::select! {
+                () = stop.stopped() => break,
+                () = tokio::time::sleep(POLL_INTERVAL) => (),
+            }
+        }
+    }
+
+    /// Sends a batch of the events due, returning how many were sent.
+    async fn dispatch_due(pool: &PgPool, client: &Client) -> Result<i64> {
+        let deliveries = query!(
+            r#"
+            UPDATE webhook_outbox AS o
+            SET attempts = o.attempts + 1, next_attempt_at = $2
+            FROM webhook_endpoints AS e
+            WHERE e.id = o.endpoint_id AND o.id IN (
+                SELECT o.id FROM webhook_outbox AS o
+                JOIN webhook_endpoints AS e ON e.id = o.endpoint_id
+                WHERE e.is_active AND o.delivered_at IS NULL AND o.abandoned_at IS NULL
+                    AND o.next_attempt_at <= now()
+                ORDER BY o.next_attempt_at LIMIT $1
+                FOR UPDATE OF o SKIP LOCKED
+            )
+            RETURNING o.id, o.created_at, o.endpoint_id, o.event AS "event: WebhookEvent",
+                o.payload, o.attempts, e.url, e.secret
+            "#,
+            BATCH_SIZE,
+            Utc::now() + LEASE,
+        )
+        .fetch_all(pool)
+        .await?;
+        let sent = i64::try_from(deliveries.len()).unwrap_or(i64::MAX);
+
+        future::join_all(deliveries.into_iter().map(|r| {
+            Self::deliver(
+                pool,
+                client,
+                Delivery {
+                    id: r.id,
+                    created_at: r.created_at,
+                    endpoint_id: r.endpoint_id,
+                    event: r.event,
+                    payload: r.payload,
+                    attempts: r.attempts,
+                    url: r.url,
+                    secret: r.secret,
+                },
+            )
+        }))
+        .await
+        .into_iter()
+        .collect::<Result<Vec<_>>>()?;
+
+        Ok(sent)
+    }
+
+    async fn deliver(pool: &PgPool, client: &Client, delivery: Delivery) -> Result<()> {
+        let body = json!({
+            "id": delivery.id,
+            "event": delivery.event,
+            "created_at": delivery.created_at,
+            "data": delivery.payload,
+        })
+        .to_string();
+        let timestamp = Utc::now().timestamp().to_string();
+        let signature = sign(&delivery.secret, &timestamp, &body);
+
+        let started_at = Instant::now();
+        let res = client
+            .post(&delivery.url)
+            .header("Content-Type", "application/json")
+            .header("X-MySK-Event", delivery.event.to_string())
+            .header("X-MySK-Delivery", delivery.id.to_string())
+            .header("X-MySK-Timestamp", &timestamp)
+            .header("X-MySK-Signature", format!("sha256={signature}"))
+            .body(body)
+            .send()
+            .await;
+        let duration_ms = i64::try_from(started_at.elapsed().as_millis()).unwrap_or(i64::MAX);
+
+        let (status_code, error) = match res {
+            Ok(res) if res.status().is_success() => (Some(res.status().as_u16()), None),
+            Ok(res) => (
+                Some(res.status().as_u16()),
+                Some(format!("The endpoint responded with {}", res.status())),
+            ),
+            Err(err) => (None, Some(err.to_string())),
+        };
+
+        let now = Utc::now();
+        let (delivered_at, abandoned_at, next_attempt_at) = if error.is_none() {
+            METRICS
+                .webhook_deliveries
+                .with_label_values(&["delivered"])
+                .inc();
+            (Some(now), None, now)
+        } else if delivery.attempts >= MAX_ATTEMPTS {
+            METRICS
+                .webhook_deliveries
+                .with_label_values(&["abandoned"])
+                .inc();
+            tracing::warn!(
+                outbox_id = %delivery.id,
+                "Abandoned a {} webhook after {} attempts",
+                delivery.event,
+                delivery.attempts,
+            );
+            (None, Some(now), now)
+        } else {
+            METRICS
+                .webhook_deliveries
+                .with_label_values(&["failed"])
+                .inc();
+            (None, None, now + retry_delay(delivery.attempts))
+        };
+
+        let mut transaction = pool.begin().await?;
+        query!(
+            "\
+            INSERT INTO api_logging.webhook_deliveries \
+            (outbox_id, endpoint_id, event, attempt, status_code, error, duration_ms) \
+            VALUES ($1, $2, $3, $4, $5, $6, $7)\
+            ",
+            delivery.id,
+            delivery.endpoint_id,
+            delivery.event as WebhookEvent,
+            delivery.attempts,
+            status_code.map(i32::from),
+            error,
+            duration_ms,
+        )
+        .execute(&mut *transaction)
+        .await?;
+        query!(
+            "\
+            UPDATE webhook_outbox \
+            SET delivered_at = $2, abandoned_at = $3, next_attempt_at = $4 WHERE id = $1\
+            ",
+            delivery.id,
+            delivered_at,
+            abandoned_at,
+            next_attempt_at,
+        )
+        .execute(&mut *transaction)
+        .await?;
+        transaction.commit().await?;
+
+        Ok(())
+    }
+}
+
+/// Signs a payload sent at `timestamp`, returning the hex-encoded HMAC-SHA256.
+pub fn sign(secret: &str, timestamp: &str, body: &str) -> String {
+    let mut mac =
+        Hmac::<Sha256>::new_from_slice(secret.as_bytes()).expect("HMAC can take a key of any size");
+    mac.update(timestamp.as_bytes());
+    mac.update(b".");
+    mac.update(body.as_bytes());
+
+    format!("{:x}", mac.finalize().into_bytes())
+}
+
+/// The delay before the attempt following the `attempts`th one.
+fn retry_delay(attempts: i32) -> TimeDelta {
+    BASE_RETRY_DELAY * 2_i32.pow(u32::try_from(attempts - 1).unwrap_or(0))
+}
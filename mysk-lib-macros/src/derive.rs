@@ -1,3 +1,6 @@
+// Expansion of `FromDeriveInput` trips this lint on recent toolchains
+#![allow(clippy::needless_continue)]
+
 use darling::FromDeriveInput;
 use proc_macro::{self, TokenStream};
 use quote::{ToTokens, quote};
@@ -10,8 +13,13 @@ struct GetByIdOpts {
     relation: Option<String>,
     query: String,
     count_query: String,
+    cache: Option<u64>,
+    cache_capacity: Option<usize>,
 }
 
+const DEFAULT_CACHE_CAPACITY: usize = 1024;
+
+#[allow(clippy::too_many_lines)]
 pub(crate) fn expand_from_query(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input);
     let GetByIdOpts {
@@ -19,6 +27,8 @@ pub(crate) fn expand_from_query(input: TokenStream) -> TokenStream {
         relation,
         query,
         count_query,
+        cache,
+        cache_capacity,
     } = match GetByIdOpts::from_derive_input(&input) {
         Ok(opts) => opts,
         Err(err) => {
@@ -46,35 +56,115 @@ pub(crate) fn expand_from_query(input: TokenStream) -> TokenStream {
         quote! { concat!(#query, " WHERE id = ANY($1) ORDER BY id") }
     };
 
-    let expanded = quote! {
-        #[automatically_derived]
-        impl crate::models::traits::GetById for #ident {
-            const BASE_QUERY: &str = #query;
-
-            const COUNT_QUERY: &str = #count_query;
-
-            type Id = #id;
-
-            async fn get_by_id(
-                conn: &mut ::sqlx::PgConnection,
-                id: Self::Id,
-            ) -> ::std::result::Result<Self, sqlx::Error> {
-                ::sqlx::query_as::<_, #ident>(#query_one)
-                    .bind(id)
-                    .fetch_one(&mut *conn)
-                    .await
+    let Some(ttl) = cache else {
+        let expanded = quote! {
+            #[automatically_derived]
+            impl crate::models::traits::GetById for #ident {
+                const BASE_QUERY: &str = #query;
+
+                const COUNT_QUERY: &str = #count_query;
+
+                type Id = #id;
+
+                async fn get_by_id(
+                    conn: &mut ::sqlx::PgConnection,
+                    id: Self::Id,
+                ) -> ::std::result::Result<Self, sqlx::Error> {
+                    ::sqlx::query_as::<_, #ident>(#query_one)
+                        .bind(id)
+                        .fetch_one(&mut *conn)
+                        .await
+                }
+
+                async fn get_by_ids(
+                    pool: &::sqlx::PgPool,
+                    id: &[Self::Id],
+                ) -> ::std::result::Result<Vec<Self>, sqlx::Error> {
+                    ::sqlx::query_as::<_, #ident>(#query_many)
+                        .bind(id)
+                        .fetch_all(pool)
+                        .await
+                }
             }
+        };
+
+        return expanded.into();
+    };
+    let capacity = cache_capacity.unwrap_or(DEFAULT_CACHE_CAPACITY);
+
+    let expanded = quote! {
+        const _: () = {
+            static CACHE: ::std::sync::LazyLock<crate::cache::RelationCache<#id, #ident>> =
+                ::std::sync::LazyLock::new(|| {
+                    crate::cache::RelationCache::new(
+                        #capacity,
+                        ::std::time::Duration::from_secs(#ttl),
+                    )
+                });
 
-            async fn get_by_ids(
-                pool: &::sqlx::PgPool,
-                id: &[Self::Id],
-            ) -> ::std::result::Result<Vec<Self>, sqlx::Error> {
-                ::sqlx::query_as::<_, #ident>(#query_many)
-                    .bind(id)
-                    .fetch_all(pool)
-                    .await
+            #[automatically_derived]
+            impl crate::models::traits::GetById for #ident {
+                const BASE_QUERY: &str = #query;
+
+                const COUNT_QUERY: &str = #count_query;
+
+                type Id = #id;
+
+                async fn get_by_id(
+                    conn: &mut ::sqlx::PgConnection,
+                    id: Self::Id,
+                ) -> ::std::result::Result<Self, sqlx::Error> {
+                    if let Some(row) = CACHE.get(&id).await {
+                        return Ok(row);
+                    }
+
+                    let row = ::sqlx::query_as::<_, #ident>(#query_one)
+                        .bind(id)
+                        .fetch_one(&mut *conn)
+                        .await?;
+                    CACHE.insert(row.id, row.clone()).await;
+
+                    Ok(row)
+                }
+
+                async fn get_by_ids(
+                    pool: &::sqlx::PgPool,
+                    ids: &[Self::Id],
+                ) -> ::std::result::Result<Vec<Self>, sqlx::Error> {
+                    let mut rows = Vec::with_capacity(ids.len());
+                    let mut missing = Vec::new();
+                    for id in ids {
+                        match CACHE.get(id).await {
+                            Some(row) => rows.push(row),
+                            None => missing.push(*id),
+                        }
+                    }
+
+                    if !missing.is_empty() {
+                        let fetched = ::sqlx::query_as::<_, #ident>(#query_many)
+                            .bind(&missing)
+                            .fetch_all(pool)
+                            .await?;
+                        for row in &fetched {
+                            CACHE.insert(row.id, row.clone()).await;
+                        }
+                        rows.extend(fetched);
+                    }
+
+                    // Keep the same ordering and uniqueness as `ORDER BY id` on `id = ANY($1)`
+                    rows.sort_unstable_by(|a, b| a.id.cmp(&b.id));
+                    rows.dedup_by(|a, b| a.id == b.id);
+
+                    Ok(rows)
+                }
+
+                async fn evict_cached(ids: &[Self::Id]) {
+                    for id in ids {
+                        CACHE.evict(id).await;
+                    }
+                }
             }
-        }
+        };
     };
 
     expanded.into()
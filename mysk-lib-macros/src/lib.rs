@@ -5,13 +5,14 @@ use proc_macro::TokenStream;
 
 mod derive;
 mod fetch_variant;
+mod validate;
 
 /// Implements a fetch variant for a base relation. Note that this macro is more of a "helper" macro
 /// and fetch variants that require additional dependencies must be hand-written.
 ///
 /// The signature of the macro being:
 ///
-/// ```rust
+/// ```rust,ignore
 /// struct RelationStruct {
 ///     id: Uuid,
 ///     created_at: DateTime<Utc>,
@@ -49,7 +50,7 @@ pub fn impl_fetch_variant_from(input: TokenStream) -> TokenStream {
 ///
 /// The signature of the macro being:
 ///
-/// ```rust
+/// ```rust,ignore
 /// struct RelationStruct {
 ///     id: Uuid,
 ///     created_at: DateTime<Utc>,
@@ -81,7 +82,31 @@ pub fn impl_id_only_variant_from(input: TokenStream) -> TokenStream {
     fetch_variant::make_from_id_only(input)
 }
 
+/// Derives [`GetById`] for a base relation from a base query.
+///
+/// - `query`: The base query of the relation, without a `WHERE` clause.
+/// - `count_query`: The count query of the relation.
+/// - `id`: The type of the ID column, defaults to `Uuid`.
+/// - `relation`: The table name used to qualify the ID column when the base query has joins.
+/// - `cache`: Enables a read-through cache for `get_by_id` and `get_by_ids` with the given TTL in
+///   seconds. Routes modifying the relation must evict the rows they change.
+/// - `cache_capacity`: The maximum number of cached rows, defaults to 1024.
 #[proc_macro_derive(GetById, attributes(from_query))]
 pub fn derive_from_query(input: TokenStream) -> TokenStream {
     derive::expand_from_query(input)
 }
+
+/// Derives [`Validate`] for a request body from `#[validate(...)]` attributes on its fields. A
+/// field which is an `Option` is only checked when it is given.
+///
+/// - `not_empty`: Strings and names must not be blank, and lists must not be empty.
+/// - `past`: Dates must not be in the future.
+/// - `not_nil`: IDs must not be the nil UUID.
+/// - `min`: Numbers must be at least the given value.
+/// - `contact_type`: The value of a contact must fit the type in the given field, e.g.
+///   `#[validate(contact_type = r#type)]`.
+/// - `nested`: The field is validated as a [`Validate`] of its own.
+#[proc_macro_derive(Validate, attributes(validate))]
+pub fn derive_validate(input: TokenStream) -> TokenStream {
+    validate::expand_validate(input)
+}
@@ -0,0 +1,133 @@
+// Expansion of `FromDeriveInput` trips this lint on recent toolchains
+#![allow(clippy::needless_continue)]
+
+use darling::{FromDeriveInput, FromField, ast::Data, util::Flag};
+use proc_macro::{self, TokenStream};
+use quote::quote;
+use syn::{
+    DeriveInput, GenericArgument, Ident, Path, PathArguments, Type, ext::IdentExt as _,
+    parse_macro_input,
+};
+
+#[derive(FromDeriveInput)]
+#[darling(attributes(validate), supports(struct_named))]
+struct ValidateOpts {
+    ident: Ident,
+    data: Data<(), ValidateField>,
+}
+
+#[derive(FromField)]
+#[darling(attributes(validate))]
+struct ValidateField {
+    ident: Option<Ident>,
+    ty: Type,
+    not_empty: Flag,
+    past: Flag,
+    not_nil: Flag,
+    min: Option<i64>,
+    contact_type: Option<Path>,
+    nested: Flag,
+}
+
+/// The `T` of an `Option<T>`, if the type is one.
+fn option_inner(ty: &Type) -> Option<&Type> {
+    let Type::Path(ty) = ty else {
+        return None;
+    };
+    let segment = ty.path.segments.last()?;
+    if segment.ident != "Option" {
+        return None;
+    }
+    let PathArguments::AngleBracketed(args) = &segment.arguments else {
+        return None;
+    };
+    match args.args.first()? {
+        GenericArgument::Type(inner) => Some(inner),
+        _ => None,
+    }
+}
+
+pub(crate) fn expand_validate(input: TokenStream) -> TokenStream {
+    let input: DeriveInput = parse_macro_input!(input);
+    let ValidateOpts { ident, data } = match ValidateOpts::from_derive_input(&input) {
+        Ok(opts) => opts,
+        Err(err) => {
+            return err.write_errors().into();
+        }
+    };
+    let fields = data
+        .take_struct()
+        .expect("Only structs with named fields are supported")
+        .fields;
+
+    let checks = fields.into_iter().map(|field| {
+        let member = field.ident.expect("Only named fields are supported");
+        let name = member.unraw().to_string();
+
+        let mut rules = Vec::new();
+        if field.not_empty.is_present() {
+            rules.push(quote! { ::mysk_lib::common::validation::rules::not_empty(value) });
+        }
+        if field.past.is_present() {
+            rules.push(quote! { ::mysk_lib::common::validation::rules::past(value) });
+        }
+        if field.not_nil.is_present() {
+            rules.push(quote! { ::mysk_lib::common::validation::rules::not_nil(value) });
+        }
+        if let Some(min) = field.min {
+            rules.push(quote! { ::mysk_lib::common::validation::rules::min(value, #min) });
+        }
+        if let Some(contact_type) = field.contact_type {
+            rules.push(quote! {
+                ::mysk_lib::common::validation::rules::contact_value(self.#contact_type, value)
+            });
+        }
+        let nested = field.nested.is_present().then(|| {
+            quote! { ::mysk_lib::common::validation::Validate::validate(value, &path, errors); }
+        });
+        if rules.is_empty() && nested.is_none() {
+            return quote! {};
+        }
+
+        let body = quote! {
+            let path = format!("{}.{}", path, #name);
+            #(
+                if let Err(reason) = #rules {
+                    errors.push(::mysk_lib::common::validation::FieldError::new(&path, reason));
+                }
+            )*
+            #nested
+        };
+
+        // Fields left out of the request aren't checked
+        if option_inner(&field.ty).is_some() {
+            quote! {
+                if let Some(value) = &self.#member {
+                    #body
+                }
+            }
+        } else {
+            quote! {
+                {
+                    let value = &self.#member;
+                    #body
+                }
+            }
+        }
+    });
+
+    let expanded = quote! {
+        #[automatically_derived]
+        impl ::mysk_lib::common::validation::Validate for #ident {
+            fn validate(
+                &self,
+                path: &str,
+                errors: &mut Vec<::mysk_lib::common::validation::FieldError>,
+            ) {
+                #(#checks)*
+            }
+        }
+    };
+
+    expanded.into()
+}
@@ -0,0 +1,4 @@
+fn main() {
+    // Embedded by `sqlx::migrate!`
+    println!("cargo::rerun-if-changed=migrations");
+}
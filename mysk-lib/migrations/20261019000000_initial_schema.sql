@@ -0,0 +1,558 @@
+-- The schema MySK API was written against, before it was tracked by migrations. Existing databases
+-- already have it and should be marked as migrated with `mysk-data-api --baseline` instead.
+
+-- Enums
+
+CREATE TYPE user_role AS ENUM ('student', 'teacher', 'organization', 'staff', 'management');
+
+CREATE TYPE submission_status AS ENUM ('approved', 'pending', 'declined');
+
+CREATE TYPE certificate_type AS ENUM (
+    'student_of_the_year', 'excellent_student', 'academic', 'morale', 'sports', 'activity'
+);
+
+CREATE TYPE cheer_practice_attendance_type AS ENUM (
+    'present', 'late', 'absent_with_leave', 'absent_without_leave', 'deserted'
+);
+
+CREATE TYPE contact_types AS ENUM (
+    'phone', 'email', 'facebook', 'line', 'instagram', 'website', 'discord', 'other'
+);
+
+CREATE TYPE sex AS ENUM ('male', 'female', 'other');
+
+CREATE TYPE shirt_size AS ENUM ('XS', 'S', 'M', 'L', 'XL', '2XL', '3XL', '4XL', '5XL', '6XL');
+
+CREATE TYPE blood_group AS ENUM ('A+', 'A-', 'B+', 'B-', 'O+', 'O-', 'AB+', 'AB-');
+
+CREATE TYPE subject_type_en_enum AS ENUM (
+    'core_course', 'additional_course', 'elective', 'learners_development_activities'
+);
+
+-- Functions
+
+CREATE FUNCTION get_current_academic_year(date DATE) RETURNS BIGINT
+LANGUAGE sql IMMUTABLE AS $$
+    SELECT CASE
+        WHEN EXTRACT(MONTH FROM date) <= 3 THEN EXTRACT(YEAR FROM date)::BIGINT - 1
+        ELSE EXTRACT(YEAR FROM date)::BIGINT
+    END
+$$;
+
+-- Users and authentication
+
+CREATE TABLE users (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    created_at TIMESTAMPTZ DEFAULT now(),
+    email TEXT NOT NULL UNIQUE,
+    role user_role NOT NULL,
+    is_admin BOOLEAN NOT NULL DEFAULT FALSE,
+    onboarded BOOLEAN NOT NULL DEFAULT FALSE
+);
+
+CREATE TABLE user_api_keys (
+    created_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+    user_id UUID NOT NULL REFERENCES users (id) ON DELETE CASCADE,
+    long_token_hash TEXT NOT NULL,
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    expire_at TIMESTAMPTZ,
+    short_token TEXT NOT NULL UNIQUE
+);
+
+CREATE TABLE permissions (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    created_at TIMESTAMPTZ DEFAULT now(),
+    name TEXT NOT NULL UNIQUE
+);
+
+CREATE TABLE user_permissions (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    created_at TIMESTAMPTZ DEFAULT now(),
+    user_id UUID NOT NULL REFERENCES users (id) ON DELETE CASCADE,
+    permission_id UUID NOT NULL REFERENCES permissions (id) ON DELETE CASCADE,
+    UNIQUE (user_id, permission_id)
+);
+
+-- People
+
+CREATE TABLE people (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    created_at TIMESTAMPTZ DEFAULT now(),
+    prefix_th TEXT NOT NULL,
+    prefix_en TEXT,
+    first_name_th TEXT NOT NULL,
+    first_name_en TEXT,
+    last_name_th TEXT NOT NULL,
+    last_name_en TEXT,
+    middle_name_th TEXT,
+    middle_name_en TEXT,
+    nickname_th TEXT,
+    nickname_en TEXT,
+    birthdate DATE,
+    citizen_id TEXT,
+    profile TEXT,
+    pants_size TEXT,
+    shirt_size shirt_size,
+    blood_group blood_group,
+    sex sex NOT NULL
+);
+
+CREATE TABLE person_allergies (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    created_at TIMESTAMPTZ DEFAULT now(),
+    person_id UUID NOT NULL REFERENCES people (id) ON DELETE CASCADE,
+    allergy_name TEXT NOT NULL
+);
+
+CREATE TABLE contacts (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    created_at TIMESTAMPTZ DEFAULT now(),
+    name_th TEXT,
+    name_en TEXT,
+    type contact_types NOT NULL,
+    value TEXT NOT NULL
+);
+
+CREATE TABLE person_contacts (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    created_at TIMESTAMPTZ DEFAULT now(),
+    person_id UUID NOT NULL REFERENCES people (id) ON DELETE CASCADE,
+    contact_id UUID NOT NULL REFERENCES contacts (id) ON DELETE CASCADE
+);
+
+CREATE TABLE subject_groups (
+    id BIGINT PRIMARY KEY GENERATED BY DEFAULT AS IDENTITY,
+    created_at TIMESTAMPTZ DEFAULT now(),
+    name_th TEXT NOT NULL,
+    name_en TEXT NOT NULL
+);
+
+CREATE TABLE students (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    created_at TIMESTAMPTZ DEFAULT now(),
+    student_id TEXT UNIQUE,
+    person_id UUID NOT NULL REFERENCES people (id),
+    user_id UUID REFERENCES users (id) ON DELETE SET NULL
+);
+
+CREATE TABLE teachers (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    created_at TIMESTAMPTZ DEFAULT now(),
+    teacher_id TEXT,
+    subject_group_id BIGINT NOT NULL REFERENCES subject_groups (id),
+    person_id UUID REFERENCES people (id),
+    user_id UUID REFERENCES users (id) ON DELETE SET NULL
+);
+
+CREATE TABLE organizations (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    created_at TIMESTAMPTZ DEFAULT now(),
+    name_th TEXT NOT NULL,
+    name_en TEXT,
+    description_th TEXT,
+    description_en TEXT,
+    main_room TEXT,
+    logo_url TEXT,
+    user_id UUID REFERENCES users (id) ON DELETE SET NULL
+);
+
+-- Classrooms
+
+CREATE TABLE classrooms (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    created_at TIMESTAMPTZ DEFAULT now(),
+    number BIGINT NOT NULL,
+    year BIGINT NOT NULL,
+    main_room TEXT,
+    UNIQUE (number, year)
+);
+
+CREATE TABLE classroom_students (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    created_at TIMESTAMPTZ DEFAULT now(),
+    classroom_id UUID NOT NULL REFERENCES classrooms (id) ON DELETE CASCADE,
+    student_id UUID NOT NULL REFERENCES students (id) ON DELETE CASCADE,
+    class_no BIGINT NOT NULL,
+    UNIQUE (classroom_id, student_id)
+);
+
+CREATE TABLE classroom_advisors (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    created_at TIMESTAMPTZ DEFAULT now(),
+    classroom_id UUID NOT NULL REFERENCES classrooms (id) ON DELETE CASCADE,
+    teacher_id UUID NOT NULL REFERENCES teachers (id) ON DELETE CASCADE,
+    UNIQUE (classroom_id, teacher_id)
+);
+
+CREATE TABLE classroom_contacts (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    created_at TIMESTAMPTZ DEFAULT now(),
+    classroom_id UUID NOT NULL REFERENCES classrooms (id) ON DELETE CASCADE,
+    contact_id UUID NOT NULL REFERENCES contacts (id) ON DELETE CASCADE
+);
+
+-- Subjects
+
+CREATE TABLE subjects (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    created_at TIMESTAMPTZ DEFAULT now(),
+    name_th TEXT NOT NULL,
+    name_en TEXT NOT NULL,
+    code_th TEXT NOT NULL,
+    code_en TEXT NOT NULL,
+    short_name_th TEXT,
+    short_name_en TEXT,
+    type subject_type_en_enum NOT NULL,
+    credit DOUBLE PRECISION NOT NULL,
+    description_th TEXT,
+    description_en TEXT,
+    semester BIGINT,
+    subject_group_id BIGINT NOT NULL REFERENCES subject_groups (id),
+    syllabus TEXT
+);
+
+CREATE TABLE subject_teachers (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    created_at TIMESTAMPTZ DEFAULT now(),
+    subject_id UUID NOT NULL REFERENCES subjects (id) ON DELETE CASCADE,
+    teacher_id UUID NOT NULL REFERENCES teachers (id) ON DELETE CASCADE,
+    year BIGINT NOT NULL
+);
+
+CREATE TABLE subject_co_teachers (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    created_at TIMESTAMPTZ DEFAULT now(),
+    subject_id UUID NOT NULL REFERENCES subjects (id) ON DELETE CASCADE,
+    teacher_id UUID NOT NULL REFERENCES teachers (id) ON DELETE CASCADE,
+    year BIGINT NOT NULL
+);
+
+CREATE TABLE subject_requirements (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    created_at TIMESTAMPTZ DEFAULT now(),
+    subject_id UUID NOT NULL REFERENCES subjects (id) ON DELETE CASCADE,
+    label_th TEXT NOT NULL,
+    label_en TEXT
+);
+
+CREATE TABLE classroom_subjects (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    created_at TIMESTAMPTZ DEFAULT now(),
+    classroom_id UUID NOT NULL REFERENCES classrooms (id) ON DELETE CASCADE,
+    subject_id UUID NOT NULL REFERENCES subjects (id) ON DELETE CASCADE,
+    year BIGINT NOT NULL
+);
+
+-- Elective subjects
+
+CREATE TABLE elective_subject_sessions (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    created_at TIMESTAMPTZ DEFAULT now(),
+    subject_id UUID NOT NULL REFERENCES subjects (id) ON DELETE CASCADE,
+    cap_size BIGINT NOT NULL,
+    room TEXT NOT NULL,
+    year BIGINT,
+    semester BIGINT,
+    session_code TEXT NOT NULL
+);
+
+CREATE TABLE elective_subject_session_classrooms (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    created_at TIMESTAMPTZ DEFAULT now(),
+    elective_subject_session_id UUID NOT NULL
+        REFERENCES elective_subject_sessions (id) ON DELETE CASCADE,
+    classroom_id UUID NOT NULL REFERENCES classrooms (id) ON DELETE CASCADE,
+    UNIQUE (elective_subject_session_id, classroom_id)
+);
+
+CREATE TABLE elective_subject_session_enrolled_students (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    created_at TIMESTAMPTZ DEFAULT now(),
+    updated_at TIMESTAMPTZ DEFAULT now(),
+    student_id UUID NOT NULL REFERENCES students (id) ON DELETE CASCADE,
+    elective_subject_session_id UUID NOT NULL
+        REFERENCES elective_subject_sessions (id) ON DELETE CASCADE,
+    is_randomized BOOLEAN NOT NULL DEFAULT FALSE,
+    UNIQUE (student_id, elective_subject_session_id)
+);
+
+CREATE TABLE elective_subject_session_blacklisted_students (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    created_at TIMESTAMPTZ DEFAULT now(),
+    student_id UUID NOT NULL UNIQUE REFERENCES students (id) ON DELETE CASCADE
+);
+
+CREATE TABLE elective_subject_enrollment_periods (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    created_at TIMESTAMPTZ DEFAULT now(),
+    start_time TIMESTAMPTZ NOT NULL,
+    end_time TIMESTAMPTZ NOT NULL,
+    grade BIGINT
+);
+
+CREATE TABLE elective_subject_trade_offers (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    created_at TIMESTAMPTZ DEFAULT now(),
+    sender_id UUID NOT NULL REFERENCES students (id) ON DELETE CASCADE,
+    receiver_id UUID NOT NULL REFERENCES students (id) ON DELETE CASCADE,
+    status submission_status NOT NULL DEFAULT 'pending',
+    sender_elective_subject_session_id UUID NOT NULL
+        REFERENCES elective_subject_sessions (id) ON DELETE CASCADE,
+    receiver_elective_subject_session_id UUID NOT NULL
+        REFERENCES elective_subject_sessions (id) ON DELETE CASCADE
+);
+
+CREATE VIEW elective_subject_sessions_with_detail_view AS
+SELECT
+    ess.id,
+    ess.created_at,
+    ess.subject_id,
+    ess.cap_size,
+    (
+        SELECT COUNT(*) FROM elective_subject_session_enrolled_students AS esses
+        WHERE esses.elective_subject_session_id = ess.id
+    ) AS class_size,
+    ess.room,
+    su.name_th,
+    su.name_en,
+    su.code_th,
+    su.code_en,
+    su.short_name_th,
+    su.short_name_en,
+    su.type,
+    su.credit,
+    su.description_th,
+    su.description_en,
+    ess.year,
+    ess.semester,
+    su.subject_group_id,
+    su.syllabus,
+    ess.session_code
+FROM elective_subject_sessions AS ess
+JOIN subjects AS su ON su.id = ess.subject_id;
+
+-- Clubs
+
+CREATE TABLE clubs (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    created_at TIMESTAMPTZ DEFAULT now(),
+    organization_id UUID NOT NULL REFERENCES organizations (id) ON DELETE CASCADE,
+    accent_color TEXT,
+    background_color TEXT,
+    house TEXT,
+    map_location BIGINT
+);
+
+CREATE TABLE club_members (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    created_at TIMESTAMPTZ DEFAULT now(),
+    club_id UUID NOT NULL REFERENCES clubs (id) ON DELETE CASCADE,
+    year BIGINT,
+    membership_status submission_status NOT NULL DEFAULT 'pending',
+    student_id UUID NOT NULL REFERENCES students (id) ON DELETE CASCADE,
+    UNIQUE (club_id, year, student_id)
+);
+
+CREATE TABLE club_staffs (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    created_at TIMESTAMPTZ DEFAULT now(),
+    club_id UUID NOT NULL REFERENCES clubs (id) ON DELETE CASCADE,
+    student_id UUID NOT NULL REFERENCES students (id) ON DELETE CASCADE,
+    year BIGINT NOT NULL
+);
+
+CREATE TABLE club_contacts (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    created_at TIMESTAMPTZ DEFAULT now(),
+    club_id UUID NOT NULL REFERENCES clubs (id) ON DELETE CASCADE,
+    contact_id UUID NOT NULL REFERENCES contacts (id) ON DELETE CASCADE
+);
+
+CREATE TABLE student_club_quotas (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    created_at TIMESTAMPTZ DEFAULT now(),
+    student_id UUID NOT NULL REFERENCES students (id) ON DELETE CASCADE,
+    year BIGINT NOT NULL,
+    max_clubs INTEGER NOT NULL,
+    UNIQUE (student_id, year)
+);
+
+CREATE VIEW clubs_with_detail_view AS
+SELECT
+    c.id,
+    c.created_at,
+    c.organization_id,
+    c.accent_color,
+    c.background_color,
+    c.house,
+    c.map_location,
+    o.description_en,
+    o.description_th,
+    o.logo_url,
+    o.name_en,
+    o.name_th,
+    (
+        SELECT COUNT(*) FROM club_members AS cm
+        WHERE cm.club_id = c.id AND cm.membership_status = 'approved'
+            AND cm.year = get_current_academic_year(CAST(now() AS DATE))
+    ) AS member_count,
+    (
+        SELECT COUNT(*) FROM club_staffs AS cs
+        WHERE cs.club_id = c.id AND cs.year = get_current_academic_year(CAST(now() AS DATE))
+    ) AS staff_count
+FROM clubs AS c
+JOIN organizations AS o ON o.id = c.organization_id;
+
+-- Every student in a classroom gets one club by default unless given a quota
+CREATE VIEW student_club_eligibility AS
+SELECT
+    cs.student_id,
+    c.year,
+    (
+        SELECT COUNT(*) FROM club_members AS cm
+        WHERE cm.student_id = cs.student_id AND cm.year = c.year
+            AND cm.membership_status = 'approved'
+    ) AS club_count,
+    COALESCE(q.max_clubs, 1) AS max_clubs
+FROM classroom_students AS cs
+JOIN classrooms AS c ON c.id = cs.classroom_id
+LEFT JOIN student_club_quotas AS q ON q.student_id = cs.student_id AND q.year = c.year;
+
+-- Certificates
+
+CREATE TABLE student_certificates (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    created_at TIMESTAMPTZ DEFAULT now(),
+    student_id UUID NOT NULL REFERENCES students (id) ON DELETE CASCADE,
+    certificate_type certificate_type NOT NULL,
+    certificate_detail TEXT NOT NULL,
+    year BIGINT NOT NULL,
+    receiving_order_number BIGINT,
+    seat_code TEXT,
+    rsvp_status submission_status
+);
+
+CREATE TABLE certificate_ceremony_rsvp_periods (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    created_at TIMESTAMPTZ DEFAULT now(),
+    start_time TIMESTAMPTZ NOT NULL,
+    end_time TIMESTAMPTZ NOT NULL
+);
+
+-- Cheer practice
+
+CREATE TABLE cheer_practice_periods (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    created_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+    date DATE NOT NULL,
+    start_time TIME NOT NULL,
+    end_time TIME NOT NULL,
+    delay BIGINT,
+    note TEXT
+);
+
+CREATE TABLE cheer_practice_period_classrooms (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    created_at TIMESTAMPTZ DEFAULT now(),
+    practice_period_id UUID NOT NULL REFERENCES cheer_practice_periods (id) ON DELETE CASCADE,
+    classroom_id UUID NOT NULL REFERENCES classrooms (id) ON DELETE CASCADE,
+    UNIQUE (practice_period_id, classroom_id)
+);
+
+CREATE TABLE cheer_practice_staffs (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    created_at TIMESTAMPTZ DEFAULT now(),
+    student_id UUID NOT NULL UNIQUE REFERENCES students (id) ON DELETE CASCADE
+);
+
+CREATE TABLE cheer_practice_teachers (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    created_at TIMESTAMPTZ DEFAULT now(),
+    teacher_id UUID NOT NULL UNIQUE REFERENCES teachers (id) ON DELETE CASCADE
+);
+
+-- Students excused from cheer practice, e.g. for medical reasons
+CREATE TABLE cheer_practice_disabled_students (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    created_at TIMESTAMPTZ DEFAULT now(),
+    student_id UUID NOT NULL UNIQUE REFERENCES students (id) ON DELETE CASCADE,
+    condition TEXT
+);
+
+CREATE TABLE cheer_practice_attendances (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    created_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+    practice_period_id UUID NOT NULL REFERENCES cheer_practice_periods (id) ON DELETE CASCADE,
+    student_id UUID NOT NULL REFERENCES students (id) ON DELETE CASCADE,
+    checker_id UUID REFERENCES users (id) ON DELETE SET NULL,
+    presence cheer_practice_attendance_type,
+    presence_at_end cheer_practice_attendance_type,
+    absence_reason TEXT,
+    UNIQUE (practice_period_id, student_id)
+);
+
+CREATE VIEW cheer_practice_attendances_with_detail_view AS
+SELECT
+    cpa.id,
+    cpa.created_at,
+    cpa.practice_period_id,
+    cpa.student_id,
+    cpa.checker_id,
+    cpa.presence,
+    cpa.presence_at_end,
+    cpa.absence_reason,
+    (cpds.id IS NOT NULL) AS disabled,
+    cpds.condition
+FROM cheer_practice_attendances AS cpa
+LEFT JOIN cheer_practice_disabled_students AS cpds ON cpds.student_id = cpa.student_id;
+
+-- Attendances are created up-front for every student of a classroom assigned to a period so that
+-- staff members only ever update them
+CREATE FUNCTION create_cheer_practice_attendances() RETURNS TRIGGER
+LANGUAGE plpgsql AS $$
+BEGIN
+    INSERT INTO cheer_practice_attendances (practice_period_id, student_id)
+    SELECT NEW.practice_period_id, cs.student_id FROM classroom_students AS cs
+    WHERE cs.classroom_id = NEW.classroom_id
+    ON CONFLICT (practice_period_id, student_id) DO NOTHING;
+
+    RETURN NEW;
+END;
+$$;
+
+CREATE TRIGGER on_cheer_practice_period_classroom_created
+AFTER INSERT ON cheer_practice_period_classrooms
+FOR EACH ROW EXECUTE FUNCTION create_cheer_practice_attendances();
+
+-- Online teaching reports
+
+CREATE TABLE online_teaching_reports (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    created_at TIMESTAMPTZ DEFAULT now(),
+    subject_id UUID REFERENCES subjects (id) ON DELETE SET NULL,
+    teacher_id UUID NOT NULL REFERENCES teachers (id) ON DELETE CASCADE,
+    classroom_id UUID REFERENCES classrooms (id) ON DELETE SET NULL,
+    date DATE NOT NULL,
+    teaching_methods TEXT[] NOT NULL,
+    teaching_topic TEXT NOT NULL,
+    suggestions TEXT,
+    absent_student_no TEXT,
+    start_time BIGINT NOT NULL,
+    duration BIGINT NOT NULL,
+    has_image BOOLEAN NOT NULL DEFAULT FALSE,
+    image_ext TEXT
+);
+
+-- Logging
+
+CREATE SCHEMA api_logging;
+
+CREATE TABLE api_logging.error_logs (
+    id UUID PRIMARY KEY,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+    code BIGINT NOT NULL,
+    error_type TEXT NOT NULL,
+    detail TEXT NOT NULL,
+    source TEXT NOT NULL,
+    api_key_id UUID REFERENCES user_api_keys (id) ON DELETE SET NULL
+);
@@ -0,0 +1,4 @@
+-- Jaturamitr periods used to be hard-coded. `IF NOT EXISTS` keeps this safe on databases where it
+-- was applied by hand.
+ALTER TABLE cheer_practice_periods
+    ADD COLUMN IF NOT EXISTS is_jaturamitr BOOLEAN NOT NULL DEFAULT FALSE;
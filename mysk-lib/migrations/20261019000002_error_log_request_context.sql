@@ -0,0 +1,16 @@
+-- Error logs are recorded with the request they came from. Rows logged before then get a random
+-- request ID and an empty method and path. `IF NOT EXISTS` keeps this safe on databases where it
+-- was applied by hand.
+ALTER TABLE api_logging.error_logs
+    ALTER COLUMN id SET DEFAULT gen_random_uuid(),
+    ADD COLUMN IF NOT EXISTS request_id UUID NOT NULL DEFAULT gen_random_uuid(),
+    ADD COLUMN IF NOT EXISTS method TEXT NOT NULL DEFAULT '',
+    ADD COLUMN IF NOT EXISTS path TEXT NOT NULL DEFAULT '',
+    ADD COLUMN IF NOT EXISTS user_id UUID REFERENCES users (id) ON DELETE SET NULL;
+
+ALTER TABLE api_logging.error_logs
+    ALTER COLUMN request_id DROP DEFAULT,
+    ALTER COLUMN method DROP DEFAULT,
+    ALTER COLUMN path DROP DEFAULT;
+
+CREATE INDEX IF NOT EXISTS error_logs_created_at_idx ON api_logging.error_logs (created_at);
@@ -0,0 +1,16 @@
+-- `IF NOT EXISTS` keeps this safe on databases where it was applied by hand.
+CREATE TABLE IF NOT EXISTS api_logging.audit_logs (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    created_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+    request_id UUID NOT NULL,
+    actor_id UUID REFERENCES users (id) ON DELETE SET NULL,
+    impersonator_id UUID REFERENCES users (id) ON DELETE SET NULL,
+    route TEXT NOT NULL,
+    entity_type TEXT NOT NULL,
+    entity_id UUID NOT NULL,
+    action TEXT NOT NULL,
+    diff JSONB NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS audit_logs_entity_idx
+    ON api_logging.audit_logs (entity_type, entity_id, created_at);
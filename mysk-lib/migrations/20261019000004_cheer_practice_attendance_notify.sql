@@ -0,0 +1,34 @@
+-- Announces every checked attendance on commit, so each instance can stream it to the staff members
+-- watching its practice period. Attendances created up-front have no checker and aren't announced.
+CREATE OR REPLACE FUNCTION notify_cheer_practice_attendance() RETURNS TRIGGER
+LANGUAGE plpgsql AS $$
+BEGIN
+    IF NEW.checker_id IS NULL THEN
+        RETURN NULL;
+    END IF;
+
+    PERFORM pg_notify('cheer_practice_attendances', json_build_object(
+        'id', NEW.id,
+        'practice_period_id', NEW.practice_period_id,
+        'classroom_id', (
+            SELECT cppc.classroom_id FROM cheer_practice_period_classrooms AS cppc
+            JOIN classroom_students AS cs ON cs.classroom_id = cppc.classroom_id
+            WHERE cppc.practice_period_id = NEW.practice_period_id
+                AND cs.student_id = NEW.student_id
+            LIMIT 1
+        ),
+        'student_id', NEW.student_id,
+        'checker_id', NEW.checker_id,
+        'presence', NEW.presence,
+        'presence_at_end', NEW.presence_at_end,
+        'absence_reason', NEW.absence_reason
+    )::TEXT);
+
+    RETURN NULL;
+END;
+$$;
+
+DROP TRIGGER IF EXISTS on_cheer_practice_attendance_checked ON cheer_practice_attendances;
+CREATE TRIGGER on_cheer_practice_attendance_checked
+AFTER INSERT OR UPDATE ON cheer_practice_attendances
+FOR EACH ROW EXECUTE FUNCTION notify_cheer_practice_attendance();
@@ -0,0 +1,47 @@
+CREATE TYPE webhook_event AS ENUM (
+    'elective_enrolled', 'elective_trade_offer_approved', 'club_request_created', 'rsvp_changed'
+);
+
+CREATE TABLE webhook_endpoints (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    created_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+    url TEXT NOT NULL,
+    -- Signs every payload sent to the endpoint, and is only shown when the endpoint is registered
+    secret TEXT NOT NULL,
+    events webhook_event[] NOT NULL,
+    description TEXT,
+    is_active BOOLEAN NOT NULL DEFAULT TRUE
+);
+
+-- One row per event and subscribed endpoint, written in the same transaction as the mutation the
+-- event describes.
+CREATE TABLE webhook_outbox (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    created_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+    endpoint_id UUID NOT NULL REFERENCES webhook_endpoints (id) ON DELETE CASCADE,
+    event webhook_event NOT NULL,
+    payload JSONB NOT NULL,
+    attempts INTEGER NOT NULL DEFAULT 0,
+    next_attempt_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+    delivered_at TIMESTAMPTZ,
+    abandoned_at TIMESTAMPTZ
+);
+
+CREATE INDEX webhook_outbox_pending_idx ON webhook_outbox (next_attempt_at)
+    WHERE delivered_at IS NULL AND abandoned_at IS NULL;
+
+CREATE TABLE api_logging.webhook_deliveries (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    created_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+    outbox_id UUID NOT NULL REFERENCES webhook_outbox (id) ON DELETE CASCADE,
+    endpoint_id UUID NOT NULL REFERENCES webhook_endpoints (id) ON DELETE CASCADE,
+    event webhook_event NOT NULL,
+    attempt INTEGER NOT NULL,
+    -- Missing when the endpoint couldn't be reached
+    status_code INTEGER,
+    error TEXT,
+    duration_ms BIGINT NOT NULL
+);
+
+CREATE INDEX webhook_deliveries_endpoint_idx
+    ON api_logging.webhook_deliveries (endpoint_id, created_at);
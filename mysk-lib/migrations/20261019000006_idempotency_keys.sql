@@ -0,0 +1,13 @@
+-- The first response to each request sent with an `Idempotency-Key`, replayed to its retries.
+CREATE TABLE idempotency_keys (
+    user_id UUID NOT NULL REFERENCES users (id) ON DELETE CASCADE,
+    key TEXT NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+    -- A SHA-256 of the method, path and body, so a key can't be reused for another request
+    request_hash TEXT NOT NULL,
+    -- Missing while the first request is still being handled
+    status_code INTEGER,
+    content_type TEXT,
+    response_body BYTEA,
+    PRIMARY KEY (user_id, key)
+);
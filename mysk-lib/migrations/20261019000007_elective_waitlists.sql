@@ -0,0 +1,14 @@
+-- Students waiting for a seat in a full elective subject session, who are enrolled in the order
+-- they joined as seats open up.
+CREATE TABLE elective_subject_session_waitlisted_students (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    -- The time of the statement rather than the transaction, to keep joins in order
+    created_at TIMESTAMPTZ NOT NULL DEFAULT clock_timestamp(),
+    student_id UUID NOT NULL REFERENCES students (id) ON DELETE CASCADE,
+    elective_subject_session_id UUID NOT NULL
+        REFERENCES elective_subject_sessions (id) ON DELETE CASCADE,
+    UNIQUE (student_id, elective_subject_session_id)
+);
+
+CREATE INDEX elective_subject_session_waitlisted_students_queue_idx
+    ON elective_subject_session_waitlisted_students (elective_subject_session_id, created_at, id);
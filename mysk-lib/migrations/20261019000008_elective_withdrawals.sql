@@ -0,0 +1,14 @@
+-- Students who dropped an elective subject session during the enrollment period. Their enrollment
+-- is deleted, so a withdrawal never counts as having taken the elective before.
+CREATE TABLE elective_subject_session_withdrawn_students (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    created_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+    student_id UUID NOT NULL REFERENCES students (id) ON DELETE CASCADE,
+    elective_subject_session_id UUID NOT NULL
+        REFERENCES elective_subject_sessions (id) ON DELETE CASCADE
+);
+
+CREATE INDEX elective_subject_session_withdrawn_students_student_id_idx
+    ON elective_subject_session_withdrawn_students (student_id);
+
+ALTER TYPE webhook_event ADD VALUE IF NOT EXISTS 'elective_withdrawn' AFTER 'elective_enrolled';
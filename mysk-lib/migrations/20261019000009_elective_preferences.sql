@@ -0,0 +1,12 @@
+-- The electives a student would like to be given, best first, if they are allocated a seat after
+-- not enrolling by themselves.
+CREATE TABLE elective_subject_preferences (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    created_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+    student_id UUID NOT NULL REFERENCES students (id) ON DELETE CASCADE,
+    elective_subject_session_id UUID NOT NULL
+        REFERENCES elective_subject_sessions (id) ON DELETE CASCADE,
+    rank INT NOT NULL CHECK (rank > 0),
+    UNIQUE (student_id, elective_subject_session_id),
+    UNIQUE (student_id, rank)
+);
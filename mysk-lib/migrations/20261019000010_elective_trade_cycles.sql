@@ -0,0 +1,35 @@
+-- The sessions an enrolled student is willing to move to, if others are willing to take their seat.
+CREATE TABLE elective_subject_trade_intents (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    created_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+    student_id UUID NOT NULL REFERENCES students (id) ON DELETE CASCADE,
+    elective_subject_session_id UUID NOT NULL
+        REFERENCES elective_subject_sessions (id) ON DELETE CASCADE,
+    UNIQUE (student_id, elective_subject_session_id)
+);
+
+-- Students who each move to the session of the next, found from their intents. The moves are only
+-- made once every member approves.
+CREATE TABLE elective_subject_trade_cycles (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    created_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+    status submission_status NOT NULL DEFAULT 'pending'
+);
+
+CREATE TABLE elective_subject_trade_cycle_members (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    trade_cycle_id UUID NOT NULL REFERENCES elective_subject_trade_cycles (id) ON DELETE CASCADE,
+    -- The member's place in the cycle, as they move to the session of the next one
+    position INT NOT NULL,
+    student_id UUID NOT NULL REFERENCES students (id) ON DELETE CASCADE,
+    from_elective_subject_session_id UUID NOT NULL
+        REFERENCES elective_subject_sessions (id) ON DELETE CASCADE,
+    to_elective_subject_session_id UUID NOT NULL
+        REFERENCES elective_subject_sessions (id) ON DELETE CASCADE,
+    status submission_status NOT NULL DEFAULT 'pending',
+    UNIQUE (trade_cycle_id, student_id),
+    UNIQUE (trade_cycle_id, position)
+);
+
+CREATE INDEX elective_subject_trade_cycle_members_student_id_idx
+    ON elective_subject_trade_cycle_members (student_id);
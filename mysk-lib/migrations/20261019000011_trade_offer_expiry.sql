@@ -0,0 +1,19 @@
+-- Why a trade offer was declined other than by the receiving student, as `submission_status` is
+-- shared with other tables.
+CREATE TYPE trade_offer_decline_reason AS ENUM ('cancelled', 'expired', 'enrollment_closed');
+
+-- Pending offers are declined once they expire, see `TradeOfferSweeper`. Offers made before this
+-- migration expire 3 days after they were made, as new ones do.
+ALTER TABLE elective_subject_trade_offers
+    ADD COLUMN expires_at TIMESTAMPTZ,
+    ADD COLUMN decline_reason trade_offer_decline_reason;
+
+UPDATE elective_subject_trade_offers
+SET expires_at = coalesce(created_at, now()) + interval '3 days';
+
+ALTER TABLE elective_subject_trade_offers
+    ALTER COLUMN expires_at SET NOT NULL,
+    ALTER COLUMN expires_at SET DEFAULT now() + interval '3 days';
+
+CREATE INDEX elective_subject_trade_offers_pending_expires_at_idx
+    ON elective_subject_trade_offers (expires_at) WHERE status = 'pending';
@@ -0,0 +1,27 @@
+-- Announces every change to whether a practice period is Jaturamitr on commit, so each instance can
+-- keep its cached set of Jaturamitr periods up to date.
+CREATE OR REPLACE FUNCTION notify_jaturamitr_period() RETURNS TRIGGER
+LANGUAGE plpgsql AS $$
+BEGIN
+    IF TG_OP = 'DELETE' THEN
+        PERFORM pg_notify('jaturamitr_periods', json_build_object(
+            'id', OLD.id,
+            'date', OLD.date,
+            'is_jaturamitr', FALSE
+        )::TEXT);
+    ELSE
+        PERFORM pg_notify('jaturamitr_periods', json_build_object(
+            'id', NEW.id,
+            'date', NEW.date,
+            'is_jaturamitr', NEW.is_jaturamitr
+        )::TEXT);
+    END IF;
+
+    RETURN NULL;
+END;
+$$;
+
+DROP TRIGGER IF EXISTS on_jaturamitr_period_changed ON cheer_practice_periods;
+CREATE TRIGGER on_jaturamitr_period_changed
+AFTER INSERT OR UPDATE OF is_jaturamitr, date OR DELETE ON cheer_practice_periods
+FOR EACH ROW EXECUTE FUNCTION notify_jaturamitr_period();
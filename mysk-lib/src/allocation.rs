@@ -0,0 +1,304 @@
+//! Allocation of elective seats to students who didn't enroll by themselves.
+//!
+//! Every student with a classroom this year who hasn't enrolled in an elective, isn't blacklisted
+//! and can take at least one of this semester's sessions is a candidate. The candidates are
+//! shuffled with a seeded RNG, then each one in turn takes the best-ranked of their
+//! [preferences](set_preferences) with an open seat, or else an open seat in a random session they
+//! can take. The same seed over the same enrollments always gives the same allocation.
+
+use crate::{
+    helpers::date::{get_current_academic_year, get_current_semester},
+    models::elective_subject::db::DbElectiveSubject,
+    prelude::*,
+    webhooks::{self, WebhookEvent},
+};
+use rand::{
+    SeedableRng as _, TryRngCore as _,
+    rngs::OsRng,
+    seq::{IndexedRandom as _, SliceRandom as _},
+};
+use rand_chacha::ChaCha8Rng;
+use serde::Serialize;
+use serde_json::json;
+use sqlx::{PgConnection, query};
+use std::collections::{BTreeMap, HashMap};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+/// A seat given to a student by an allocation run.
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct ElectiveAllocation {
+    pub student_id: Uuid,
+    pub elective_subject_session_id: Uuid,
+    /// The rank the student gave the session, if it was one of their preferences.
+    pub preference_rank: Option<i32>,
+}
+
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct AllocationReport {
+    /// Run again with this seed to get the same allocation.
+    pub seed: u32,
+    /// Whether the seats were only planned, rather than given.
+    pub dry_run: bool,
+    pub allocations: Vec<ElectiveAllocation>,
+    /// Candidates left without a seat, as every session they can take is full.
+    pub unallocated_student_ids: Vec<Uuid>,
+}
+
+/// Replaces the student's preferences with the given sessions, best first.
+pub async fn set_preferences(
+    conn: &mut PgConnection,
+    student_id: Uuid,
+    session_ids: &[Uuid],
+) -> Result<()> {
+    query!(
+        "DELETE FROM elective_subject_preferences WHERE student_id = $1",
+        student_id,
+    )
+    .execute(&mut *conn)
+    .await?;
+    query!(
+        "\
+        INSERT INTO elective_subject_preferences \
+        (student_id, elective_subject_session_id, rank) \
+        SELECT $1, session_id, rank::int \
+        FROM unnest($2::uuid[]) WITH ORDINALITY AS p(session_id, rank)\
+        ",
+        student_id,
+        session_ids,
+    )
+    .execute(conn)
+    .await?;
+
+    Ok(())
+}
+
+/// The student's preferences, best first.
+pub async fn get_preferences(conn: &mut PgConnection, student_id: Uuid) -> Result<Vec<Uuid>> {
+    let res = query!(
+        "\
+        SELECT elective_subject_session_id FROM elective_subject_preferences \
+        WHERE student_id = $1 ORDER BY rank\
+        ",
+        student_id,
+    )
+    .fetch_all(conn)
+    .await?;
+
+    Ok(res
+        .into_iter()
+        .map(|r| r.elective_subject_session_id)
+        .collect())
+}
+
+/// Allocates a seat to every candidate, drawing a seed if none is given. Unless it is a dry run,
+/// the seats are given as randomized enrollments, locking every session of this semester until the
+/// end of the transaction.
+pub async fn run(
+    conn: &mut PgConnection,
+    seed: Option<u32>,
+    dry_run: bool,
+) -> Result<AllocationReport> {
+    let seed = match seed {
+        Some(seed) => seed,
+        None => draw_seed()?,
+    };
+    let academic_year = get_current_academic_year(None);
+    let semester = get_current_semester(None);
+
+    let session_ids = query!(
+        "\
+        SELECT id FROM elective_subject_sessions WHERE year = $1 AND semester = $2 ORDER BY id\
+        ",
+        academic_year,
+        semester,
+    )
+    .fetch_all(&mut *conn)
+    .await?
+    .into_iter()
+    .map(|r| r.id)
+    .collect::<Vec<_>>();
+    if !dry_run {
+        DbElectiveSubject::lock_sessions(&mut *conn, &session_ids).await?;
+    }
+
+    let open_seats = query!(
+        r#"
+        SELECT id AS "id!", cap_size AS "cap_size!", class_size AS "class_size!"
+        FROM elective_subject_sessions_with_detail_view WHERE id = ANY($1)
+        "#,
+        &session_ids,
+    )
+    .fetch_all(&mut *conn)
+    .await?
+    .into_iter()
+    .map(|r| (r.id, r.cap_size - r.class_size))
+    .collect::<HashMap<_, _>>();
+
+    // The sessions each candidate can take, following the rules of enrolling by themselves
+    let mut eligible_sessions = BTreeMap::<Uuid, Vec<Uuid>>::new();
+    for row in query!(
+        "\
+        SELECT cs.student_id, essc.elective_subject_session_id \
+        FROM classroom_students AS cs \
+        JOIN classrooms AS c ON c.id = cs.classroom_id \
+        JOIN elective_subject_session_classrooms AS essc ON essc.classroom_id = cs.classroom_id \
+        JOIN elective_subject_sessions AS ess ON ess.id = essc.elective_subject_session_id \
+        WHERE c.year = $1 AND ess.year = $1 AND ess.semester = $2 \
+        AND NOT EXISTS (\
+            SELECT FROM elective_subject_session_blacklisted_students AS bs \
+            WHERE bs.student_id = cs.student_id\
+        ) AND NOT EXISTS (\
+            SELECT FROM elective_subject_session_enrolled_students AS esses \
+            JOIN elective_subject_sessions AS i_ess ON i_ess.id = esses.elective_subject_session_id \
+            WHERE esses.student_id = cs.student_id AND i_ess.year = $1 AND i_ess.semester = $2\
+        ) AND ess.subject_id NOT IN (\
+            SELECT i_ess.subject_id FROM elective_subject_session_enrolled_students AS esses \
+            JOIN elective_subject_sessions AS i_ess ON i_ess.id = esses.elective_subject_session_id \
+            WHERE esses.student_id = cs.student_id \
+            AND (i_ess.year != $1 OR i_ess.semester != $2)\
+        ) \
+        ORDER BY cs.student_id, essc.elective_subject_session_id\
+        ",
+        academic_year,
+        semester,
+    )
+    .fetch_all(&mut *conn)
+    .await?
+    {
+        eligible_sessions
+            .entry(row.student_id)
+            .or_default()
+            .push(row.elective_subject_session_id);
+    }
+
+    let mut preferences = HashMap::<Uuid, Vec<(Uuid, i32)>>::new();
+    for row in query!(
+        "\
+        SELECT student_id, elective_subject_session_id, rank FROM elective_subject_preferences \
+        WHERE student_id = ANY($1) ORDER BY student_id, rank\
+        ",
+        &eligible_sessions.keys().copied().collect::<Vec<_>>(),
+    )
+    .fetch_all(&mut *conn)
+    .await?
+    {
+        preferences
+            .entry(row.student_id)
+            .or_default()
+            .push((row.elective_subject_session_id, row.rank));
+    }
+
+    let (allocations, unallocated_student_ids) =
+        allocate(seed, open_seats, &eligible_sessions, &preferences);
+    if !dry_run {
+        give_seats(conn, &allocations).await?;
+    }
+
+    Ok(AllocationReport {
+        seed,
+        dry_run,
+        allocations,
+        unallocated_student_ids,
+    })
+}
+
+fn draw_seed() -> Result<u32> {
+    OsRng.try_next_u32().map_err(|err| {
+        Error::InternalServerError(
+            format!("Failed to draw an allocation seed: {err}"),
+            "allocation::run".to_string(),
+        )
+    })
+}
+
+fn allocate(
+    seed: u32,
+    mut open_seats: HashMap<Uuid, i64>,
+    eligible_sessions: &BTreeMap<Uuid, Vec<Uuid>>,
+    preferences: &HashMap<Uuid, Vec<(Uuid, i32)>>,
+) -> (Vec<ElectiveAllocation>, Vec<Uuid>) {
+    // Unlike `StdRng`, ChaCha8 gives the same numbers for a seed on every platform and version
+    let mut rng = ChaCha8Rng::seed_from_u64(u64::from(seed));
+    let mut student_ids = eligible_sessions.keys().copied().collect::<Vec<_>>();
+    student_ids.shuffle(&mut rng);
+
+    let mut allocations = Vec::new();
+    let mut unallocated_student_ids = Vec::new();
+    for student_id in student_ids {
+        let eligible = &eligible_sessions[&student_id];
+        let has_seat = |session_id: &Uuid| open_seats.get(session_id).is_some_and(|n| *n > 0);
+
+        let preferred = preferences.get(&student_id).and_then(|preferences| {
+            preferences
+                .iter()
+                .find(|(session_id, _)| eligible.contains(session_id) && has_seat(session_id))
+        });
+        let allocation = if let Some((session_id, rank)) = preferred {
+            Some((*session_id, Some(*rank)))
+        } else {
+            eligible
+                .iter()
+                .filter(|session_id| has_seat(session_id))
+                .copied()
+                .collect::<Vec<_>>()
+                .choose(&mut rng)
+                .map(|session_id| (*session_id, None))
+        };
+
+        match allocation {
+            Some((session_id, preference_rank)) => {
+                *open_seats.get_mut(&session_id).unwrap() -= 1;
+                allocations.push(ElectiveAllocation {
+                    student_id,
+                    elective_subject_session_id: session_id,
+                    preference_rank,
+                });
+            }
+            None => unallocated_student_ids.push(student_id),
+        }
+    }
+
+    (allocations, unallocated_student_ids)
+}
+
+async fn give_seats(conn: &mut PgConnection, allocations: &[ElectiveAllocation]) -> Result<()> {
+    let (student_ids, session_ids): (Vec<_>, Vec<_>) = allocations
+        .iter()
+        .map(|a| (a.student_id, a.elective_subject_session_id))
+        .unzip();
+
+    query!(
+        "\
+        INSERT INTO elective_subject_session_enrolled_students \
+        (student_id, elective_subject_session_id, is_randomized) \
+        SELECT student_id, session_id, true FROM unnest($1::uuid[], $2::uuid[]) \
+        AS a(student_id, session_id)\
+        ",
+        &student_ids,
+        &session_ids,
+    )
+    .execute(&mut *conn)
+    .await?;
+    query!(
+        "DELETE FROM elective_subject_session_waitlisted_students WHERE student_id = ANY($1)",
+        &student_ids,
+    )
+    .execute(&mut *conn)
+    .await?;
+
+    for allocation in allocations {
+        webhooks::enqueue(
+            &mut *conn,
+            WebhookEvent::ElectiveEnrolled,
+            json!({
+                "student_id": allocation.student_id,
+                "elective_subject_session_id": allocation.elective_subject_session_id,
+                "previous_elective_subject_session_id": None::<Uuid>,
+            }),
+        )
+        .await?;
+    }
+
+    Ok(())
+}
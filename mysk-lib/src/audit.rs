@@ -0,0 +1,230 @@
+//! Audit trail of mutations made through the API.
+//!
+//! Routes take an [`AuditSnapshot`] of the rows they are about to change and hand it to
+//! [`AuditContext::record`] once done, inside the same transaction. Every row which changed in
+//! between is written to `api_logging.audit_logs` as a diff of its columns.
+
+use crate::{common::request_id, models::user::User, prelude::*};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value, json};
+use sqlx::{PgConnection, query, query_as};
+use std::{
+    collections::{BTreeSet, HashMap},
+    fmt::{Display, Formatter},
+};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum AuditEntity {
+    /// A person, including their allergies.
+    Person,
+    /// A teacher, including the classrooms they advise.
+    Teacher,
+    Contact,
+    /// The club quotas of a student, keyed by the student ID.
+    StudentClubQuota,
+    /// The elective enrollments of a student, keyed by the student ID.
+    ElectiveEnrollment,
+    ElectiveTradeOffer,
+    CheerPracticeAttendance,
+}
+
+impl AuditEntity {
+    /// Selects `(id, row)` pairs of the entity where the IDs are bound to `$1`.
+    fn snapshot_query(self) -> &'static str {
+        match self {
+            AuditEntity::Person => {
+                "\
+                SELECT p.id, to_jsonb(p) || jsonb_build_object('allergies', COALESCE((\
+                    SELECT jsonb_agg(pa.allergy_name ORDER BY pa.allergy_name) \
+                    FROM person_allergies AS pa WHERE pa.person_id = p.id\
+                ), '[]'::jsonb)) \
+                FROM people AS p WHERE p.id = ANY($1)\
+                "
+            }
+            AuditEntity::Teacher => {
+                "\
+                SELECT t.id, to_jsonb(t) || jsonb_build_object('advisor_classroom_ids', COALESCE((\
+                    SELECT jsonb_agg(ca.classroom_id ORDER BY ca.classroom_id) \
+                    FROM classroom_advisors AS ca WHERE ca.teacher_id = t.id\
+                ), '[]'::jsonb)) \
+                FROM teachers AS t WHERE t.id = ANY($1)\
+                "
+            }
+            AuditEntity::Contact => {
+                "SELECT c.id, to_jsonb(c) FROM contacts AS c WHERE c.id = ANY($1)"
+            }
+            AuditEntity::StudentClubQuota => {
+                "\
+                SELECT student_id, jsonb_object_agg(year::text, max_clubs) \
+                FROM student_club_quotas WHERE student_id = ANY($1) GROUP BY student_id\
+                "
+            }
+            AuditEntity::ElectiveEnrollment => {
+                "\
+                SELECT esses.student_id, jsonb_object_agg(\
+                    concat(ess.year, '/', ess.semester), esses.elective_subject_session_id\
+                ) \
+                FROM elective_subject_session_enrolled_students AS esses \
+                JOIN elective_subject_sessions AS ess \
+                    ON ess.id = esses.elective_subject_session_id \
+                WHERE esses.student_id = ANY($1) GROUP BY esses.student_id\
+                "
+            }
+            AuditEntity::ElectiveTradeOffer => {
+                "\
+                SELECT o.id, to_jsonb(o) FROM elective_subject_trade_offers AS o \
+                WHERE o.id = ANY($1)\
+                "
+            }
+            AuditEntity::CheerPracticeAttendance => {
+                "\
+                SELECT a.id, to_jsonb(a) FROM cheer_practice_attendances AS a \
+                WHERE a.id = ANY($1)\
+                "
+            }
+        }
+    }
+
+    async fn fetch_rows(
+        self,
+        conn: &mut PgConnection,
+        ids: &[Uuid],
+    ) -> Result<HashMap<Uuid, Value>> {
+        let rows = query_as::<_, (Uuid, Value)>(self.snapshot_query())
+            .bind(ids)
+            .fetch_all(conn)
+            .await?;
+
+        Ok(rows.into_iter().collect())
+    }
+}
+
+impl Display for AuditEntity {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AuditEntity::Person => write!(f, "person"),
+            AuditEntity::Teacher => write!(f, "teacher"),
+            AuditEntity::Contact => write!(f, "contact"),
+            AuditEntity::StudentClubQuota => write!(f, "student_club_quota"),
+            AuditEntity::ElectiveEnrollment => write!(f, "elective_enrollment"),
+            AuditEntity::ElectiveTradeOffer => write!(f, "elective_trade_offer"),
+            AuditEntity::CheerPracticeAttendance => write!(f, "cheer_practice_attendance"),
+        }
+    }
+}
+
+/// The state of some rows of an entity before they are changed.
+pub struct AuditSnapshot {
+    entity: AuditEntity,
+    ids: Vec<Uuid>,
+    rows: HashMap<Uuid, Value>,
+}
+
+impl AuditSnapshot {
+    pub async fn take(conn: &mut PgConnection, entity: AuditEntity, ids: &[Uuid]) -> Result<Self> {
+        Ok(Self {
+            entity,
+            ids: ids.to_vec(),
+            rows: entity.fetch_rows(conn, ids).await?,
+        })
+    }
+
+    /// Adds a row whose ID wasn't known when the snapshot was taken, e.g. one that has just been
+    /// inserted.
+    pub fn track(&mut self, id: Uuid) {
+        if !self.ids.contains(&id) {
+            self.ids.push(id);
+        }
+    }
+}
+
+/// Who is making changes and through which route.
+pub struct AuditContext {
+    request_id: Uuid,
+    actor_id: Uuid,
+    impersonator_id: Option<Uuid>,
+    route: &'static str,
+}
+
+impl AuditContext {
+    pub fn new(user: &User, route: &'static str) -> Self {
+        Self {
+            request_id: request_id::current(),
+            actor_id: user.id,
+            impersonator_id: user.impersonator_id,
+            route,
+        }
+    }
+
+    /// Records the changes made to the rows of `snapshot` since it was taken. Rows which did not
+    /// change are skipped.
+    pub async fn record(&self, conn: &mut PgConnection, snapshot: AuditSnapshot) -> Result<()> {
+        let AuditSnapshot {
+            entity,
+            ids,
+            rows: before,
+        } = snapshot;
+        let after = entity.fetch_rows(conn, &ids).await?;
+
+        for id in ids {
+            let (before, after) = (before.get(&id), after.get(&id));
+            let action = match (before, after) {
+                (None, None) => continue,
+                (None, Some(_)) => "create",
+                (Some(_), None) => "delete",
+                (Some(_), Some(_)) => "update",
+            };
+            let diff = diff_columns(before, after);
+            if diff.is_empty() {
+                continue;
+            }
+
+            query!(
+                "\
+                INSERT INTO api_logging.audit_logs (\
+                    request_id, actor_id, impersonator_id, route, entity_type, entity_id, action, \
+                    diff\
+                ) VALUES ($1, $2, $3, $4, $5, $6, $7, $8)\
+                ",
+                self.request_id,
+                self.actor_id,
+                self.impersonator_id,
+                self.route,
+                entity.to_string(),
+                id,
+                action,
+                Value::Object(diff),
+            )
+            .execute(&mut *conn)
+            .await?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Maps every column whose value differs to its old and new values.
+fn diff_columns(before: Option<&Value>, after: Option<&Value>) -> Map<String, Value> {
+    let empty = Map::new();
+    let before = before.and_then(Value::as_object).unwrap_or(&empty);
+    let after = after.and_then(Value::as_object).unwrap_or(&empty);
+
+    before
+        .keys()
+        .chain(after.keys())
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .filter_map(|column| {
+            let (old, new) = (before.get(column), after.get(column));
+            (old != new).then(|| {
+                (
+                    column.clone(),
+                    json!({ "old": old.unwrap_or(&Value::Null), "new": new.unwrap_or(&Value::Null) }),
+                )
+            })
+        })
+        .collect()
+}
@@ -7,7 +7,7 @@ use sqlx::{PgConnection, prelude::FromRow, query};
 use std::fmt::{Display, Formatter};
 use uuid::Uuid;
 
-#[derive(Debug, Serialize, FromRow)]
+#[derive(Clone, Debug, Serialize, FromRow)]
 pub struct ApiKey {
     pub id: Uuid,
     pub created_at: Option<DateTime<Utc>>,
@@ -14,6 +14,9 @@ pub struct TokenClaims {
     pub sub: Uuid,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub mta: Option<Uuid>,
+    /// The admin acting as `sub`, for tokens issued to impersonate another user.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub imp: Option<Uuid>,
     pub iat: usize,
     pub exp: usize,
 }
@@ -111,8 +114,7 @@ pub fn generate_oauth_init_url(client_id: &str, redirect_uri: &str) -> Result<(S
             "https://www.googleapis.com/auth/userinfo.email",
             "https://www.googleapis.com/auth/userinfo.profile",
         ]
-        .join(" ")
-        .to_string(),
+        .join(" "),
         access_type: "online".to_string(),
         state: &state,
         include_granted_scopes: true,
@@ -1,13 +1,75 @@
+use chrono::NaiveDate;
 use futures::TryStreamExt as _;
-use scc::TreeIndex;
-use sqlx::{Error as SqlxError, PgPool, query_scalar};
-use std::sync::Arc;
+use scc::{Guard, HashCache, TreeIndex, hash_cache::Entry};
+use sqlx::{Error as SqlxError, PgPool, query, query_scalar};
+use std::{
+    cell::RefCell,
+    hash::Hash,
+    pin::Pin,
+    sync::{
+        Arc,
+        atomic::{AtomicBool, Ordering},
+    },
+    time::{Duration, Instant},
+};
 use uuid::Uuid;
 
+type Write = Pin<Box<dyn Future<Output = ()> + Send>>;
+
+tokio::task_local! {
+    /// The cache writes held back until the transaction they depend on commits.
+    static HELD_WRITES: RefCell<Vec<Write>>;
+}
+
+/// Writes to the caches held back by [`hold_writes`].
+#[must_use = "held writes are discarded unless applied"]
+pub struct HeldWrites(Vec<Write>);
+
+impl HeldWrites {
+    /// Applies the writes, once the transaction they depend on has committed.
+    pub async fn apply(self) {
+        for write in self.0 {
+            write.await;
+        }
+    }
+}
+
+/// Runs `future` as part of a transaction which may yet be rolled back. Relation caches are
+/// bypassed, so that rows which are never committed aren't cached, and writes to the caches are
+/// returned instead of applied, to be applied once the transaction commits.
+pub async fn hold_writes<F: Future>(future: F) -> (F::Output, HeldWrites) {
+    HELD_WRITES
+        .scope(RefCell::new(Vec::new()), async {
+            let output = future.await;
+            let writes = HELD_WRITES.with(RefCell::take);
+
+            (output, HeldWrites(writes))
+        })
+        .await
+}
+
+/// Whether the caches are bypassed, see [`hold_writes`].
+fn is_bypassed() -> bool {
+    HELD_WRITES.try_with(|_| ()).is_ok()
+}
+
+/// Applies `write` to a cache, or holds it back if run in [`hold_writes`].
+async fn write(write: impl Future<Output = ()> + Send + 'static) {
+    let mut write = Some(Box::pin(write) as Write);
+    let _ = HELD_WRITES.try_with(|held| held.borrow_mut().extend(write.take()));
+    if let Some(write) = write {
+        write.await;
+    }
+}
+
 /// The shared **global** cache of the application.
 pub struct GlobalCache {
     cheer_staff_members: TreeIndex<Uuid, ()>,
     cheer_staff_teachers: TreeIndex<Uuid, ()>,
+    /// Jaturamitr cheer practice periods mapped to their dates.
+    jaturamitr_periods: TreeIndex<Uuid, NaiveDate>,
+    /// Whether [`GlobalCache::populate_cache`] has finished.
+    populated: AtomicBool,
 }
 
 impl GlobalCache {
@@ -15,6 +77,8 @@ impl GlobalCache {
         Arc::new(Self {
             cheer_staff_members: TreeIndex::new(),
             cheer_staff_teachers: TreeIndex::new(),
+            jaturamitr_periods: TreeIndex::new(),
+            populated: AtomicBool::new(false),
         })
     }
 
@@ -43,9 +107,17 @@ impl GlobalCache {
             })
             .await?;
 
+        self.reload_jaturamitr_periods(pool).await?;
+
+        self.populated.store(true, Ordering::Release);
+
         Ok(self)
     }
 
+    pub fn is_populated(&self) -> bool {
+        self.populated.load(Ordering::Acquire)
+    }
+
     pub fn contains_cheer_staff(&self, student_id: Uuid) -> bool {
         self.cheer_staff_members.contains(&student_id)
     }
@@ -53,4 +125,137 @@ impl GlobalCache {
     pub fn contains_cheer_teacher(&self, teacher_id: Uuid) -> bool {
         self.cheer_staff_teachers.contains(&teacher_id)
     }
+
+    pub fn contains_jaturamitr_period(&self, practice_period_id: Uuid) -> bool {
+        self.jaturamitr_periods.contains(&practice_period_id)
+    }
+
+    pub fn contains_jaturamitr_date(&self, date: NaiveDate) -> bool {
+        self.jaturamitr_periods
+            .iter(&Guard::new())
+            .any(|(_, period_date)| *period_date == date)
+    }
+
+    pub async fn insert_jaturamitr_period(
+        self: &Arc<Self>,
+        practice_period_id: Uuid,
+        date: NaiveDate,
+    ) {
+        let cache = Arc::clone(self);
+        write(async move {
+            cache
+                .jaturamitr_periods
+                .upsert_async(practice_period_id, date)
+                .await;
+        })
+        .await;
+    }
+
+    pub async fn remove_jaturamitr_period(self: &Arc<Self>, practice_period_id: Uuid) {
+        let cache = Arc::clone(self);
+        write(async move {
+            cache
+                .jaturamitr_periods
+                .remove_async(&practice_period_id)
+                .await;
+        })
+        .await;
+    }
+
+    /// Replaces the cached Jaturamitr periods with those in the database, for when changes made by
+    /// other instances may have been missed.
+    pub async fn reload_jaturamitr_periods(&self, pool: &PgPool) -> Result<(), SqlxError> {
+        let periods =
+            query!("SELECT id, date FROM cheer_practice_periods WHERE is_jaturamitr = TRUE")
+                .fetch_all(pool)
+                .await?;
+
+        let stale_ids = self
+            .jaturamitr_periods
+            .iter(&Guard::new())
+            .filter(|(id, _)| !periods.iter().any(|period| period.id == **id))
+            .map(|(id, _)| *id)
+            .collect::<Vec<_>>();
+        for id in stale_ids {
+            self.jaturamitr_periods.remove_async(&id).await;
+        }
+        for period in periods {
+            self.jaturamitr_periods
+                .upsert_async(period.id, period.date)
+                .await;
+        }
+
+        Ok(())
+    }
+}
+
+/// A bounded read-through cache for rows of a relation keyed by their ID. Entries older than the
+/// configured TTL are treated as missing, and the least recently used entries are evicted once the
+/// cache is full. It is bypassed within [`hold_writes`], where evictions are held back.
+///
+/// Relations opt into this cache through `#[from_query(cache = <ttl in seconds>)]` on the
+/// [`GetById`](crate::models::traits::GetById) derive.
+pub struct RelationCache<K, V> {
+    entries: HashCache<K, (Instant, V)>,
+    ttl: Duration,
+}
+
+impl<K, V> RelationCache<K, V>
+where
+    K: Clone + Eq + Hash + Send + Sync + 'static,
+    V: Clone + Send + Sync + 'static,
+{
+    pub fn new(capacity: usize, ttl: Duration) -> Self {
+        Self {
+            entries: HashCache::with_capacity(0, capacity),
+            ttl,
+        }
+    }
+
+    /// Gets a clone of the cached row, removing it if it has expired.
+    pub async fn get(&self, key: &K) -> Option<V> {
+        if is_bypassed() {
+            return None;
+        }
+
+        let (inserted_at, value) = self
+            .entries
+            .read_async(key, |_, (inserted_at, value)| (*inserted_at, value.clone()))
+            .await?;
+
+        if inserted_at.elapsed() < self.ttl {
+            return Some(value);
+        }
+
+        self.entries
+            .remove_if_async(key, |(current, _)| current.elapsed() >= self.ttl)
+            .await;
+
+        None
+    }
+
+    /// Inserts or replaces the cached row.
+    pub async fn insert(&self, key: K, value: V) {
+        if is_bypassed() {
+            return;
+        }
+
+        match self.entries.entry_async(key).await {
+            Entry::Occupied(mut entry) => {
+                entry.put((Instant::now(), value));
+            }
+            Entry::Vacant(entry) => {
+                entry.put_entry((Instant::now(), value));
+            }
+        }
+    }
+
+    /// Removes the cached row, if any.
+    pub async fn evict(&'static self, key: &K) {
+        let key = key.clone();
+        write(async move {
+            self.entries.remove_async(&key).await;
+        })
+        .await;
+    }
 }
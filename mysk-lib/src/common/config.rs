@@ -1,10 +1,24 @@
-use anyhow::{Context as _, Result as AnyhowResult};
-use std::env::var;
-use std::net::IpAddr;
+use anyhow::{Context as _, Result as AnyhowResult, bail, ensure};
+use reqwest::Url;
+use serde::Deserialize;
+use sqlx::postgres::{PgConnectOptions, PgSslMode};
+use std::{
+    env::{VarError, var},
+    fmt::Display,
+    fs,
+    net::IpAddr,
+    path::Path,
+    str::FromStr,
+};
+
+/// The configuration file read when `MYSK_CONFIG` isn't set. It is optional.
+const DEFAULT_CONFIG_PATH: &str = "mysk.toml";
 
 #[derive(Debug, Clone)]
 pub struct Config {
     pub database_url: String,
+    pub database_max_connections: u32,
+    pub database_ssl_mode: PgSslMode,
     pub google_oauth_client_id: String,
     pub google_oauth_client_secret: String,
     pub host: IpAddr,
@@ -14,35 +28,191 @@ pub struct Config {
     pub supabase_uri: String,
     pub token_max_age: u64,
     pub token_secret: String,
+    /// Origins allowed to make cross-origin requests, e.g. `https://mysk.school`.
+    pub cors_allowed_origins: Vec<String>,
+    /// The `tracing` filter directives, e.g. `mysk_data_api=info,sqlx=warn`.
+    pub log_filter: String,
+    /// The maximum size of a JSON request body in bytes.
+    pub json_limit: usize,
+    /// How long in-flight requests are given to finish when shutting down, in seconds.
+    pub shutdown_timeout: u64,
+}
+
+/// The parts of [`Config`] which are safe to change while the server is running.
+#[derive(Debug, Clone)]
+pub struct ReloadableConfig {
+    pub cors_allowed_origins: Vec<String>,
+    pub log_filter: String,
+}
+
+/// The settings of the configuration file. Every key is named after its field in [`Config`] and
+/// can be overridden by the environment variable of the same name in upper case.
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+struct ConfigFile {
+    database_url: Option<String>,
+    database_max_connections: Option<u32>,
+    database_ssl_mode: Option<String>,
+    google_oauth_client_id: Option<String>,
+    google_oauth_client_secret: Option<String>,
+    host: Option<IpAddr>,
+    port: Option<u16>,
+    root_uri: Option<String>,
+    supabase_secret_key: Option<String>,
+    supabase_uri: Option<String>,
+    token_max_age: Option<u64>,
+    token_secret: Option<String>,
+    cors_allowed_origins: Option<Vec<String>>,
+    log_filter: Option<String>,
+    json_limit: Option<usize>,
+    shutdown_timeout: Option<u64>,
 }
 
 impl Config {
-    pub fn try_from_env() -> AnyhowResult<Config> {
-        let database_url = var("DATABASE_URL").context("DATABASE_URL must be set")?;
+    /// Loads the configuration from the TOML file at `MYSK_CONFIG` (or `mysk.toml` if it exists),
+    /// overrides it with environment variables, then validates it.
+    pub fn load() -> AnyhowResult<Config> {
+        let mut file = match var("MYSK_CONFIG") {
+            Ok(path) => ConfigFile::read(Path::new(&path))?,
+            Err(VarError::NotPresent) if Path::new(DEFAULT_CONFIG_PATH).exists() => {
+                ConfigFile::read(Path::new(DEFAULT_CONFIG_PATH))?
+            }
+            Err(VarError::NotPresent) => ConfigFile::default(),
+            Err(VarError::NotUnicode(_)) => bail!("MYSK_CONFIG must be valid unicode"),
+        };
+        file.override_from_env()?;
+
+        Config::try_from(file)
+    }
+
+    pub fn reloadable(&self) -> ReloadableConfig {
+        ReloadableConfig {
+            cors_allowed_origins: self.cors_allowed_origins.clone(),
+            log_filter: self.log_filter.clone(),
+        }
+    }
+
+    /// The default `tracing` filter directives, more verbose on debug builds.
+    fn default_log_filter() -> String {
+        if cfg!(debug_assertions) {
+            "mysk_data_api=trace,mysk_lib=trace,actix_web=info,sqlx=trace".to_string()
+        } else {
+            "mysk_data_api=info,mysk_lib=info,actix_web=warn,sqlx=warn".to_string()
+        }
+    }
+}
+
+impl ConfigFile {
+    fn read(path: &Path) -> AnyhowResult<ConfigFile> {
+        let contents = fs::read_to_string(path)
+            .with_context(|| format!("Failed to read the config file {}", path.display()))?;
+
+        toml::from_str(&contents)
+            .with_context(|| format!("Failed to parse the config file {}", path.display()))
+    }
+
+    fn override_from_env(&mut self) -> AnyhowResult<()> {
+        override_from_env("DATABASE_URL", &mut self.database_url)?;
+        override_from_env(
+            "DATABASE_MAX_CONNECTIONS",
+            &mut self.database_max_connections,
+        )?;
+        override_from_env("DATABASE_SSL_MODE", &mut self.database_ssl_mode)?;
+        override_from_env("GOOGLE_OAUTH_CLIENT_ID", &mut self.google_oauth_client_id)?;
+        override_from_env(
+            "GOOGLE_OAUTH_CLIENT_SECRET",
+            &mut self.google_oauth_client_secret,
+        )?;
+        override_from_env("HOST", &mut self.host)?;
+        override_from_env("PORT", &mut self.port)?;
+        override_from_env("ROOT_URI", &mut self.root_uri)?;
+        override_from_env("SUPABASE_SECRET_KEY", &mut self.supabase_secret_key)?;
+        override_from_env("SUPABASE_URI", &mut self.supabase_uri)?;
+        // `TOKEN_MAXAGE` predates the naming scheme of the config file
+        override_from_env("TOKEN_MAXAGE", &mut self.token_max_age)?;
+        override_from_env("TOKEN_SECRET", &mut self.token_secret)?;
+        override_from_env("LOG_FILTER", &mut self.log_filter)?;
+        override_from_env("JSON_LIMIT", &mut self.json_limit)?;
+        override_from_env("SHUTDOWN_TIMEOUT", &mut self.shutdown_timeout)?;
+
+        // A comma-separated list, e.g. `https://mysk.school,http://localhost:3000`
+        let mut cors_allowed_origins = None::<String>;
+        override_from_env("CORS_ALLOWED_ORIGINS", &mut cors_allowed_origins)?;
+        if let Some(origins) = cors_allowed_origins {
+            self.cors_allowed_origins = Some(
+                origins
+                    .split(',')
+                    .map(str::trim)
+                    .filter(|origin| !origin.is_empty())
+                    .map(ToString::to_string)
+                    .collect(),
+            );
+        }
+
+        Ok(())
+    }
+}
+
+impl TryFrom<ConfigFile> for Config {
+    type Error = anyhow::Error;
+
+    fn try_from(file: ConfigFile) -> AnyhowResult<Config> {
+        let database_url = required("DATABASE_URL", file.database_url)?;
+        database_url
+            .parse::<PgConnectOptions>()
+            .context("DATABASE_URL must be a valid PostgreSQL connection string")?;
+        let database_max_connections = file.database_max_connections.unwrap_or(16);
+        ensure!(
+            database_max_connections > 0,
+            "DATABASE_MAX_CONNECTIONS must be a positive integer",
+        );
+        let database_ssl_mode = file
+            .database_ssl_mode
+            .as_deref()
+            .map_or(Ok(PgSslMode::Require), PgSslMode::from_str)
+            .context(
+                "DATABASE_SSL_MODE must be one of `disable`, `allow`, `prefer`, `require`, \
+                `verify-ca` or `verify-full`",
+            )?;
         let google_oauth_client_id =
-            var("GOOGLE_OAUTH_CLIENT_ID").context("GOOGLE_OAUTH_CLIENT_ID must be set")?;
-        let google_oauth_client_secret =
-            var("GOOGLE_OAUTH_CLIENT_SECRET").context("GOOGLE_OAUTH_CLIENT_SECRET must be set")?;
-        let host = var("HOST")
-            .unwrap_or("0.0.0.0".into())
-            .parse()
-            .context("HOST must be a valid IP address")?;
-        let port = var("PORT")
-            .unwrap_or("8000".into())
-            .parse()
-            .context("PORT must be a valid port number")?;
-        let root_uri = var("ROOT_URI").context("ROOT_URI must be set")?;
-        let supabase_secret_key =
-            var("SUPABASE_SECRET_KEY").context("SUPABASE_SECRET_KEY must be set")?;
-        let supabase_uri = var("SUPABASE_URI").context("SUPABASE_URI must be set")?;
-        let token_max_age = var("TOKEN_MAXAGE")
-            .context("TOKEN_MAXAGE must be set")?
-            .parse()
-            .context("TOKEN_MAXAGE must be a positive integer")?;
-        let token_secret = var("TOKEN_SECRET").context("TOKEN_SECRET must be set")?;
+            required("GOOGLE_OAUTH_CLIENT_ID", file.google_oauth_client_id)?;
+        let google_oauth_client_secret = required(
+            "GOOGLE_OAUTH_CLIENT_SECRET",
+            file.google_oauth_client_secret,
+        )?;
+        let host = file.host.unwrap_or(IpAddr::from([0, 0, 0, 0]));
+        let port = file.port.unwrap_or(8000);
+        let root_uri = required("ROOT_URI", file.root_uri)?;
+        Url::parse(&root_uri).context("ROOT_URI must be a valid URL")?;
+        let supabase_secret_key = required("SUPABASE_SECRET_KEY", file.supabase_secret_key)?;
+        let supabase_uri = required("SUPABASE_URI", file.supabase_uri)?;
+        Url::parse(&supabase_uri).context("SUPABASE_URI must be a valid URL")?;
+        let token_max_age = file.token_max_age.context("TOKEN_MAXAGE must be set")?;
+        ensure!(token_max_age > 0, "TOKEN_MAXAGE must be a positive integer");
+        let token_secret = required("TOKEN_SECRET", file.token_secret)?;
+        let cors_allowed_origins = file.cors_allowed_origins.unwrap_or_else(|| {
+            vec![
+                "http://localhost:3000".to_string(),
+                "http://localhost:8000".to_string(),
+                "https://mysk.school".to_string(),
+            ]
+        });
+        for origin in &cors_allowed_origins {
+            ensure!(
+                is_origin(origin),
+                "CORS_ALLOWED_ORIGINS must only contain origins (`scheme://host[:port]`), got \
+                `{origin}`",
+            );
+        }
+        let log_filter = file.log_filter.unwrap_or_else(Config::default_log_filter);
+        let json_limit = file.json_limit.unwrap_or(2 * 1024 * 1024); // 2 MiB
+        ensure!(json_limit > 0, "JSON_LIMIT must be a positive integer");
+        let shutdown_timeout = file.shutdown_timeout.unwrap_or(30);
 
         Ok(Config {
             database_url,
+            database_max_connections,
+            database_ssl_mode,
             google_oauth_client_id,
             google_oauth_client_secret,
             host,
@@ -52,6 +222,45 @@ impl Config {
             supabase_uri,
             token_max_age,
             token_secret,
+            cors_allowed_origins,
+            log_filter,
+            json_limit,
+            shutdown_timeout,
         })
     }
 }
+
+/// Replaces `value` with the environment variable `name` if it is set and not empty.
+fn override_from_env<T>(name: &str, value: &mut Option<T>) -> AnyhowResult<()>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    match var(name) {
+        Ok(raw) if raw.is_empty() => Ok(()),
+        Ok(raw) => {
+            *value = Some(
+                raw.parse()
+                    .map_err(|err| anyhow::anyhow!("{name} is invalid: {err}"))?,
+            );
+
+            Ok(())
+        }
+        Err(VarError::NotPresent) => Ok(()),
+        Err(VarError::NotUnicode(_)) => bail!("{name} must be valid unicode"),
+    }
+}
+
+fn required(name: &str, value: Option<String>) -> AnyhowResult<String> {
+    match value {
+        Some(value) if !value.is_empty() => Ok(value),
+        _ => bail!("{name} must be set"),
+    }
+}
+
+/// Whether `origin` is exactly a serialized origin, i.e. without a path, query or trailing slash.
+fn is_origin(origin: &str) -> bool {
+    Url::parse(origin).is_ok_and(|url| {
+        matches!(url.scheme(), "http" | "https") && url.origin().ascii_serialization() == origin
+    })
+}
@@ -0,0 +1,229 @@
+//! Thai translations of the error details shown to students and teachers.
+
+/// Pairs of English details and their Thai translations. A `{}` in a detail stands for a part
+/// which varies, and is carried over to the translation.
+const THAI: &[(&str, &str)] = &[
+    // Authentication and permissions
+    ("Missing authorization token", "ไม่พบโทเคนยืนยันตัวตน"),
+    ("Invalid authorization token", "โทเคนยืนยันตัวตนไม่ถูกต้อง"),
+    ("Invalid authorization scheme", "รูปแบบการยืนยันตัวตนไม่ถูกต้อง"),
+    ("User is not a student", "ผู้ใช้ไม่ใช่นักเรียน"),
+    ("User is not a teacher", "ผู้ใช้ไม่ใช่ครู"),
+    ("User is not an admin", "ผู้ใช้ไม่ใช่ผู้ดูแลระบบ"),
+    (
+        "Insufficient permissions to perform this action",
+        "ไม่มีสิทธิ์ดำเนินการนี้",
+    ),
+    ("Internal server error", "เกิดข้อผิดพลาดภายในเซิร์ฟเวอร์"),
+    // Requests
+    (
+        "Page number must be greater than zero",
+        "หมายเลขหน้าต้องมากกว่าศูนย์",
+    ),
+    ("Page number is out of bounds", "หมายเลขหน้าเกินจำนวนหน้าที่มี"),
+    (
+        "Status must be either `approved` or `declined`",
+        "สถานะต้องเป็น `approved` หรือ `declined` เท่านั้น",
+    ),
+    ("Some fields are invalid: {}", "ข้อมูลบางช่องไม่ถูกต้อง: {}"),
+    (
+        "Idempotency-Key has already been used for a different request",
+        "Idempotency-Key นี้ถูกใช้กับคำขออื่นไปแล้ว",
+    ),
+    (
+        "A request with this Idempotency-Key is still being handled",
+        "คำขอที่ใช้ Idempotency-Key นี้กำลังดำเนินการอยู่",
+    ),
+    // Entities
+    ("Entity not found", "ไม่พบข้อมูล"),
+    ("Classroom not found", "ไม่พบห้องเรียน"),
+    ("Subject not found", "ไม่พบวิชา"),
+    ("Practice period not found", "ไม่พบคาบซ้อมเชียร์"),
+    ("Invitee student not found", "ไม่พบนักเรียนที่ได้รับเชิญ"),
+    ("Student has no classroom", "นักเรียนยังไม่มีห้องเรียน"),
+    (
+        "Contact with the same value already exists",
+        "มีช่องทางติดต่อนี้อยู่แล้ว",
+    ),
+    // Electives
+    (
+        "Enrollment changed while it was being modified",
+        "การลงทะเบียนเปลี่ยนไประหว่างที่กำลังแก้ไข",
+    ),
+    (
+        "The elective enrollment period has ended",
+        "หมดช่วงเวลาลงทะเบียนวิชาเลือกแล้ว",
+    ),
+    (
+        "Student is blacklisted from enrolling in electives",
+        "นักเรียนถูกระงับสิทธิ์การลงทะเบียนวิชาเลือก",
+    ),
+    (
+        "Student has already enrolled in an elective this semester",
+        "นักเรียนลงทะเบียนวิชาเลือกในภาคเรียนนี้แล้ว",
+    ),
+    (
+        "Student has not enrolled in an elective this semester",
+        "นักเรียนยังไม่ได้ลงทะเบียนวิชาเลือกในภาคเรียนนี้",
+    ),
+    (
+        "Student is enrolled in another elective",
+        "นักเรียนลงทะเบียนวิชาเลือกอื่นอยู่",
+    ),
+    (
+        "Student cannot re-enroll in the same elective",
+        "นักเรียนไม่สามารถลงทะเบียนวิชาเลือกเดิมซ้ำได้",
+    ),
+    (
+        "Student cannot enroll in a non-current elective",
+        "นักเรียนไม่สามารถลงทะเบียนวิชาเลือกที่ไม่ได้เปิดในภาคเรียนนี้ได้",
+    ),
+    ("The elective is already full", "วิชาเลือกนี้เต็มแล้ว"),
+    (
+        "Student is not eligible to enroll in this elective",
+        "นักเรียนไม่มีสิทธิ์ลงทะเบียนวิชาเลือกนี้",
+    ),
+    ("The elective still has open seats", "วิชาเลือกนี้ยังมีที่ว่าง"),
+    (
+        "Student is already on the waitlist of this elective",
+        "นักเรียนอยู่ในรายชื่อรอของวิชาเลือกนี้แล้ว",
+    ),
+    (
+        "Student is not on the waitlist of this elective",
+        "นักเรียนไม่ได้อยู่ในรายชื่อรอของวิชาเลือกนี้",
+    ),
+    // Elective trade offers
+    (
+        "Receiving student has not enrolled in an elective this semester",
+        "นักเรียนผู้รับยังไม่ได้ลงทะเบียนวิชาเลือกในภาคเรียนนี้",
+    ),
+    (
+        "Receiving student is not eligible to enroll in this elective",
+        "นักเรียนผู้รับไม่มีสิทธิ์ลงทะเบียนวิชาเลือกนี้",
+    ),
+    (
+        "Both the sender and receiver has the same elective subjects",
+        "ผู้ส่งและผู้รับลงทะเบียนวิชาเลือกเดียวกัน",
+    ),
+    (
+        "Trade offer with the receiving student already exists",
+        "มีข้อเสนอแลกเปลี่ยนกับนักเรียนผู้รับอยู่แล้ว",
+    ),
+    (
+        "Student has reached the maximum number of pending trade offers",
+        "นักเรียนมีข้อเสนอแลกเปลี่ยนที่รอการตอบรับครบจำนวนสูงสุดแล้ว",
+    ),
+    (
+        "Receiving student has reached the maximum number of pending trade offers",
+        "นักเรียนผู้รับมีข้อเสนอแลกเปลี่ยนที่รอการตอบรับครบจำนวนสูงสุดแล้ว",
+    ),
+    (
+        "Student is not allowed to approve own trade offer",
+        "นักเรียนไม่สามารถอนุมัติข้อเสนอแลกเปลี่ยนของตนเองได้",
+    ),
+    (
+        "Trade offer has already been {}",
+        "ข้อเสนอแลกเปลี่ยนนี้ได้รับการตอบกลับแล้ว ({})",
+    ),
+    ("Trade offer has expired", "ข้อเสนอแลกเปลี่ยนนี้หมดอายุแล้ว"),
+    (
+        "Only the sending student can cancel a trade offer",
+        "เฉพาะนักเรียนผู้ส่งเท่านั้นที่สามารถยกเลิกข้อเสนอแลกเปลี่ยนได้",
+    ),
+    // Elective trade cycles
+    (
+        "Student is already enrolled in this elective",
+        "นักเรียนลงทะเบียนวิชาเลือกนี้อยู่แล้ว",
+    ),
+    (
+        "Trade cycle has already been approved or declined",
+        "วงการแลกเปลี่ยนนี้ได้รับการตอบกลับแล้ว",
+    ),
+    (
+        "The enrollments of the trade cycle have changed since it was found",
+        "การลงทะเบียนของนักเรียนในวงการแลกเปลี่ยนนี้เปลี่ยนไปแล้ว",
+    ),
+    // Clubs
+    (
+        "Student is already a staff member of the club",
+        "นักเรียนเป็นกรรมการชมรมนี้อยู่แล้ว",
+    ),
+    (
+        "Student is already a member of the club",
+        "นักเรียนเป็นสมาชิกชมรมนี้อยู่แล้ว",
+    ),
+    (
+        "Student has reached the maximum number of clubs allowed",
+        "นักเรียนเข้าร่วมชมรมครบจำนวนสูงสุดแล้ว",
+    ),
+    (
+        "Student must be a staff of the club to add club members",
+        "นักเรียนต้องเป็นกรรมการชมรมจึงจะเพิ่มสมาชิกได้",
+    ),
+    (
+        "Invitee student is already a staff member of the club",
+        "นักเรียนที่ได้รับเชิญเป็นกรรมการชมรมนี้อยู่แล้ว",
+    ),
+    (
+        "Student is not allowed to interact with this club request",
+        "นักเรียนไม่มีสิทธิ์จัดการคำขอเข้าชมรมนี้",
+    ),
+    // Certificates
+    (
+        "The certificate ceremony RSVP period has ended",
+        "หมดช่วงเวลาตอบรับเข้าร่วมพิธีมอบเกียรติบัตรแล้ว",
+    ),
+    // Cheer practice attendance
+    (
+        "Student must be a staff member to update attendances",
+        "นักเรียนต้องเป็นสตาฟจึงจะแก้ไขการเช็กชื่อได้",
+    ),
+    (
+        "Cheer staff cannot take their own cheer practice attendance",
+        "สตาฟเชียร์ไม่สามารถเช็กชื่อการซ้อมเชียร์ของตนเองได้",
+    ),
+    (
+        "Teacher is not allowed to take attendance on this period",
+        "ครูไม่มีสิทธิ์เช็กชื่อในคาบนี้",
+    ),
+    (
+        "Requested classroom is not a part of the current cheer practice period",
+        "ห้องเรียนนี้ไม่ได้อยู่ในคาบซ้อมเชียร์ปัจจุบัน",
+    ),
+    (
+        "Invalid presence type for the current attendance-taking phase",
+        "ประเภทการเข้าร่วมไม่ถูกต้องสำหรับช่วงการเช็กชื่อปัจจุบัน",
+    ),
+    (
+        "Absence reason was specified for a presence type that forbids a reason",
+        "ไม่สามารถระบุเหตุผลการขาดสำหรับประเภทการเข้าร่วมนี้ได้",
+    ),
+    (
+        "Only staff members can watch attendances being taken",
+        "เฉพาะสตาฟเท่านั้นที่ดูการเช็กชื่อได้",
+    ),
+    // Teaching reports
+    ("Class report not found", "ไม่พบบันทึกการสอน"),
+    (
+        "Class report is incomplete (missing image)",
+        "บันทึกการสอนยังไม่สมบูรณ์ (ไม่มีรูปภาพ)",
+    ),
+    ("Class report already has an image", "บันทึกการสอนมีรูปภาพอยู่แล้ว"),
+    ("Invalid file extension provided", "นามสกุลไฟล์ไม่ถูกต้อง"),
+    (
+        "At least one teaching method must be provided",
+        "ต้องระบุวิธีการสอนอย่างน้อยหนึ่งวิธี",
+    ),
+];
+
+/// Translates an English error detail to Thai, if it is in the catalogue.
+pub(super) fn to_thai(detail: &str) -> Option<String> {
+    THAI.iter().find_map(|(english, thai)| {
+        let Some((prefix, suffix)) = english.split_once("{}") else {
+            return (*english == detail).then(|| (*thai).to_string());
+        };
+        let varying = detail.strip_prefix(prefix)?.strip_suffix(suffix)?;
+
+        Some(thai.replacen("{}", varying, 1))
+    })
+}
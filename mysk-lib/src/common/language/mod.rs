@@ -0,0 +1,80 @@
+//! The language a response is localized to, if the client asked for one.
+//!
+//! While a language is set, [`MultiLangString`](crate::common::string::MultiLangString) and
+//! [`FlexibleMultiLangString`](crate::common::string::FlexibleMultiLangString) serialize to a
+//! single string in that language, falling back to the other one, and error details are translated
+//! through the [`catalogue`]. Without one, responses keep every language as before.
+
+use std::{future::Future, str::FromStr};
+
+mod catalogue;
+
+tokio::task_local! {
+    static LANGUAGE: Language;
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Language {
+    English,
+    Thai,
+}
+
+impl FromStr for Language {
+    type Err = ();
+
+    /// Parses a language tag such as `th`, `th-TH`, `en` or `en-US`.
+    fn from_str(tag: &str) -> Result<Self, Self::Err> {
+        let primary = tag.split(['-', '_']).next().unwrap_or_default();
+
+        if primary.eq_ignore_ascii_case("th") {
+            Ok(Language::Thai)
+        } else if primary.eq_ignore_ascii_case("en") {
+            Ok(Language::English)
+        } else {
+            Err(())
+        }
+    }
+}
+
+impl Language {
+    /// Picks the supported language the client prefers the most from an `Accept-Language` header,
+    /// ignoring the ones with a quality of zero.
+    pub fn negotiate(accept_language: &str) -> Option<Self> {
+        accept_language
+            .split(',')
+            .enumerate()
+            .filter_map(|(position, range)| {
+                let mut params = range.split(';');
+                let language = params.next()?.trim().parse::<Language>().ok()?;
+                let quality = params
+                    .find_map(|param| param.trim().strip_prefix("q="))
+                    .map_or(Some(1.0), |quality| quality.trim().parse::<f32>().ok())?;
+
+                (quality > 0.0).then_some((language, quality, position))
+            })
+            // The first of the languages with the highest quality
+            .max_by(|(_, a, a_position), (_, b, b_position)| {
+                a.total_cmp(b).then(b_position.cmp(a_position))
+            })
+            .map(|(language, _, _)| language)
+    }
+}
+
+/// Runs `future` with responses localized to `language`.
+pub async fn scope<F: Future>(language: Language, future: F) -> F::Output {
+    LANGUAGE.scope(language, future).await
+}
+
+/// Gets the language the response being built is localized to, if any.
+pub fn current() -> Option<Language> {
+    LANGUAGE.try_with(|language| *language).ok()
+}
+
+/// Translates an error detail to the current language. Details missing from the catalogue are kept
+/// in English.
+pub fn translate_detail(detail: &str) -> String {
+    match current() {
+        Some(Language::Thai) => catalogue::to_thai(detail).unwrap_or_else(|| detail.to_string()),
+        Some(Language::English) | None => detail.to_string(),
+    }
+}
@@ -1,7 +1,10 @@
 pub mod config;
+pub mod language;
 pub mod pagination;
+pub mod request_id;
 pub mod requests;
 pub mod response;
 pub mod string;
+pub mod validation;
 
 pub use pagination::{PaginationConfig, PaginationType};
@@ -1,8 +1,9 @@
 use crate::prelude::*;
 use serde::{Deserialize, Serialize};
 use sqlx::{Postgres, QueryBuilder};
+use utoipa::ToSchema;
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, ToSchema)]
 pub struct PaginationConfig {
     pub p: u32,
     pub size: Option<u32>,
@@ -39,7 +40,7 @@ impl PaginationConfig {
     }
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, ToSchema)]
 pub struct PaginationType {
     first_p: u32,
     last_p: u32,
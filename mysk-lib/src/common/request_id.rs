@@ -0,0 +1,18 @@
+use std::future::Future;
+use uuid::Uuid;
+
+tokio::task_local! {
+    static REQUEST_ID: Uuid;
+}
+
+/// Runs `future` with `id` as the ID of the request being handled.
+pub async fn scope<F: Future>(id: Uuid, future: F) -> F::Output {
+    REQUEST_ID.scope(id, future).await
+}
+
+/// Gets the ID of the request being handled. Outside of a request, a fresh ID is generated.
+pub fn current() -> Uuid {
+    REQUEST_ID
+        .try_with(|id| *id)
+        .unwrap_or_else(|_| Uuid::new_v4())
+}
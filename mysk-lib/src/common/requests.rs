@@ -1,16 +1,26 @@
 use crate::{
-    common::PaginationConfig,
+    common::{
+        PaginationConfig,
+        validation::{Validate, validate_request_data},
+    },
     prelude::*,
     query::{Queryable, QueryablePlaceholder},
 };
-use actix_web::{FromRequest, HttpRequest, dev::Payload};
-use futures::future;
+use actix_web::{FromRequest, HttpRequest, dev::Payload, web};
+use futures::future::{self, LocalBoxFuture};
 use serde::{Deserialize, de::DeserializeOwned};
 use sqlx::{Postgres, QueryBuilder};
 use std::fmt::{Display, Formatter};
-use std::string::ToString;
+use std::{any::TypeId, string::ToString};
+use utoipa::{
+    IntoParams, PartialSchema, ToSchema,
+    openapi::{
+        RefOr, Required, Schema,
+        path::{Parameter, ParameterBuilder, ParameterIn, ParameterStyle},
+    },
+};
 
-#[derive(Clone, Copy, Debug, Default, Deserialize)]
+#[derive(Clone, Copy, Debug, Default, Deserialize, ToSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum FetchLevel {
     #[default]
@@ -20,13 +30,13 @@ pub enum FetchLevel {
     Detailed,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, ToSchema)]
 pub struct FilterConfig<T> {
     pub data: Option<T>,
     pub q: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, ToSchema)]
 pub struct SortingConfig<S: Display> {
     by: Vec<S>,
     ascending: Option<bool>,
@@ -54,7 +64,7 @@ impl<S: Display> SortingConfig<S> {
     }
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, ToSchema)]
 pub struct SortablePlaceholder;
 
 impl Display for SortablePlaceholder {
@@ -63,17 +73,26 @@ impl Display for SortablePlaceholder {
     }
 }
 
-pub type EmptyRequestData = Option<()>;
+/// The `data` of a request to a route which takes none, which is `null` if given at all. Accepts
+/// exactly what `()` does, which can't be used itself as it has no schema.
+#[derive(Clone, Copy, Debug, Default, Deserialize, ToSchema)]
+pub struct NoData;
+
+pub type EmptyRequestData = Option<NoData>;
 
-#[derive(Clone, Debug, Default, Deserialize)]
+#[derive(Clone, Debug, Default, Deserialize, ToSchema)]
 pub struct RequestType<
     T = EmptyRequestData,
     Q: Queryable = QueryablePlaceholder,
     S: Display = SortablePlaceholder,
 > {
     pub data: T,
+    // Only read from query strings, which are documented by the `IntoParams` impl below
+    #[schema(ignore)]
     pub pagination: Option<PaginationConfig>,
+    #[schema(ignore)]
     pub filter: Option<FilterConfig<Q>>,
+    #[schema(ignore)]
     pub sort: Option<SortingConfig<S>>,
     #[serde(default)]
     pub fetch_level: FetchLevel,
@@ -84,7 +103,7 @@ pub struct RequestType<
 // Implement from request for `RequestType` with any `T`, `Q`, and `S`
 impl<T, Q, S> FromRequest for RequestType<T, Q, S>
 where
-    T: DeserializeOwned,
+    T: DeserializeOwned + Validate,
     Q: DeserializeOwned + Queryable,
     S: DeserializeOwned + Display,
 {
@@ -97,8 +116,102 @@ where
         let request_query = qs_parser.deserialize_str::<RequestType<T, Q, S>>(query_string);
 
         match request_query {
-            Ok(query) => future::ok(query),
+            Ok(query) => {
+                future::ready(validate_request_data(&query.data, req.path()).map(|()| query))
+            }
             Err(e) => future::err(Error::InvalidRequest(e.to_string(), req.path().to_string())),
         }
     }
 }
+
+/// A [`RequestType`] sent as a JSON body, whose `data` is validated like that of one sent as a
+/// query string. Takes the place of `actix_web::web::Json`, which it parses the body with.
+#[derive(Debug)]
+pub struct Json<T>(pub T);
+
+impl<T, Q, S> FromRequest for Json<RequestType<T, Q, S>>
+where
+    T: DeserializeOwned + Validate + 'static,
+    Q: DeserializeOwned + Queryable + 'static,
+    S: DeserializeOwned + Display + 'static,
+{
+    type Error = actix_web::Error;
+    type Future = LocalBoxFuture<'static, Result<Self, actix_web::Error>>;
+
+    fn from_request(req: &HttpRequest, payload: &mut Payload) -> Self::Future {
+        let body = web::Json::<RequestType<T, Q, S>>::from_request(req, payload);
+        let source = req.path().to_string();
+
+        Box::pin(async move {
+            let web::Json(request) = body.await?;
+            validate_request_data(&request.data, &source)?;
+
+            Ok(Json(request))
+        })
+    }
+}
+
+// Documents `RequestType` as the query string it's parsed from when it isn't wrapped in `Json`.
+// Parameters the route can't use, such as `filter` on a route that returns a single model, are left
+// out.
+impl<T, Q, S> IntoParams for RequestType<T, Q, S>
+where
+    T: PartialSchema + 'static,
+    Q: ToSchema + Queryable + 'static,
+    S: ToSchema + Display + 'static,
+{
+    fn into_params(_: impl Fn() -> Option<ParameterIn>) -> Vec<Parameter> {
+        fn param(name: &str, description: &str, schema: RefOr<Schema>) -> Parameter {
+            ParameterBuilder::new()
+                .name(name)
+                .parameter_in(ParameterIn::Query)
+                .required(Required::False)
+                .description(Some(description))
+                .schema(Some(schema))
+                .build()
+        }
+        fn nested_param(name: &str, description: &str, schema: RefOr<Schema>) -> Parameter {
+            let mut param = param(name, description, schema);
+            param.style = Some(ParameterStyle::DeepObject);
+            param.explode = Some(true);
+
+            param
+        }
+
+        let mut params = Vec::new();
+        if TypeId::of::<T>() != TypeId::of::<EmptyRequestData>() {
+            params.push(nested_param("data", "Route-specific input.", T::schema()));
+        }
+        if TypeId::of::<Q>() != TypeId::of::<QueryablePlaceholder>() {
+            params.push(nested_param(
+                "pagination",
+                "The page to return, e.g. `pagination[p]=2&pagination[size]=20`.",
+                PaginationConfig::schema(),
+            ));
+            params.push(nested_param(
+                "filter",
+                "Filters on the fields of `data`, e.g. `filter[data][ids][0]=<uuid>`.",
+                FilterConfig::<Q>::schema(),
+            ));
+        }
+        if TypeId::of::<S>() != TypeId::of::<SortablePlaceholder>() {
+            params.push(nested_param(
+                "sort",
+                "Columns to sort by, e.g. `sort[by][0]=id&sort[ascending]=false`.",
+                SortingConfig::<S>::schema(),
+            ));
+        }
+        params.push(param(
+            "fetch_level",
+            "How much of each result to return.",
+            FetchLevel::schema(),
+        ));
+        params.push(param(
+            "descendant_fetch_level",
+            "How much of each related model to return.",
+            FetchLevel::schema(),
+        ));
+
+        params
+    }
+}
@@ -1,11 +1,15 @@
-use crate::common::PaginationType;
+use crate::{
+    common::{PaginationType, request_id, validation::FieldError},
+    error::ErrorCode,
+};
 use chrono::{DateTime, Utc};
 use serde::Serialize;
-use sqlx::{PgConnection, query};
+use utoipa::ToSchema;
 use uuid::Uuid;
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, ToSchema)]
 pub struct MetadataType {
+    request_id: Uuid,
     timestamp: DateTime<Utc>,
     pagination: Option<PaginationType>,
 }
@@ -13,6 +17,7 @@ pub struct MetadataType {
 impl Default for MetadataType {
     fn default() -> Self {
         MetadataType {
+            request_id: request_id::current(),
             timestamp: Utc::now(),
             pagination: None,
         }
@@ -22,13 +27,14 @@ impl Default for MetadataType {
 impl MetadataType {
     pub fn new(pagination: Option<PaginationType>) -> Self {
         MetadataType {
+            request_id: request_id::current(),
             timestamp: Utc::now(),
             pagination,
         }
     }
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, ToSchema)]
 pub struct ResponseType<T> {
     api_version: String,
     data: Option<T>,
@@ -49,40 +55,36 @@ impl<T> ResponseType<T> {
     }
 }
 
-#[derive(Debug, Serialize)]
+/// Documents a [`ResponseType`] whose data is a list of models, e.g. `ResponseListType<Student>`
+/// for `ResponseType<Vec<Student>>`. Models implement `ToSchema` by hand, which utoipa can't put in
+/// a `Vec` given as a generic argument.
+#[derive(ToSchema)]
+#[schema(as = ResponseType_Vec)]
+#[allow(dead_code)]
+pub struct ResponseListType<T> {
+    api_version: String,
+    data: Option<Vec<T>>,
+    error: Option<String>,
+    meta: MetadataType,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
 pub struct EmptyResponseData;
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, ToSchema)]
 pub struct ErrorType {
     pub id: Uuid,
     pub code: i64,
     pub error_type: String,
     pub detail: String,
     pub source: String,
+    /// The business rule the request broke, if that is why it failed.
+    pub error_code: Option<ErrorCode>,
+    /// The fields which failed validation, if that is why the request failed.
+    pub fields: Option<Vec<FieldError>>,
 }
 
-impl ErrorType {
-    // TODO: tracing and error logging
-    pub async fn log(&self, conn: &mut PgConnection, api_key: Option<Uuid>) {
-        query!(
-            "
-            INSERT INTO api_logging.error_logs (id, code, error_type, detail, source, api_key_id)
-            VALUES ($1, $2, $3, $4, $5, $6)
-            ",
-            self.id,
-            self.code,
-            self.error_type,
-            self.detail,
-            self.source,
-            api_key,
-        )
-        .execute(conn)
-        .await
-        .unwrap();
-    }
-}
-
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, ToSchema)]
 pub struct ErrorResponseType {
     api_version: String,
     error: ErrorType,
@@ -1,14 +1,23 @@
-use serde::{Deserialize, Serialize};
+use crate::common::language::{self, Language};
+use async_graphql::SimpleObject;
+use serde::{Deserialize, Serialize, Serializer, ser::SerializeStruct as _};
+use utoipa::{
+    PartialSchema, ToSchema,
+    openapi::{ObjectBuilder, OneOfBuilder, RefOr, Schema, Type, schema::SchemaType},
+};
 
 // NOTE: The "en" field is renamed to "en-US" in the JSON representation
-#[derive(Clone, Debug, Deserialize, Serialize)]
+/// Collapses to a single string when the response is localized, see [`language`].
+#[derive(Clone, Debug, Deserialize, SimpleObject)]
 pub struct MultiLangString {
     #[serde(rename = "en-US")]
     pub en: Option<String>,
     pub th: String,
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize)]
+/// Collapses to a single string, or `null` if there is none, when the response is localized, see
+/// [`language`].
+#[derive(Clone, Debug, Deserialize, SimpleObject)]
 pub struct FlexibleMultiLangString {
     #[serde(rename = "en-US")]
     pub en: Option<String>,
@@ -19,10 +28,101 @@ impl MultiLangString {
     pub fn new(th: String, en: Option<String>) -> MultiLangString {
         MultiLangString { en, th }
     }
+
+    /// The string in the given language, or in Thai if there is no English one.
+    pub fn get(&self, language: Language) -> &str {
+        match language {
+            Language::English => self.en.as_deref().unwrap_or(&self.th),
+            Language::Thai => &self.th,
+        }
+    }
 }
 
 impl FlexibleMultiLangString {
     pub fn new(th: Option<String>, en: Option<String>) -> FlexibleMultiLangString {
         FlexibleMultiLangString { en, th }
     }
+
+    /// The string in the given language, or in the other one if it is missing.
+    pub fn get(&self, language: Language) -> Option<&str> {
+        let (preferred, fallback) = match language {
+            Language::English => (&self.en, &self.th),
+            Language::Thai => (&self.th, &self.en),
+        };
+
+        preferred.as_deref().or(fallback.as_deref())
+    }
+}
+
+/// An object with a string for each language, or a single string when the response is localized.
+fn multi_lang_schema(nullable: bool) -> RefOr<Schema> {
+    let string = || {
+        if nullable {
+            SchemaType::from_iter([Type::String, Type::Null])
+        } else {
+            SchemaType::Type(Type::String)
+        }
+    };
+    let object = ObjectBuilder::new()
+        .property(
+            "en-US",
+            ObjectBuilder::new().schema_type(SchemaType::from_iter([Type::String, Type::Null])),
+        )
+        .property("th", ObjectBuilder::new().schema_type(string()));
+    let object = if nullable {
+        object
+    } else {
+        object.required("th")
+    };
+
+    OneOfBuilder::new()
+        .item(object)
+        .item(
+            ObjectBuilder::new()
+                .schema_type(string())
+                .description(Some("The string in the language asked for by `lang`")),
+        )
+        .into()
+}
+
+impl PartialSchema for MultiLangString {
+    fn schema() -> RefOr<Schema> {
+        multi_lang_schema(false)
+    }
+}
+
+impl ToSchema for MultiLangString {}
+
+impl PartialSchema for FlexibleMultiLangString {
+    fn schema() -> RefOr<Schema> {
+        multi_lang_schema(true)
+    }
+}
+
+impl ToSchema for FlexibleMultiLangString {}
+
+impl Serialize for MultiLangString {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if let Some(language) = language::current() {
+            return serializer.serialize_str(self.get(language));
+        }
+
+        let mut state = serializer.serialize_struct("MultiLangString", 2)?;
+        state.serialize_field("en-US", &self.en)?;
+        state.serialize_field("th", &self.th)?;
+        state.end()
+    }
+}
+
+impl Serialize for FlexibleMultiLangString {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if let Some(language) = language::current() {
+            return self.get(language).serialize(serializer);
+        }
+
+        let mut state = serializer.serialize_struct("FlexibleMultiLangString", 2)?;
+        state.serialize_field("en-US", &self.en)?;
+        state.serialize_field("th", &self.th)?;
+        state.end()
+    }
 }
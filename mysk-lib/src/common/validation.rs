@@ -0,0 +1,185 @@
+//! Field-level validation of request data.
+//!
+//! The `data` of every [`RequestType`](crate::common::requests::RequestType) is validated when the
+//! request is extracted, before the route sees it. A request with invalid fields is rejected as
+//! [`Error::InvalidFields`], listing the path of every one with the reason it was rejected.
+
+use crate::{common::requests::NoData, prelude::*};
+use serde::Serialize;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+pub use mysk_lib_macros::Validate;
+
+/// A field which failed validation, e.g. `data.person.first_name` which must not be empty.
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct FieldError {
+    /// The path to the field from the root of the request, e.g. `data.person.birthdate`.
+    pub field: String,
+    pub reason: String,
+}
+
+impl FieldError {
+    pub fn new(field: &str, reason: impl Into<String>) -> Self {
+        Self {
+            field: field.to_string(),
+            reason: reason.into(),
+        }
+    }
+}
+
+/// Request data whose fields can be checked beyond what deserializing it does. Usually derived,
+/// see [`mysk_lib_macros::Validate`].
+pub trait Validate {
+    /// Adds a [`FieldError`] to `errors` for every invalid field, with its path under `path`.
+    fn validate(&self, path: &str, errors: &mut Vec<FieldError>);
+}
+
+impl Validate for NoData {
+    fn validate(&self, _: &str, _: &mut Vec<FieldError>) {}
+}
+
+impl Validate for Uuid {
+    fn validate(&self, _: &str, _: &mut Vec<FieldError>) {}
+}
+
+impl<T: Validate> Validate for Option<T> {
+    fn validate(&self, path: &str, errors: &mut Vec<FieldError>) {
+        if let Some(value) = self {
+            value.validate(path, errors);
+        }
+    }
+}
+
+impl<T: Validate> Validate for Vec<T> {
+    fn validate(&self, path: &str, errors: &mut Vec<FieldError>) {
+        for (index, value) in self.iter().enumerate() {
+            value.validate(&format!("{path}[{index}]"), errors);
+        }
+    }
+}
+
+/// Validates the `data` of a request to `source`, failing with every invalid field.
+pub fn validate_request_data<T: Validate>(data: &T, source: &str) -> Result<()> {
+    let mut errors = Vec::new();
+    data.validate("data", &mut errors);
+    if errors.is_empty() {
+        return Ok(());
+    }
+
+    Err(Error::InvalidFields(errors, source.to_string()))
+}
+
+/// The checks which can be derived with `#[validate(...)]`, each returning the reason a value is
+/// invalid.
+pub mod rules {
+    use crate::{
+        common::string::{FlexibleMultiLangString, MultiLangString},
+        helpers::date::get_current_date,
+        models::enums::ContactType,
+    };
+    use chrono::NaiveDate;
+    use uuid::Uuid;
+
+    /// Values which can be blank, as checked by [`not_empty`].
+    pub trait Blank {
+        fn is_blank(&self) -> bool;
+    }
+
+    impl Blank for String {
+        fn is_blank(&self) -> bool {
+            self.trim().is_empty()
+        }
+    }
+
+    impl Blank for MultiLangString {
+        fn is_blank(&self) -> bool {
+            self.th.is_blank() || self.en.as_ref().is_some_and(Blank::is_blank)
+        }
+    }
+
+    impl Blank for FlexibleMultiLangString {
+        fn is_blank(&self) -> bool {
+            (self.th.is_none() && self.en.is_none())
+                || self.th.as_ref().is_some_and(Blank::is_blank)
+                || self.en.as_ref().is_some_and(Blank::is_blank)
+        }
+    }
+
+    impl<T> Blank for Vec<T> {
+        fn is_blank(&self) -> bool {
+            self.is_empty()
+        }
+    }
+
+    pub fn not_empty(value: &impl Blank) -> Result<(), String> {
+        if value.is_blank() {
+            return Err("must not be empty".to_string());
+        }
+
+        Ok(())
+    }
+
+    pub fn past(value: &NaiveDate) -> Result<(), String> {
+        if *value > get_current_date() {
+            return Err("must not be in the future".to_string());
+        }
+
+        Ok(())
+    }
+
+    pub fn not_nil(value: &Uuid) -> Result<(), String> {
+        if value.is_nil() {
+            return Err("must not be the nil UUID".to_string());
+        }
+
+        Ok(())
+    }
+
+    pub fn min<T: Copy + Into<i64>>(value: &T, min: i64) -> Result<(), String> {
+        if (*value).into() < min {
+            return Err(format!("must be at least {min}"));
+        }
+
+        Ok(())
+    }
+
+    /// Checks that the value of a contact fits its type, if it is known. Only phone numbers and
+    /// emails have a format which can be checked.
+    pub fn contact_value(
+        r#type: impl Into<Option<ContactType>>,
+        value: &str,
+    ) -> Result<(), String> {
+        if value.trim().is_empty() {
+            return Err("must not be empty".to_string());
+        }
+
+        match r#type.into() {
+            Some(ContactType::Phone) => {
+                // Separators are allowed, e.g. `+66 81-234-5678`
+                let digits = value.strip_prefix('+').unwrap_or(value);
+                let is_valid = digits
+                    .chars()
+                    .all(|c| c.is_ascii_digit() || matches!(c, ' ' | '-' | '(' | ')'))
+                    && (9..=15).contains(&digits.chars().filter(char::is_ascii_digit).count());
+                if !is_valid {
+                    return Err("must be a phone number of 9 to 15 digits".to_string());
+                }
+            }
+            Some(ContactType::Email) => {
+                let is_valid = value.split_once('@').is_some_and(|(local, domain)| {
+                    !local.is_empty()
+                        && !domain.contains('@')
+                        && domain.split('.').count() > 1
+                        && domain.split('.').all(|label| !label.is_empty())
+                });
+                if !is_valid || value.contains(char::is_whitespace) {
+                    return Err("must be an email address".to_string());
+                }
+            }
+            Some(_) | None => (),
+        }
+
+        Ok(())
+    }
+}
@@ -1,13 +1,17 @@
-use crate::common::response::{ErrorResponseType, ErrorType};
-use actix_web::{HttpResponse, ResponseError};
-use serde::Serialize;
+use crate::common::{
+    language, request_id,
+    response::{ErrorResponseType, ErrorType, MetadataType},
+    validation::FieldError,
+};
+use actix_web::{HttpResponse, ResponseError, http::StatusCode};
+use serde::{Deserialize, Serialize};
 use sqlx::Error as SqlxError;
 use std::{
     fmt::{Display, Formatter},
     panic,
 };
 use tokio::task::JoinError;
-use uuid::Uuid;
+use utoipa::ToSchema;
 
 #[allow(clippy::doc_markdown)]
 /// Error enums for MySK API responses.
@@ -20,6 +24,10 @@ pub enum Error {
     /// HTTP 400 - [Bad Request](https://developer.mozilla.org/docs/Web/HTTP/Status/400)
     InvalidRequest(String, String),
 
+    /// HTTP 400 - [Bad Request](https://developer.mozilla.org/docs/Web/HTTP/Status/400), with
+    /// every field of the request which failed validation instead of a detail
+    InvalidFields(Vec<FieldError>, String),
+
     /// HTTP 404 - [Not Found](https://developer.mozilla.org/docs/Web/HTTP/Status/404)
     EntityNotFound(String, String),
 
@@ -53,6 +61,213 @@ pub enum Error {
     /// HTTP 503 -
     /// [Service Unavailable](https://developer.mozilla.org/en-US/docs/Web/HTTP/Status/503)
     ServiceUnavailable(String, String),
+
+    // Business Rule Errors
+    /// A business rule the request broke, along with the source. The status, `error_type` and
+    /// detail come from the [`ErrorCode`].
+    BrokenRule(ErrorCode, String),
+}
+
+/// Stable, machine-readable codes for the business rules a request can break, sent as
+/// `error_code` in the error response. Clients should match on these instead of the `detail`,
+/// which may be reworded or translated.
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Eq, Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ErrorCode {
+    // Requests
+    InvalidFields,
+    // Electives
+    ElectiveEnrollmentClosed,
+    ElectiveBlacklisted,
+    ElectiveAlreadyEnrolled,
+    ElectiveNotEnrolled,
+    ElectiveEnrolledElsewhere,
+    ElectiveReenrollment,
+    ElectiveNotCurrent,
+    ElectiveFull,
+    ElectiveIneligible,
+    ElectiveNotFull,
+    ElectiveAlreadyWaitlisted,
+    ElectiveNotWaitlisted,
+    ElectiveNoClassroom,
+    // Elective trade offers
+    TradeOfferReceiverNotEnrolled,
+    TradeOfferReceiverIneligible,
+    TradeOfferSameElective,
+    TradeOfferExists,
+    TradeOfferQuotaExceeded,
+    TradeOfferReceiverQuotaExceeded,
+    TradeOfferOwnApproval,
+    TradeOfferAlreadyApproved,
+    TradeOfferAlreadyDeclined,
+    TradeOfferExpired,
+    TradeOfferNotSender,
+    // Elective trade cycles
+    TradeIntentOwnElective,
+    TradeCycleResolved,
+    TradeCycleOutdated,
+    // Clubs
+    ClubAlreadyStaff,
+    ClubAlreadyMember,
+    ClubQuotaExceeded,
+    ClubStaffOnly,
+    ClubInviteeAlreadyStaff,
+    ClubRequestNotOwned,
+    // Contacts
+    ContactExists,
+    // Certificates
+    RsvpClosed,
+    // Cheer practice attendance
+    CheerStaffOnly,
+    CheerOwnAttendance,
+    CheerTeacherNotAllowed,
+    CheerClassroomNotInPeriod,
+    CheerPresenceNotAllowed,
+    CheerAbsenceReasonForbidden,
+    CheerStreamStaffOnly,
+    // Teaching reports
+    ReportImageMissing,
+    ReportImageExists,
+    // Idempotency
+    IdempotencyKeyReused,
+    IdempotencyKeyInProgress,
+    // Impersonation
+    ImpersonateAdmin,
+}
+
+impl ErrorCode {
+    /// The English detail sent along with the code.
+    pub fn detail(self) -> &'static str {
+        match self {
+            // Requests
+            ErrorCode::InvalidFields => "Some fields are invalid",
+            // Electives
+            ErrorCode::ElectiveEnrollmentClosed => "The elective enrollment period has ended",
+            ErrorCode::ElectiveBlacklisted => "Student is blacklisted from enrolling in electives",
+            ErrorCode::ElectiveAlreadyEnrolled => {
+                "Student has already enrolled in an elective this semester"
+            }
+            ErrorCode::ElectiveNotEnrolled => {
+                "Student has not enrolled in an elective this semester"
+            }
+            ErrorCode::ElectiveEnrolledElsewhere => "Student is enrolled in another elective",
+            ErrorCode::ElectiveReenrollment => "Student cannot re-enroll in the same elective",
+            ErrorCode::ElectiveNotCurrent => "Student cannot enroll in a non-current elective",
+            ErrorCode::ElectiveFull => "The elective is already full",
+            ErrorCode::ElectiveIneligible => "Student is not eligible to enroll in this elective",
+            ErrorCode::ElectiveNotFull => "The elective still has open seats",
+            ErrorCode::ElectiveAlreadyWaitlisted => {
+                "Student is already on the waitlist of this elective"
+            }
+            ErrorCode::ElectiveNotWaitlisted => "Student is not on the waitlist of this elective",
+            ErrorCode::ElectiveNoClassroom => "Student has no classroom",
+            // Elective trade offers
+            ErrorCode::TradeOfferReceiverNotEnrolled => {
+                "Receiving student has not enrolled in an elective this semester"
+            }
+            ErrorCode::TradeOfferReceiverIneligible => {
+                "Receiving student is not eligible to enroll in this elective"
+            }
+            ErrorCode::TradeOfferSameElective => {
+                "Both the sender and receiver has the same elective subjects"
+            }
+            ErrorCode::TradeOfferExists => "Trade offer with the receiving student already exists",
+            ErrorCode::TradeOfferQuotaExceeded => {
+                "Student has reached the maximum number of pending trade offers"
+            }
+            ErrorCode::TradeOfferReceiverQuotaExceeded => {
+                "Receiving student has reached the maximum number of pending trade offers"
+            }
+            ErrorCode::TradeOfferOwnApproval => "Student is not allowed to approve own trade offer",
+            ErrorCode::TradeOfferAlreadyApproved => "Trade offer has already been approved",
+            ErrorCode::TradeOfferAlreadyDeclined => "Trade offer has already been declined",
+            ErrorCode::TradeOfferExpired => "Trade offer has expired",
+            ErrorCode::TradeOfferNotSender => "Only the sending student can cancel a trade offer",
+            // Elective trade cycles
+            ErrorCode::TradeIntentOwnElective => "Student is already enrolled in this elective",
+            ErrorCode::TradeCycleResolved => "Trade cycle has already been approved or declined",
+            ErrorCode::TradeCycleOutdated => {
+                "The enrollments of the trade cycle have changed since it was found"
+            }
+            // Clubs
+            ErrorCode::ClubAlreadyStaff => "Student is already a staff member of the club",
+            ErrorCode::ClubAlreadyMember => "Student is already a member of the club",
+            ErrorCode::ClubQuotaExceeded => {
+                "Student has reached the maximum number of clubs allowed"
+            }
+            ErrorCode::ClubStaffOnly => "Student must be a staff of the club to add club members",
+            ErrorCode::ClubInviteeAlreadyStaff => {
+                "Invitee student is already a staff member of the club"
+            }
+            ErrorCode::ClubRequestNotOwned => {
+                "Student is not allowed to interact with this club request"
+            }
+            // Contacts
+            ErrorCode::ContactExists => "Contact with the same value already exists",
+            // Certificates
+            ErrorCode::RsvpClosed => "The certificate ceremony RSVP period has ended",
+            // Cheer practice attendance
+            ErrorCode::CheerStaffOnly => "Student must be a staff member to update attendances",
+            ErrorCode::CheerOwnAttendance => {
+                "Cheer staff cannot take their own cheer practice attendance"
+            }
+            ErrorCode::CheerTeacherNotAllowed => {
+                "Teacher is not allowed to take attendance on this period"
+            }
+            ErrorCode::CheerClassroomNotInPeriod => {
+                "Requested classroom is not a part of the current cheer practice period"
+            }
+            ErrorCode::CheerPresenceNotAllowed => {
+                "Invalid presence type for the current attendance-taking phase"
+            }
+            ErrorCode::CheerAbsenceReasonForbidden => {
+                "Absence reason was specified for a presence type that forbids a reason"
+            }
+            ErrorCode::CheerStreamStaffOnly => {
+                "Only staff members can watch attendances being taken"
+            }
+            // Teaching reports
+            ErrorCode::ReportImageMissing => "Class report is incomplete (missing image)",
+            ErrorCode::ReportImageExists => "Class report already has an image",
+            // Idempotency
+            ErrorCode::IdempotencyKeyReused => {
+                "Idempotency-Key has already been used for a different request"
+            }
+            ErrorCode::IdempotencyKeyInProgress => {
+                "A request with this Idempotency-Key is still being handled"
+            }
+            // Impersonation
+            ErrorCode::ImpersonateAdmin => "Admins cannot be impersonated",
+        }
+    }
+
+    /// The status and `error_type` the error is sent with, kept from before codes existed.
+    fn kind(self) -> (StatusCode, &'static str) {
+        match self {
+            ErrorCode::InvalidFields
+            | ErrorCode::TradeOfferSameElective
+            | ErrorCode::TradeOfferExists
+            | ErrorCode::TradeIntentOwnElective
+            | ErrorCode::ContactExists
+            | ErrorCode::CheerClassroomNotInPeriod
+            | ErrorCode::CheerPresenceNotAllowed
+            | ErrorCode::CheerAbsenceReasonForbidden
+            | ErrorCode::ReportImageMissing
+            | ErrorCode::ReportImageExists => (StatusCode::BAD_REQUEST, "invalid_request"),
+            ErrorCode::ElectiveNotWaitlisted => (StatusCode::NOT_FOUND, "entity_not_found"),
+            ErrorCode::TradeCycleOutdated
+            | ErrorCode::IdempotencyKeyReused
+            | ErrorCode::IdempotencyKeyInProgress => (StatusCode::CONFLICT, "conflicted"),
+            _ => (StatusCode::FORBIDDEN, "invalid_permission"),
+        }
+    }
+}
+
+// Written as the name it's serialized with, so the two can't drift apart
+impl Display for ErrorCode {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        self.serialize(f)
+    }
 }
 
 impl Display for Error {
@@ -62,6 +277,12 @@ impl Display for Error {
             Error::InvalidRequest(detail, source) => {
                 format!("Invalid request: {detail} (source: {source})")
             }
+            Error::InvalidFields(fields, source) => {
+                format!(
+                    "Invalid fields: {} (source: {source})",
+                    describe_fields(fields)
+                )
+            }
             Error::EntityNotFound(detail, source) => {
                 format!("Entity not found: {detail} (source: {source})")
             }
@@ -95,6 +316,10 @@ impl Display for Error {
             Error::ServiceUnavailable(detail, source) => {
                 format!("Service unavailable: {detail} (source: {source})")
             }
+            // Business Rule Errors
+            Error::BrokenRule(code, source) => {
+                format!("Broken rule {code}: {} (source: {source})", code.detail())
+            }
         };
 
         write!(f, "{error}")
@@ -185,11 +410,16 @@ impl From<jsonwebtoken::errors::Error> for Error {
 
 impl From<&Error> for HttpResponse {
     fn from(value: &Error) -> Self {
-        let response = ErrorResponseType::new(value.into(), None);
+        let mut error = ErrorType::from(value);
+        error.detail = language::translate_detail(&error.detail);
+        let response = ErrorResponseType::new(error, Some(MetadataType::default()));
 
         match value {
             // Client Errors
             Error::InvalidRequest(_, _) => HttpResponse::BadRequest().json(response),
+            Error::InvalidFields(_, _) => {
+                HttpResponse::build(ErrorCode::InvalidFields.kind().0).json(response)
+            }
             Error::EntityNotFound(_, _) => HttpResponse::NotFound().json(response),
             Error::Conflicted(_, _) => HttpResponse::Conflict().json(response),
             // Authentication Errors
@@ -203,98 +433,163 @@ impl From<&Error> for HttpResponse {
             // Server Errors
             Error::InternalServerError(_, _) => HttpResponse::InternalServerError().json(response),
             Error::ServiceUnavailable(_, _) => HttpResponse::ServiceUnavailable().json(response),
+            // Business Rule Errors
+            Error::BrokenRule(code, _) => HttpResponse::build(code.kind().0).json(response),
         }
     }
 }
 
 impl From<&Error> for ErrorType {
+    #[allow(clippy::too_many_lines)]
     fn from(value: &Error) -> Self {
         match value {
             // Client Errors
             Error::InvalidRequest(detail, source) => ErrorType {
-                id: Uuid::new_v4(),
+                id: request_id::current(),
                 code: 400,
                 error_type: "invalid_request".to_string(),
-                detail: detail.to_string(),
-                source: source.to_string(),
+                detail: detail.clone(),
+                source: source.clone(),
+                error_code: None,
+                fields: None,
             },
+            Error::InvalidFields(fields, source) => {
+                let (status, error_type) = ErrorCode::InvalidFields.kind();
+
+                ErrorType {
+                    id: request_id::current(),
+                    code: status.as_u16().into(),
+                    error_type: error_type.to_string(),
+                    detail: format!(
+                        "{}: {}",
+                        ErrorCode::InvalidFields.detail(),
+                        describe_fields(fields),
+                    ),
+                    source: source.clone(),
+                    error_code: Some(ErrorCode::InvalidFields),
+                    fields: Some(fields.clone()),
+                }
+            }
             Error::EntityNotFound(detail, source) => ErrorType {
-                id: Uuid::new_v4(),
+                id: request_id::current(),
                 code: 404,
                 error_type: "entity_not_found".to_string(),
-                detail: detail.to_string(),
-                source: source.to_string(),
+                detail: detail.clone(),
+                source: source.clone(),
+                error_code: None,
+                fields: None,
             },
             Error::Conflicted(detail, source) => ErrorType {
-                id: Uuid::new_v4(),
+                id: request_id::current(),
                 code: 409,
                 error_type: "conflicted".to_string(),
-                detail: detail.to_string(),
-                source: source.to_string(),
+                detail: detail.clone(),
+                source: source.clone(),
+                error_code: None,
+                fields: None,
             },
             // Authentication Errors
             Error::MissingApiKey(detail, source) => ErrorType {
-                id: Uuid::new_v4(),
+                id: request_id::current(),
                 code: 401,
                 error_type: "missing_api_key".to_string(),
-                detail: detail.to_string(),
-                source: source.to_string(),
+                detail: detail.clone(),
+                source: source.clone(),
+                error_code: None,
+                fields: None,
             },
             Error::InvalidApiKey(detail, source) => ErrorType {
-                id: Uuid::new_v4(),
+                id: request_id::current(),
                 code: 401,
                 error_type: "invalid_api_key".to_string(),
-                detail: detail.to_string(),
-                source: source.to_string(),
+                detail: detail.clone(),
+                source: source.clone(),
+                error_code: None,
+                fields: None,
             },
             Error::InvalidAuthorizationScheme(detail, source) => ErrorType {
-                id: Uuid::new_v4(),
+                id: request_id::current(),
                 code: 401,
                 error_type: "invalid_authorization_scheme".to_string(),
-                detail: detail.to_string(),
-                source: source.to_string(),
+                detail: detail.clone(),
+                source: source.clone(),
+                error_code: None,
+                fields: None,
             },
             Error::MissingToken(detail, source) => ErrorType {
-                id: Uuid::new_v4(),
+                id: request_id::current(),
                 code: 401,
                 error_type: "missing_token".to_string(),
-                detail: detail.to_string(),
-                source: source.to_string(),
+                detail: detail.clone(),
+                source: source.clone(),
+                error_code: None,
+                fields: None,
             },
             Error::InvalidToken(detail, source) => ErrorType {
-                id: Uuid::new_v4(),
+                id: request_id::current(),
                 code: 401,
                 error_type: "invalid_token".to_string(),
-                detail: detail.to_string(),
-                source: source.to_string(),
+                detail: detail.clone(),
+                source: source.clone(),
+                error_code: None,
+                fields: None,
             },
             // Authorization Error
             Error::InvalidPermission(detail, source) => ErrorType {
-                id: Uuid::new_v4(),
+                id: request_id::current(),
                 code: 403,
                 error_type: "invalid_permission".to_string(),
-                detail: detail.to_string(),
-                source: source.to_string(),
+                detail: detail.clone(),
+                source: source.clone(),
+                error_code: None,
+                fields: None,
             },
             // Server Errors
             Error::InternalServerError(detail, source) => ErrorType {
-                id: Uuid::new_v4(),
+                id: request_id::current(),
                 code: 500,
                 error_type: "internal_server_error".to_string(),
-                detail: detail.to_string(),
-                source: source.to_string(),
+                detail: detail.clone(),
+                source: source.clone(),
+                error_code: None,
+                fields: None,
             },
             Error::ServiceUnavailable(detail, source) => ErrorType {
-                id: Uuid::new_v4(),
+                id: request_id::current(),
                 code: 503,
                 error_type: "service_unavailable".to_string(),
-                detail: detail.to_string(),
-                source: source.to_string(),
+                detail: detail.clone(),
+                source: source.clone(),
+                error_code: None,
+                fields: None,
             },
+            // Business Rule Errors
+            Error::BrokenRule(code, source) => {
+                let (status, error_type) = code.kind();
+
+                ErrorType {
+                    id: request_id::current(),
+                    code: status.as_u16().into(),
+                    error_type: error_type.to_string(),
+                    detail: code.detail().to_string(),
+                    source: source.clone(),
+                    error_code: Some(*code),
+                    fields: None,
+                }
+            }
         }
     }
 }
 
+/// Lists the fields in one line, e.g. `data.name (must not be empty), data.value (...)`.
+fn describe_fields(fields: &[FieldError]) -> String {
+    fields
+        .iter()
+        .map(|field| format!("{} ({})", field.field, field.reason))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
 impl ResponseError for Error {
     fn error_response(&self) -> HttpResponse {
         self.into()
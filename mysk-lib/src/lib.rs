@@ -8,12 +8,16 @@
     clippy::new_without_default
 )]
 
+pub mod allocation;
+pub mod audit;
 pub mod auth;
 pub mod cache;
 pub mod common;
 pub mod error;
 pub mod helpers;
+pub mod migrations;
 pub mod models;
 pub mod permissions;
 pub mod prelude;
 pub mod query;
+pub mod webhooks;
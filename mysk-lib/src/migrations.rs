@@ -0,0 +1,108 @@
+//! The database schema, embedded from `mysk-lib/migrations` and tracked in `_sqlx_migrations`.
+
+use anyhow::{Result as AnyhowResult, bail, ensure};
+use sqlx::{
+    PgPool,
+    migrate::{Migrate as _, Migration, Migrator},
+    query, query_scalar,
+};
+
+pub static MIGRATOR: Migrator = sqlx::migrate!();
+
+/// Applies every pending migration.
+pub async fn run(pool: &PgPool) -> AnyhowResult<()> {
+    MIGRATOR.run(pool).await?;
+
+    Ok(())
+}
+
+/// Marks the initial migration as applied without running it, for databases which were set up
+/// before the schema was tracked by migrations. Later migrations are applied by [`run`] as usual.
+pub async fn baseline(pool: &PgPool) -> AnyhowResult<()> {
+    let Some(initial) = up_migrations().next() else {
+        bail!("There are no migrations to baseline");
+    };
+
+    let mut conn = pool.acquire().await?;
+    let is_initialised = query_scalar::<_, bool>("SELECT to_regclass('users') IS NOT NULL")
+        .fetch_one(&mut *conn)
+        .await?;
+    ensure!(
+        is_initialised,
+        "The database is empty; run with `--migrate` to create the schema instead",
+    );
+
+    conn.ensure_migrations_table().await?;
+    ensure!(
+        conn.list_applied_migrations().await?.is_empty(),
+        "The database is already tracked by migrations",
+    );
+
+    query(
+        "\
+        INSERT INTO _sqlx_migrations (version, description, success, checksum, execution_time) \
+        VALUES ($1, $2, TRUE, $3, 0)\
+        ",
+    )
+    .bind(initial.version)
+    .bind(&*initial.description)
+    .bind(&*initial.checksum)
+    .execute(&mut *conn)
+    .await?;
+
+    Ok(())
+}
+
+/// Ensures the database schema matches the migrations embedded in this build. A database ahead of
+/// this build is allowed, as older instances keep running during a rolling deployment.
+pub async fn check_schema_version(pool: &PgPool) -> AnyhowResult<()> {
+    let mut conn = pool.acquire().await?;
+    let is_tracked = query_scalar::<_, bool>("SELECT to_regclass('_sqlx_migrations') IS NOT NULL")
+        .fetch_one(&mut *conn)
+        .await?;
+    ensure!(
+        is_tracked,
+        "The database schema isn't tracked by migrations; run with `--migrate` to create it, or \
+        `--baseline` if it was set up by hand",
+    );
+
+    if let Some(version) = conn.dirty_version().await? {
+        bail!("Migration {version} failed partway and must be fixed by hand");
+    }
+
+    let applied = conn.list_applied_migrations().await?;
+    for migration in up_migrations() {
+        match applied
+            .iter()
+            .find(|applied| applied.version == migration.version)
+        {
+            Some(applied) => ensure!(
+                applied.checksum == migration.checksum,
+                "Migration {} was modified after it was applied",
+                migration.version,
+            ),
+            None => bail!(
+                "The database schema is behind (migration {} is pending); run with `--migrate`",
+                migration.version,
+            ),
+        }
+    }
+
+    let latest = up_migrations().map(|migration| migration.version).max();
+    if let Some(version) = applied
+        .iter()
+        .map(|applied| applied.version)
+        .filter(|&version| Some(version) > latest)
+        .max()
+    {
+        tracing::warn!("The database schema (version {version}) is newer than this build");
+    }
+
+    Ok(())
+}
+
+fn up_migrations() -> impl Iterator<Item = &'static Migration> {
+    MIGRATOR
+        .iter()
+        .filter(|migration| migration.migration_type.is_up_migration())
+}
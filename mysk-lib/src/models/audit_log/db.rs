@@ -0,0 +1,55 @@
+use crate::{
+    common::requests::FilterConfig,
+    models::{
+        audit_log::request::{queryable::QueryableAuditLog, sortable::SortableAuditLog},
+        traits::QueryRelation,
+    },
+    query::Queryable as _,
+};
+use chrono::{DateTime, Utc};
+use mysk_lib_macros::GetById;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use sqlx::{FromRow, Postgres, QueryBuilder};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+/// A change recorded by [`AuditContext`](crate::audit::AuditContext) in
+/// `api_logging.audit_logs`.
+#[derive(Clone, Debug, Deserialize, FromRow, GetById, Serialize, ToSchema)]
+#[from_query(
+    query = "\
+        SELECT id, created_at, request_id, actor_id, impersonator_id, route, entity_type, \
+        entity_id, action, diff FROM api_logging.audit_logs\
+    ",
+    count_query = "SELECT COUNT(id) FROM api_logging.audit_logs"
+)]
+pub struct DbAuditLog {
+    pub id: Uuid,
+    pub created_at: DateTime<Utc>,
+    pub request_id: Uuid,
+    pub actor_id: Option<Uuid>,
+    pub impersonator_id: Option<Uuid>,
+    pub route: String,
+    pub entity_type: String,
+    pub entity_id: Uuid,
+    pub action: String,
+    pub diff: Value,
+}
+
+impl QueryRelation for DbAuditLog {
+    type Q = QueryableAuditLog;
+    type S = SortableAuditLog;
+
+    fn build_shared_query(
+        query_builder: &mut QueryBuilder<'_, Postgres>,
+        filter: Option<FilterConfig<Self::Q>>,
+    ) {
+        if let Some(filter) = filter
+            && let Some(data) = filter.data
+        {
+            data.to_where_clause()
+                .append_into_query_builder(query_builder);
+        }
+    }
+}
@@ -0,0 +1,53 @@
+use crate::{
+    audit::AuditEntity,
+    models::audit_log::db::DbAuditLog,
+    query::{QueryParam, Queryable, SqlWhereClause},
+};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+#[derive(Clone, Debug, Serialize, Deserialize, ToSchema)]
+pub struct QueryableAuditLog {
+    pub entity_type: Option<AuditEntity>,
+    pub entity_id: Option<Uuid>,
+    pub actor_id: Option<Uuid>,
+    pub request_id: Option<Uuid>,
+}
+
+impl Queryable for QueryableAuditLog {
+    type Relation = DbAuditLog;
+
+    fn to_where_clause<'sql>(self) -> SqlWhereClause<'sql> {
+        let mut wc = SqlWhereClause::new();
+        wc.push_if_some(
+            self.entity_type.map(|entity_type| entity_type.to_string()),
+            |mut f, entity_type| {
+                f.push_sql("entity_type = ")
+                    .push_param(QueryParam::String(entity_type));
+
+                f
+            },
+        )
+        .push_if_some(self.entity_id, |mut f, entity_id| {
+            f.push_sql("entity_id = ")
+                .push_param(QueryParam::Uuid(entity_id));
+
+            f
+        })
+        .push_if_some(self.actor_id, |mut f, actor_id| {
+            f.push_sql("actor_id = ")
+                .push_param(QueryParam::Uuid(actor_id));
+
+            f
+        })
+        .push_if_some(self.request_id, |mut f, request_id| {
+            f.push_sql("request_id = ")
+                .push_param(QueryParam::Uuid(request_id));
+
+            f
+        });
+
+        wc
+    }
+}
@@ -0,0 +1,20 @@
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+use utoipa::ToSchema;
+
+#[derive(Clone, Debug, Serialize, Deserialize, Default, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum SortableAuditLog {
+    #[default]
+    CreatedAt,
+    EntityType,
+}
+
+impl Display for SortableAuditLog {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SortableAuditLog::CreatedAt => write!(f, "created_at"),
+            SortableAuditLog::EntityType => write!(f, "entity_type"),
+        }
+    }
+}
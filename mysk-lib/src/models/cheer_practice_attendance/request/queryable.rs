@@ -3,9 +3,10 @@ use crate::{
     query::{QueryParam, Queryable, SqlWhereClause},
 };
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 use uuid::Uuid;
 
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize, ToSchema)]
 pub struct QueryableCheerPracticeAttendance {
     pub ids: Option<Vec<Uuid>>,
     pub practice_period_id: Option<Uuid>,
@@ -1,19 +1,15 @@
 use serde::{Deserialize, Serialize};
 use std::fmt::{Display, Formatter};
+use utoipa::ToSchema;
 
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize, Default, ToSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum SortableCheerPracticeAttendance {
+    #[default]
     Id,
     CreatedAt,
 }
 
-impl Default for SortableCheerPracticeAttendance {
-    fn default() -> Self {
-        Self::Id
-    }
-}
-
 impl Display for SortableCheerPracticeAttendance {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match self {
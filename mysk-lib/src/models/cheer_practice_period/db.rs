@@ -5,7 +5,9 @@ use crate::{
         cheer_practice_period::request::{
             queryable::QueryableCheerPracticePeriod, sortable::SortableCheerPracticePeriod,
         },
+        enums::UserRole,
         traits::QueryRelation,
+        user::{User, UserMeta},
     },
     prelude::*,
     query::Queryable as _,
@@ -14,20 +16,11 @@ use chrono::{DateTime, NaiveDate, NaiveTime, Utc};
 use mysk_lib_macros::GetById;
 use serde::Deserialize;
 use sqlx::{FromRow, PgConnection, Postgres, QueryBuilder, query_scalar};
-use std::{collections::HashSet, sync::LazyLock};
-use uuid::{Uuid, uuid};
-
-static JATURAMITR_PERIODS: LazyLock<HashSet<Uuid>> = LazyLock::new(|| {
-    HashSet::from([
-        uuid!("870658c9-231d-454b-af62-86c0c7827ada"),
-        uuid!("a5b701d0-be27-4c52-a640-e23790457b61"),
-        uuid!("0c18a3b9-3b7f-4c71-a380-1ad0c448e35a"),
-    ])
-});
+use uuid::Uuid;
 
 #[derive(Clone, Debug, Deserialize, FromRow, GetById)]
 #[from_query(
-    query = "SELECT id, created_at, date, start_time, end_time, delay, note FROM cheer_practice_periods",
+    query = "SELECT id, created_at, date, start_time, end_time, delay, note, is_jaturamitr FROM cheer_practice_periods",
     count_query = "SELECT COUNT(id) FROM cheer_practice_periods"
 )]
 pub struct DbCheerPracticePeriod {
@@ -38,6 +31,7 @@ pub struct DbCheerPracticePeriod {
     pub end_time: NaiveTime,
     pub delay: Option<i64>,
     pub note: Option<String>,
+    pub is_jaturamitr: bool,
 }
 
 impl DbCheerPracticePeriod {
@@ -72,8 +66,30 @@ impl DbCheerPracticePeriod {
         cache.contains_cheer_teacher(teacher_id)
     }
 
-    pub fn in_jaturamitr_period(practice_period_id: Uuid) -> bool {
-        JATURAMITR_PERIODS.contains(&practice_period_id)
+    pub fn in_jaturamitr_period(cache: &GlobalCache, practice_period_id: Uuid) -> bool {
+        cache.contains_jaturamitr_period(practice_period_id)
+    }
+
+    /// Whether the user may take attendance on the practice period, or watch it being taken. Admins
+    /// may always watch.
+    pub fn is_attendance_staff(cache: &GlobalCache, user: &User, practice_period_id: Uuid) -> bool {
+        match user {
+            User { is_admin: true, .. } => true,
+            User {
+                role: UserRole::Student,
+                meta: Some(UserMeta::Student { student_id }),
+                ..
+            } => Self::is_student_cheer_staff(cache, *student_id),
+            User {
+                role: UserRole::Teacher,
+                meta: Some(UserMeta::Teacher { teacher_id }),
+                ..
+            } => {
+                Self::in_jaturamitr_period(cache, practice_period_id)
+                    || Self::is_teacher_cheer_staff(cache, *teacher_id)
+            }
+            _ => false,
+        }
     }
 }
 
@@ -7,9 +7,10 @@ use crate::{
 use chrono::{NaiveDate, NaiveTime};
 use serde::{Deserialize, Serialize};
 use sqlx::PgPool;
+use utoipa::ToSchema;
 use uuid::Uuid;
 
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize, ToSchema)]
 pub struct DefaultCheerPracticePeriod {
     pub id: Uuid,
     pub date: NaiveDate,
@@ -17,6 +18,7 @@ pub struct DefaultCheerPracticePeriod {
     pub end_time: NaiveTime,
     pub delay: Option<i64>,
     pub note: Option<String>,
+    pub is_jaturamitr: bool,
     pub classrooms: Vec<Uuid>,
 }
 
@@ -41,6 +43,7 @@ impl FetchVariant for DefaultCheerPracticePeriod {
             end_time: relation.end_time,
             delay: relation.delay,
             note: relation.note,
+            is_jaturamitr: relation.is_jaturamitr,
             classrooms: classroom_ids,
         })
     }
@@ -13,9 +13,10 @@ use chrono::{NaiveDate, NaiveTime};
 use futures::future;
 use serde::{Deserialize, Serialize};
 use sqlx::PgPool;
+use utoipa::ToSchema;
 use uuid::Uuid;
 
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize, ToSchema)]
 pub struct DetailedCheerPracticePeriod {
     pub id: Uuid,
     pub date: NaiveDate,
@@ -23,10 +24,11 @@ pub struct DetailedCheerPracticePeriod {
     pub end_time: NaiveTime,
     pub delay: Option<i64>,
     pub note: Option<String>,
+    pub is_jaturamitr: bool,
     pub classrooms: Vec<ClassroomWCheerAttendance>,
 }
 
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize, ToSchema)]
 pub struct ClassroomWCheerAttendance {
     pub classroom: Classroom,
     pub attendances: Vec<CheerPracticeAttendance>,
@@ -90,6 +92,7 @@ impl FetchVariant for DetailedCheerPracticePeriod {
             end_time: relation.end_time,
             delay: relation.delay,
             note: relation.note,
+            is_jaturamitr: relation.is_jaturamitr,
             classrooms,
         })
     }
@@ -4,13 +4,15 @@ use crate::{
 };
 use chrono::NaiveDate;
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 use uuid::Uuid;
 
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize, ToSchema)]
 pub struct QueryableCheerPracticePeriod {
     pub ids: Option<Vec<Uuid>>,
     pub date: Option<NaiveDate>,
     pub classroom_id: Option<Uuid>,
+    pub is_jaturamitr: Option<bool>,
 }
 
 impl Queryable for QueryableCheerPracticePeriod {
@@ -36,6 +38,12 @@ impl Queryable for QueryableCheerPracticePeriod {
                 .push_param(QueryParam::Uuid(classroom_id))
                 .push_sql(")");
 
+            f
+        })
+        .push_if_some(self.is_jaturamitr, |mut f, is_jaturamitr| {
+            f.push_sql("is_jaturamitr = ")
+                .push_param(QueryParam::Bool(is_jaturamitr));
+
             f
         });
 
@@ -1,20 +1,16 @@
 use serde::{Deserialize, Serialize};
 use std::fmt::{Display, Formatter};
+use utoipa::ToSchema;
 
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize, Default, ToSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum SortableCheerPracticePeriod {
+    #[default]
     Id,
     Date,
     StartTime,
 }
 
-impl Default for SortableCheerPracticePeriod {
-    fn default() -> Self {
-        Self::Id
-    }
-}
-
 impl Display for SortableCheerPracticePeriod {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -6,7 +6,10 @@ use sqlx::{FromRow, PgConnection, query};
 use uuid::Uuid;
 
 #[derive(Debug, Clone, Deserialize, FromRow, GetById)]
-#[from_query(query = "SELECT id, created_at, number, year, main_room FROM classrooms")]
+#[from_query(
+    cache = 3600,
+    query = "SELECT id, created_at, number, year, main_room FROM classrooms"
+)]
 pub struct DbClassroom {
     pub id: Uuid,
     pub created_at: Option<DateTime<Utc>>,
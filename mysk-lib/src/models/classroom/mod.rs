@@ -9,6 +9,7 @@ use crate::models::{
 };
 use serde::Deserialize;
 use sqlx::FromRow;
+use utoipa::ToSchema;
 use uuid::Uuid;
 
 pub mod db;
@@ -17,7 +18,7 @@ pub mod fetch_levels;
 pub type Classroom =
     Model<DbClassroom, IdOnlyClassroom, CompactClassroom, DefaultClassroom, DefaultClassroom>;
 
-#[derive(Debug, Clone, Deserialize, FromRow)]
+#[derive(Debug, Clone, Deserialize, FromRow, ToSchema)]
 pub struct ClassroomWClassNo {
     pub id: Uuid,
     pub class_no: i64,
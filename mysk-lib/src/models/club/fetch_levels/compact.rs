@@ -4,9 +4,10 @@ use crate::{
 };
 use mysk_lib_macros::impl_fetch_variant_from;
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 use uuid::Uuid;
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct CompactClub {
     pub id: Uuid,
     pub name: MultiLangString,
@@ -3,10 +3,13 @@ use crate::{
     models::club::db::DbClub,
     query::{QueryParam, Queryable, SqlWhereClause},
 };
+use async_graphql::InputObject;
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 use uuid::Uuid;
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema, InputObject)]
+#[graphql(name = "ClubFilter")]
 pub struct QueryableClub {
     pub ids: Option<Vec<Uuid>>,
     pub name: Option<String>,
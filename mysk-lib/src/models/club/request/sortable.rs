@@ -1,21 +1,19 @@
+use async_graphql::Enum;
 use serde::{Deserialize, Serialize};
 use std::fmt::{Display, Formatter};
+use utoipa::ToSchema;
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default, ToSchema, Enum)]
+#[graphql(name = "ClubSortColumn")]
 #[serde(rename_all = "snake_case")]
 pub enum SortableClub {
+    #[default]
     Id,
     House,
     MapLocation,
     Name,
 }
 
-impl Default for SortableClub {
-    fn default() -> Self {
-        Self::Id
-    }
-}
-
 impl Display for SortableClub {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match self {
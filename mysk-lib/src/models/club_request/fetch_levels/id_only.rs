@@ -1,9 +1,10 @@
 use crate::models::club_request::db::DbClubRequest;
 use mysk_lib_macros::impl_id_only_variant_from;
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 use uuid::Uuid;
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct IdOnlyClubRequest {
     pub id: Uuid,
 }
@@ -3,9 +3,10 @@ use crate::{
     query::{QueryParam, Queryable, SqlWhereClause},
 };
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 use uuid::Uuid;
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct QueryableClubRequest {
     pub ids: Option<Vec<Uuid>>,
     pub club_ids: Option<Vec<Uuid>>,
@@ -1,9 +1,11 @@
 use serde::{Deserialize, Serialize};
 use std::fmt::{Display, Formatter};
+use utoipa::ToSchema;
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default, ToSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum SortableClubRequest {
+    #[default]
     Id,
     ClubId,
     StudentId,
@@ -11,12 +13,6 @@ pub enum SortableClubRequest {
     Year,
 }
 
-impl Default for SortableClubRequest {
-    fn default() -> Self {
-        Self::Id
-    }
-}
-
 impl Display for SortableClubRequest {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -16,7 +16,9 @@ use uuid::Uuid;
 #[derive(Clone, Debug, Deserialize, FromRow, GetById)]
 #[from_query(
     query = "SELECT id, created_at, name_th, name_en, type, value FROM contacts",
-    count_query = "SELECT COUNT(*) FROM contacts"
+    count_query = "SELECT COUNT(*) FROM contacts",
+    cache = 300,
+    cache_capacity = 4096
 )]
 pub struct DbContact {
     pub id: Uuid,
@@ -4,9 +4,10 @@ use crate::{
 };
 use mysk_lib_macros::impl_fetch_variant_from;
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 use uuid::Uuid;
 
-#[derive(Clone, Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
 pub struct DefaultContact {
     pub id: Uuid,
     pub name: Option<FlexibleMultiLangString>,
@@ -1,20 +1,16 @@
 use serde::{Deserialize, Serialize};
 use std::fmt::{Display, Formatter};
+use utoipa::ToSchema;
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default, ToSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum SortableContact {
+    #[default]
     Id,
     Name,
     Type,
 }
 
-impl Default for SortableContact {
-    fn default() -> Self {
-        Self::Id
-    }
-}
-
 impl Display for SortableContact {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -59,8 +59,8 @@ impl DbElectiveSubject {
             match DbStudent::get_student_classroom(&mut *conn, student_id, None).await? {
                 Some(classroom) => classroom.id,
                 None => {
-                    return Err(Error::InvalidPermission(
-                        "Student has no classroom".to_string(),
+                    return Err(Error::BrokenRule(
+                        ErrorCode::ElectiveNoClassroom,
                         "DbElectiveSubject::is_student_eligible".to_string(),
                     ));
                 }
@@ -121,6 +121,8 @@ impl DbElectiveSubject {
         Ok(res.map(|r| r.elective_subject_session_id))
     }
 
+    /// The sessions this semester of electives the student took in an earlier semester. Sessions the
+    /// student withdrew from are not enrollments, see `elective_subject_session_withdrawn_students`.
     pub async fn get_previously_enrolled_electives(
         conn: &mut PgConnection,
         student_id: Uuid,
@@ -193,6 +195,41 @@ impl DbElectiveSubject {
         Ok(res.iter().map(|r| r.student_id).collect())
     }
 
+    /// Takes the enrollment lock of each session until the end of the transaction, in order of
+    /// session code so that transactions locking several sessions can't deadlock each other.
+    ///
+    /// The lock is a transaction-level advisory lock keyed by the session code, so that it only
+    /// blocks students enrolling in the same session. The numbers "69 69 76" are ASCII code that
+    /// translates to "E E L" (Enroll Electives Lock).
+    pub async fn lock_sessions(conn: &mut PgConnection, session_ids: &[Uuid]) -> Result<()> {
+        query!(
+            "\
+            SELECT pg_advisory_xact_lock(696976, session_code::int) \
+            FROM elective_subject_sessions WHERE id = ANY($1) \
+            ORDER BY session_code::int\
+            ",
+            session_ids,
+        )
+        .execute(conn)
+        .await?;
+
+        Ok(())
+    }
+
+    /// Takes the row lock of each student until the end of the transaction, in order of ID. Must
+    /// be taken after [`Self::lock_sessions`], and before checking whether the students are
+    /// enrolled, so that a student can't be enrolled in two sessions at once.
+    pub async fn lock_students(conn: &mut PgConnection, student_ids: &[Uuid]) -> Result<()> {
+        query!(
+            "SELECT id FROM students WHERE id = ANY($1) ORDER BY id FOR NO KEY UPDATE",
+            student_ids,
+        )
+        .fetch_all(conn)
+        .await?;
+
+        Ok(())
+    }
+
     pub async fn is_enrollment_period(conn: &mut PgConnection, student_id: Uuid) -> Result<bool> {
         let res = query!(
             "\
@@ -12,9 +12,10 @@ use crate::{
 };
 use serde::{Deserialize, Serialize};
 use sqlx::PgPool;
+use utoipa::ToSchema;
 use uuid::Uuid;
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct DefaultElectiveSubject {
     pub id: Uuid,
     pub name: MultiLangString,
@@ -45,15 +46,6 @@ impl FetchVariant for DefaultElectiveSubject {
         authorizer: &Authorizer,
     ) -> Result<Self> {
         let mut conn = pool.acquire().await?;
-        let subject_group = SubjectGroup::get_by_id(
-            pool,
-            relation.subject_group_id,
-            FetchLevel::IdOnly,
-            FetchLevel::IdOnly,
-            authorizer,
-        )
-        .await?;
-
         let teacher_ids =
             DbSubject::get_subject_teachers(&mut conn, relation.subject_id, None).await?;
         let co_teacher_ids =
@@ -77,6 +69,15 @@ impl FetchVariant for DefaultElectiveSubject {
         let requirements = DbSubject::get_requirements(&mut conn, relation.subject_id).await?;
         drop(conn);
 
+        let subject_group = SubjectGroup::get_by_id(
+            pool,
+            relation.subject_group_id,
+            FetchLevel::IdOnly,
+            FetchLevel::IdOnly,
+            authorizer,
+        )
+        .await?;
+
         Ok(Self {
             id: relation.id,
             name: MultiLangString::new(relation.name_th, Some(relation.name_en)),
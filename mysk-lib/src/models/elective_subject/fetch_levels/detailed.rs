@@ -13,9 +13,10 @@ use crate::{
 };
 use serde::{Deserialize, Serialize};
 use sqlx::PgPool;
+use utoipa::ToSchema;
 use uuid::Uuid;
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct DetailedElectiveSubject {
     pub id: Uuid,
     pub name: MultiLangString,
@@ -50,15 +51,6 @@ impl FetchVariant for DetailedElectiveSubject {
         authorizer: &Authorizer,
     ) -> Result<Self> {
         let mut conn = pool.acquire().await?;
-        let subject_group = SubjectGroup::get_by_id(
-            pool,
-            relation.subject_group_id,
-            FetchLevel::IdOnly,
-            FetchLevel::IdOnly,
-            authorizer,
-        )
-        .await?;
-
         let teacher_ids =
             DbSubject::get_subject_teachers(&mut conn, relation.subject_id, None).await?;
         let co_teacher_ids =
@@ -87,6 +79,15 @@ impl FetchVariant for DetailedElectiveSubject {
         let requirements = DbSubject::get_requirements(&mut conn, relation.id).await?;
         drop(conn);
 
+        let subject_group = SubjectGroup::get_by_id(
+            pool,
+            relation.subject_group_id,
+            FetchLevel::IdOnly,
+            FetchLevel::IdOnly,
+            authorizer,
+        )
+        .await?;
+
         Ok(Self {
             id: relation.id,
             name: MultiLangString::new(relation.name_th, Some(relation.name_en)),
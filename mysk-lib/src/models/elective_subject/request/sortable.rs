@@ -1,9 +1,11 @@
 use serde::{Deserialize, Serialize};
 use std::fmt::{Display, Formatter};
+use utoipa::ToSchema;
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default, ToSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum SortableElectiveSubject {
+    #[default]
     Id,
     CodeTh,
     CodeEn,
@@ -15,12 +17,6 @@ pub enum SortableElectiveSubject {
     SessionCode,
 }
 
-impl Default for SortableElectiveSubject {
-    fn default() -> Self {
-        Self::Id
-    }
-}
-
 impl Display for SortableElectiveSubject {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match self {
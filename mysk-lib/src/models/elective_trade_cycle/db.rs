@@ -0,0 +1,343 @@
+use crate::{
+    helpers::date::{get_current_academic_year, get_current_semester},
+    models::enums::SubmissionStatus,
+    prelude::*,
+};
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use sqlx::{PgConnection, query};
+use std::collections::{HashMap, HashSet, VecDeque};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+/// Students who each move to the session the next one leaves, with the last moving to the session
+/// of the first, from `elective_subject_trade_cycles`. Proposed by
+/// [`DbElectiveTradeCycle::match_intents`].
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct DbElectiveTradeCycle {
+    pub id: Uuid,
+    pub created_at: DateTime<Utc>,
+    /// Approved once every member has approved, or declined once any member has declined.
+    pub status: SubmissionStatus,
+    /// The members in the order of the cycle.
+    pub members: Vec<DbElectiveTradeCycleMember>,
+}
+
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct DbElectiveTradeCycleMember {
+    pub student_id: Uuid,
+    pub from_elective_subject_session_id: Uuid,
+    pub to_elective_subject_session_id: Uuid,
+    pub status: SubmissionStatus,
+}
+
+impl DbElectiveTradeCycle {
+    pub async fn get(conn: &mut PgConnection, cycle_id: Uuid) -> Result<Option<Self>> {
+        let Some(cycle) = query!(
+            r#"
+            SELECT id, created_at, status AS "status: SubmissionStatus"
+            FROM elective_subject_trade_cycles WHERE id = $1
+            "#,
+            cycle_id,
+        )
+        .fetch_optional(&mut *conn)
+        .await?
+        else {
+            return Ok(None);
+        };
+
+        let members = query!(
+            r#"
+            SELECT student_id, from_elective_subject_session_id, to_elective_subject_session_id,
+                status AS "status: SubmissionStatus"
+            FROM elective_subject_trade_cycle_members WHERE trade_cycle_id = $1 ORDER BY position
+            "#,
+            cycle_id,
+        )
+        .fetch_all(conn)
+        .await?
+        .into_iter()
+        .map(|member| DbElectiveTradeCycleMember {
+            student_id: member.student_id,
+            from_elective_subject_session_id: member.from_elective_subject_session_id,
+            to_elective_subject_session_id: member.to_elective_subject_session_id,
+            status: member.status,
+        })
+        .collect();
+
+        Ok(Some(Self {
+            id: cycle.id,
+            created_at: cycle.created_at,
+            status: cycle.status,
+            members,
+        }))
+    }
+
+    /// The cycles the student is a member of, newest first.
+    pub async fn get_by_student(conn: &mut PgConnection, student_id: Uuid) -> Result<Vec<Self>> {
+        let cycle_ids = query!(
+            "\
+            SELECT tc.id FROM elective_subject_trade_cycles AS tc \
+            JOIN elective_subject_trade_cycle_members AS m ON m.trade_cycle_id = tc.id \
+            WHERE m.student_id = $1 ORDER BY tc.created_at DESC\
+            ",
+            student_id,
+        )
+        .fetch_all(&mut *conn)
+        .await?;
+
+        let mut cycles = Vec::with_capacity(cycle_ids.len());
+        for cycle in cycle_ids {
+            if let Some(cycle) = Self::get(&mut *conn, cycle.id).await? {
+                cycles.push(cycle);
+            }
+        }
+
+        Ok(cycles)
+    }
+
+    /// Replaces the sessions the student is willing to move to.
+    pub async fn set_intents(
+        conn: &mut PgConnection,
+        student_id: Uuid,
+        session_ids: &[Uuid],
+    ) -> Result<()> {
+        Self::clear_intents(&mut *conn, &[student_id]).await?;
+        query!(
+            "\
+            INSERT INTO elective_subject_trade_intents (student_id, elective_subject_session_id) \
+            SELECT $1, unnest($2::uuid[])\
+            ",
+            student_id,
+            session_ids,
+        )
+        .execute(conn)
+        .await?;
+
+        Ok(())
+    }
+
+    pub async fn get_intents(conn: &mut PgConnection, student_id: Uuid) -> Result<Vec<Uuid>> {
+        let res = query!(
+            "\
+            SELECT elective_subject_session_id FROM elective_subject_trade_intents \
+            WHERE student_id = $1 ORDER BY created_at, elective_subject_session_id\
+            ",
+            student_id,
+        )
+        .fetch_all(conn)
+        .await?;
+
+        Ok(res
+            .into_iter()
+            .map(|r| r.elective_subject_session_id)
+            .collect())
+    }
+
+    /// Takes back every intent of the students, once they have traded or stopped trading.
+    pub async fn clear_intents(conn: &mut PgConnection, student_ids: &[Uuid]) -> Result<()> {
+        query!(
+            "DELETE FROM elective_subject_trade_intents WHERE student_id = ANY($1)",
+            student_ids,
+        )
+        .execute(conn)
+        .await?;
+
+        Ok(())
+    }
+
+    /// Records a member's answer to the cycle, declining the whole cycle if they declined.
+    pub async fn respond(
+        conn: &mut PgConnection,
+        cycle_id: Uuid,
+        student_id: Uuid,
+        status: SubmissionStatus,
+    ) -> Result<()> {
+        query!(
+            "\
+            UPDATE elective_subject_trade_cycle_members SET status = $1 \
+            WHERE trade_cycle_id = $2 AND student_id = $3\
+            ",
+            status as SubmissionStatus,
+            cycle_id,
+            student_id,
+        )
+        .execute(&mut *conn)
+        .await?;
+        if matches!(status, SubmissionStatus::Declined) {
+            Self::set_status(conn, cycle_id, SubmissionStatus::Declined).await?;
+        }
+
+        Ok(())
+    }
+
+    pub async fn set_status(
+        conn: &mut PgConnection,
+        cycle_id: Uuid,
+        status: SubmissionStatus,
+    ) -> Result<()> {
+        query!(
+            "UPDATE elective_subject_trade_cycles SET status = $1 WHERE id = $2",
+            status as SubmissionStatus,
+            cycle_id,
+        )
+        .execute(conn)
+        .await?;
+
+        Ok(())
+    }
+
+    /// Finds cycles among the intents of students who aren't in a pending cycle yet, and proposes
+    /// each as a new pending cycle, returning their IDs.
+    ///
+    /// A student can move to another's session if they're willing to, it's this semester, their
+    /// classroom can take it, they haven't taken the elective before and neither is blacklisted.
+    /// Students are taken in the order they posted their intents, each in the shortest cycle
+    /// through them. Every session in a cycle loses as many students as it gains, so no cap is
+    /// exceeded.
+    #[allow(clippy::too_many_lines)]
+    pub async fn match_intents(conn: &mut PgConnection) -> Result<Vec<Uuid>> {
+        // Only one transaction matches at a time, so that no student is put in two cycles.
+        //
+        // P.S. The numbers "84 67 76" are ASCII code that translates to "T C L"
+        //      (Trade Cycle Lock).
+        query!("SELECT pg_advisory_xact_lock(846776, 0)")
+            .execute(&mut *conn)
+            .await?;
+
+        let academic_year = get_current_academic_year(None);
+        let semester = get_current_semester(None);
+        let edges = query!(
+            "\
+            SELECT i.student_id AS mover_id, holder.student_id AS holder_id, \
+                holder.elective_subject_session_id \
+            FROM elective_subject_trade_intents AS i \
+            JOIN elective_subject_session_enrolled_students AS holder \
+                ON holder.elective_subject_session_id = i.elective_subject_session_id \
+            JOIN elective_subject_sessions AS ess ON ess.id = i.elective_subject_session_id \
+            WHERE ess.year = $1 AND ess.semester = $2 AND holder.student_id != i.student_id \
+            AND EXISTS (\
+                SELECT FROM elective_subject_trade_intents AS hi \
+                WHERE hi.student_id = holder.student_id\
+            ) AND NOT EXISTS (\
+                SELECT FROM elective_subject_session_enrolled_students AS me \
+                WHERE me.student_id = i.student_id \
+                AND me.elective_subject_session_id = i.elective_subject_session_id\
+            ) AND EXISTS (\
+                SELECT FROM classroom_students AS cs \
+                JOIN classrooms AS c ON c.id = cs.classroom_id \
+                JOIN elective_subject_session_classrooms AS essc \
+                    ON essc.classroom_id = cs.classroom_id \
+                WHERE cs.student_id = i.student_id AND c.year = $1 \
+                AND essc.elective_subject_session_id = i.elective_subject_session_id\
+            ) AND NOT EXISTS (\
+                SELECT FROM elective_subject_session_blacklisted_students AS bs \
+                WHERE bs.student_id IN (i.student_id, holder.student_id)\
+            ) AND NOT EXISTS (\
+                SELECT FROM elective_subject_trade_cycle_members AS m \
+                JOIN elective_subject_trade_cycles AS tc ON tc.id = m.trade_cycle_id \
+                WHERE tc.status = $3 AND m.student_id IN (i.student_id, holder.student_id)\
+            ) AND ess.subject_id NOT IN (\
+                SELECT p_ess.subject_id FROM elective_subject_session_enrolled_students AS p \
+                JOIN elective_subject_sessions AS p_ess \
+                    ON p_ess.id = p.elective_subject_session_id \
+                WHERE p.student_id = i.student_id \
+                AND (p_ess.year != $1 OR p_ess.semester != $2)\
+            ) \
+            ORDER BY i.created_at, i.student_id, holder.student_id\
+            ",
+            academic_year,
+            semester,
+            SubmissionStatus::Pending as SubmissionStatus,
+        )
+        .fetch_all(&mut *conn)
+        .await?;
+
+        let mut movers = Vec::new();
+        let mut adjacency = HashMap::<Uuid, Vec<Uuid>>::new();
+        let mut sessions = HashMap::new();
+        for edge in edges {
+            if !adjacency.contains_key(&edge.mover_id) {
+                movers.push(edge.mover_id);
+            }
+            adjacency
+                .entry(edge.mover_id)
+                .or_default()
+                .push(edge.holder_id);
+            sessions.insert(edge.holder_id, edge.elective_subject_session_id);
+        }
+
+        let mut matched = HashSet::new();
+        let mut cycle_ids = Vec::new();
+        for mover_id in movers {
+            if matched.contains(&mover_id) {
+                continue;
+            }
+            let Some(cycle) = shortest_cycle(mover_id, &adjacency, &matched) else {
+                continue;
+            };
+            matched.extend(cycle.iter().copied());
+
+            // Each member moves to the session of the next
+            let from_session_ids = cycle.iter().map(|id| sessions[id]).collect::<Vec<_>>();
+            let mut to_session_ids = from_session_ids.clone();
+            to_session_ids.rotate_left(1);
+
+            let cycle_id =
+                query!("INSERT INTO elective_subject_trade_cycles DEFAULT VALUES RETURNING id")
+                    .fetch_one(&mut *conn)
+                    .await?
+                    .id;
+            query!(
+                "\
+                INSERT INTO elective_subject_trade_cycle_members (\
+                    trade_cycle_id, position, student_id, from_elective_subject_session_id, \
+                    to_elective_subject_session_id\
+                ) \
+                SELECT $1, position::int, student_id, from_id, to_id \
+                FROM unnest($2::uuid[], $3::uuid[], $4::uuid[]) \
+                WITH ORDINALITY AS m(student_id, from_id, to_id, position)\
+                ",
+                cycle_id,
+                &cycle,
+                &from_session_ids,
+                &to_session_ids,
+            )
+            .execute(&mut *conn)
+            .await?;
+            cycle_ids.push(cycle_id);
+        }
+
+        Ok(cycle_ids)
+    }
+}
+
+/// The shortest cycle of students from `start` back to it, through students not yet matched, in
+/// the order they move.
+fn shortest_cycle(
+    start: Uuid,
+    adjacency: &HashMap<Uuid, Vec<Uuid>>,
+    matched: &HashSet<Uuid>,
+) -> Option<Vec<Uuid>> {
+    let mut previous = HashMap::new();
+    let mut queue = VecDeque::from([start]);
+    while let Some(student_id) = queue.pop_front() {
+        for next_id in adjacency.get(&student_id).into_iter().flatten() {
+            if *next_id == start {
+                let mut cycle = vec![student_id];
+                while let Some(previous_id) = previous.get(cycle.last().unwrap()) {
+                    cycle.push(*previous_id);
+                }
+                cycle.reverse();
+                return Some(cycle);
+            }
+            if matched.contains(next_id) || previous.contains_key(next_id) {
+                continue;
+            }
+            previous.insert(*next_id, student_id);
+            queue.push_back(*next_id);
+        }
+    }
+
+    None
+}
@@ -1,18 +1,20 @@
 use crate::{
     common::requests::FilterConfig,
+    helpers::date::{get_current_academic_year, get_current_semester},
     models::{
         elective_trade_offer::request::{
             queryable::QueryableElectiveTradeOffer, sortable::SortableElectiveTradeOffer,
         },
-        enums::SubmissionStatus,
+        enums::{SubmissionStatus, TradeOfferDeclineReason},
         traits::QueryRelation,
     },
+    prelude::*,
     query::Queryable as _,
 };
 use chrono::{DateTime, Utc};
 use mysk_lib_macros::GetById;
 use serde::Deserialize;
-use sqlx::{FromRow, Postgres, QueryBuilder};
+use sqlx::{FromRow, PgConnection, Postgres, QueryBuilder, query};
 use uuid::Uuid;
 
 #[derive(Clone, Debug, Deserialize, FromRow, GetById)]
@@ -28,6 +30,73 @@ pub struct DbElectiveTradeOffer {
     pub status: SubmissionStatus,
     pub sender_elective_subject_session_id: Uuid,
     pub receiver_elective_subject_session_id: Uuid,
+    pub expires_at: DateTime<Utc>,
+    pub decline_reason: Option<TradeOfferDeclineReason>,
+}
+
+impl DbElectiveTradeOffer {
+    /// Declines the pending offers past their `expires_at`, returning their IDs. Offers locked by
+    /// another transaction are left for the next call.
+    pub async fn decline_expired(conn: &mut PgConnection) -> Result<Vec<Uuid>> {
+        let res = query!(
+            "\
+            UPDATE elective_subject_trade_offers SET status = $1, decline_reason = $2 \
+            WHERE id IN (\
+                SELECT id FROM elective_subject_trade_offers \
+                WHERE status = $3 AND expires_at <= now() \
+                FOR UPDATE SKIP LOCKED\
+            ) RETURNING id\
+            ",
+            SubmissionStatus::Declined as SubmissionStatus,
+            TradeOfferDeclineReason::Expired as TradeOfferDeclineReason,
+            SubmissionStatus::Pending as SubmissionStatus,
+        )
+        .fetch_all(conn)
+        .await?;
+
+        Ok(res.into_iter().map(|r| r.id).collect())
+    }
+
+    /// Declines the pending offers which can no longer be approved, as the enrollment period of
+    /// either student has closed or the offer is from an earlier semester, returning their IDs.
+    /// Offers locked by another transaction are left for the next call.
+    pub async fn decline_closed(conn: &mut PgConnection) -> Result<Vec<Uuid>> {
+        let res = query!(
+            "\
+            UPDATE elective_subject_trade_offers SET status = $1, decline_reason = $2 \
+            WHERE id IN (\
+                SELECT o.id FROM elective_subject_trade_offers AS o \
+                JOIN elective_subject_sessions AS ess \
+                    ON ess.id = o.sender_elective_subject_session_id \
+                WHERE o.status = $3 AND (\
+                    ess.year != $4 OR ess.semester != $5 OR EXISTS (\
+                        SELECT FROM unnest(ARRAY[o.sender_id, o.receiver_id]) AS s(student_id) \
+                        WHERE NOT EXISTS (\
+                            SELECT FROM elective_subject_enrollment_periods \
+                            WHERE now() BETWEEN start_time AND end_time AND (\
+                                grade IS NULL OR grade = floor((\
+                                    SELECT number FROM classrooms AS c \
+                                    JOIN classroom_students AS cs ON cs.classroom_id = c.id \
+                                    WHERE cs.student_id = s.student_id AND year = $4\
+                                ) / 100)\
+                            )\
+                        )\
+                    )\
+                ) \
+                FOR UPDATE OF o SKIP LOCKED\
+            ) RETURNING id\
+            ",
+            SubmissionStatus::Declined as SubmissionStatus,
+            TradeOfferDeclineReason::EnrollmentClosed as TradeOfferDeclineReason,
+            SubmissionStatus::Pending as SubmissionStatus,
+            get_current_academic_year(None),
+            get_current_semester(None),
+        )
+        .fetch_all(conn)
+        .await?;
+
+        Ok(res.into_iter().map(|r| r.id).collect())
+    }
 }
 
 impl QueryRelation for DbElectiveTradeOffer {
@@ -1,17 +1,22 @@
 use crate::{
     common::requests::FetchLevel,
     models::{
-        elective_subject::ElectiveSubject, elective_trade_offer::db::DbElectiveTradeOffer,
-        enums::SubmissionStatus, student::Student, traits::FetchVariant,
+        elective_subject::ElectiveSubject,
+        elective_trade_offer::db::DbElectiveTradeOffer,
+        enums::{SubmissionStatus, TradeOfferDeclineReason},
+        student::Student,
+        traits::FetchVariant,
     },
     permissions::Authorizer,
     prelude::*,
 };
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use sqlx::PgPool;
+use utoipa::ToSchema;
 use uuid::Uuid;
 
-#[derive(Clone, Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
 pub struct DefaultElectiveTradeOffer {
     pub id: Uuid,
     pub sender: Student,
@@ -19,6 +24,10 @@ pub struct DefaultElectiveTradeOffer {
     pub sender_elective_subject: ElectiveSubject,
     pub receiver_elective_subject: ElectiveSubject,
     pub status: SubmissionStatus,
+    /// When the offer is declined if it is still pending.
+    pub expires_at: DateTime<Utc>,
+    /// Set if the offer was cancelled by the sender or declined automatically.
+    pub decline_reason: Option<TradeOfferDeclineReason>,
 }
 
 impl FetchVariant for DefaultElectiveTradeOffer {
@@ -65,6 +74,8 @@ impl FetchVariant for DefaultElectiveTradeOffer {
             )
             .await?,
             status: relation.status,
+            expires_at: relation.expires_at,
+            decline_reason: relation.decline_reason,
         })
     }
 }
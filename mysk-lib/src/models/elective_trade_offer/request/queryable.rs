@@ -3,9 +3,10 @@ use crate::{
     query::{QueryParam, Queryable, SqlWhereClause},
 };
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 use uuid::Uuid;
 
-#[derive(Clone, Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
 pub struct QueryableElectiveTradeOffer {
     pub ids: Option<Vec<Uuid>>,
     pub sender_ids: Option<Vec<Uuid>>,
@@ -1,9 +1,11 @@
 use serde::{Deserialize, Serialize};
 use std::fmt::{Display, Formatter};
+use utoipa::ToSchema;
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default, ToSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum SortableElectiveTradeOffer {
+    #[default]
     Id,
     SenderId,
     ReceiverId,
@@ -11,12 +13,6 @@ pub enum SortableElectiveTradeOffer {
     CreatedAt,
 }
 
-impl Default for SortableElectiveTradeOffer {
-    fn default() -> Self {
-        Self::Id
-    }
-}
-
 impl Display for SortableElectiveTradeOffer {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -0,0 +1,189 @@
+use crate::{
+    models::{elective_subject::db::DbElectiveSubject, traits::GetById as _},
+    prelude::*,
+    webhooks::{self, WebhookEvent},
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use sqlx::{PgConnection, query, query_as};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+/// A student's place on the waitlist of a full elective subject session, from
+/// `elective_subject_session_waitlisted_students`.
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
+pub struct DbElectiveWaitlistEntry {
+    pub id: Uuid,
+    /// When the student joined the waitlist.
+    pub created_at: DateTime<Utc>,
+    pub student_id: Uuid,
+    pub elective_subject_session_id: Uuid,
+    /// The number of students ahead of this one, plus one.
+    pub position: i64,
+}
+
+impl DbElectiveWaitlistEntry {
+    pub async fn get(
+        conn: &mut PgConnection,
+        session_id: Uuid,
+        student_id: Uuid,
+    ) -> Result<Option<Self>> {
+        let entry = query_as!(
+            Self,
+            r#"
+            SELECT id, created_at, student_id, elective_subject_session_id,
+                position AS "position!"
+            FROM (
+                SELECT *, row_number() OVER (ORDER BY created_at, id) AS position
+                FROM elective_subject_session_waitlisted_students
+                WHERE elective_subject_session_id = $1
+            ) AS w
+            WHERE student_id = $2
+            "#,
+            session_id,
+            student_id,
+        )
+        .fetch_optional(conn)
+        .await?;
+
+        Ok(entry)
+    }
+
+    /// Puts the student at the back of the session's waitlist, returning `None` if they are
+    /// already on it.
+    pub async fn join(
+        conn: &mut PgConnection,
+        session_id: Uuid,
+        student_id: Uuid,
+    ) -> Result<Option<Self>> {
+        let joined = query!(
+            "\
+            INSERT INTO elective_subject_session_waitlisted_students \
+            (student_id, elective_subject_session_id) VALUES ($1, $2) \
+            ON CONFLICT (student_id, elective_subject_session_id) DO NOTHING \
+            RETURNING id\
+            ",
+            student_id,
+            session_id,
+        )
+        .fetch_optional(&mut *conn)
+        .await?;
+        if joined.is_none() {
+            return Ok(None);
+        }
+
+        Self::get(conn, session_id, student_id).await
+    }
+
+    /// Takes the student off the session's waitlist, returning whether they were on it.
+    pub async fn leave(
+        conn: &mut PgConnection,
+        session_id: Uuid,
+        student_id: Uuid,
+    ) -> Result<bool> {
+        let res = query!(
+            "\
+            DELETE FROM elective_subject_session_waitlisted_students \
+            WHERE elective_subject_session_id = $1 AND student_id = $2\
+            ",
+            session_id,
+            student_id,
+        )
+        .execute(conn)
+        .await?;
+
+        Ok(res.rows_affected() > 0)
+    }
+
+    /// Takes the student off every waitlist, once they have enrolled in an elective.
+    pub async fn leave_all(conn: &mut PgConnection, student_id: Uuid) -> Result<()> {
+        query!(
+            "DELETE FROM elective_subject_session_waitlisted_students WHERE student_id = $1",
+            student_id,
+        )
+        .execute(conn)
+        .await?;
+
+        Ok(())
+    }
+
+    /// Enrolls the students at the front of the session's waitlist into its open seats, returning
+    /// their IDs. Students who can no longer enroll, e.g. as they have enrolled elsewhere since
+    /// joining, are taken off the waitlist instead.
+    ///
+    /// Must be called in the transaction which opened the seats, while holding the enrollment lock
+    /// of the session, see [`DbElectiveSubject::lock_sessions`]. The waiting students are locked
+    /// before they are checked, so that none of them can enroll elsewhere at the same time.
+    pub async fn promote(conn: &mut PgConnection, session_id: Uuid) -> Result<Vec<Uuid>> {
+        let session = DbElectiveSubject::get_by_id(&mut *conn, session_id).await?;
+        let mut open_seats = session.cap_size - session.class_size;
+        let mut promoted = Vec::new();
+        if open_seats <= 0 {
+            return Ok(promoted);
+        }
+
+        let waiting = query!(
+            "\
+            SELECT student_id FROM elective_subject_session_waitlisted_students \
+            WHERE elective_subject_session_id = $1 ORDER BY created_at, id\
+            ",
+            session_id,
+        )
+        .fetch_all(&mut *conn)
+        .await?
+        .into_iter()
+        .map(|r| r.student_id)
+        .collect::<Vec<_>>();
+        DbElectiveSubject::lock_students(&mut *conn, &waiting).await?;
+
+        for student_id in waiting {
+            if open_seats == 0 {
+                break;
+            }
+
+            let can_enroll = DbElectiveSubject::is_currently_enrolled(&mut *conn, student_id)
+                .await?
+                .is_none()
+                && !DbElectiveSubject::is_student_blacklisted(&mut *conn, student_id).await?
+                && match DbElectiveSubject::is_student_eligible(&mut *conn, session_id, student_id)
+                    .await
+                {
+                    Ok(is_eligible) => is_eligible,
+                    Err(Error::BrokenRule(ErrorCode::ElectiveNoClassroom, _)) => false,
+                    Err(err) => return Err(err),
+                };
+            if !can_enroll {
+                Self::leave(&mut *conn, session_id, student_id).await?;
+                continue;
+            }
+
+            query!(
+                "\
+                INSERT INTO elective_subject_session_enrolled_students \
+                (student_id, elective_subject_session_id) VALUES ($1, $2)\
+                ",
+                student_id,
+                session_id,
+            )
+            .execute(&mut *conn)
+            .await?;
+            Self::leave_all(&mut *conn, student_id).await?;
+            webhooks::enqueue(
+                &mut *conn,
+                WebhookEvent::ElectiveEnrolled,
+                json!({
+                    "student_id": student_id,
+                    "elective_subject_session_id": session_id,
+                    "previous_elective_subject_session_id": None::<Uuid>,
+                }),
+            )
+            .await?;
+
+            promoted.push(student_id);
+            open_seats -= 1;
+        }
+
+        Ok(promoted)
+    }
+}
@@ -1,8 +1,9 @@
 use serde::{Deserialize, Serialize};
 use sqlx::Type as SqlxType;
 use std::fmt::{Display, Formatter};
+use utoipa::ToSchema;
 
-#[derive(Clone, Copy, Debug, Deserialize, Serialize, SqlxType)]
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, SqlxType, ToSchema)]
 #[sqlx(type_name = "blood_group")]
 pub enum BloodGroup {
     #[serde(rename = "A+")]
@@ -1,8 +1,9 @@
 use serde::{Deserialize, Serialize};
 use sqlx::Type as SqlxType;
 use std::fmt::{Display, Formatter};
+use utoipa::ToSchema;
 
-#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize, SqlxType)]
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize, SqlxType, ToSchema)]
 #[serde(rename_all = "snake_case")]
 #[sqlx(type_name = "submission_status", rename_all = "snake_case")]
 pub enum CertificateType {
@@ -1,8 +1,10 @@
+use async_graphql::Enum;
 use serde::{Deserialize, Serialize};
 use sqlx::Type as SqlxType;
 use std::fmt::{Display, Formatter};
+use utoipa::ToSchema;
 
-#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize, SqlxType)]
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Eq, Serialize, SqlxType, ToSchema, Enum)]
 #[serde(rename_all = "snake_case")]
 #[sqlx(type_name = "contact_types", rename_all = "snake_case")]
 pub enum ContactType {
@@ -6,6 +6,7 @@ mod sex;
 mod shirt_size;
 mod subject_type;
 mod submission_status;
+mod trade_offer_decline_reason;
 mod user_role;
 
 pub use blood_group::BloodGroup;
@@ -16,4 +17,5 @@ pub use sex::Sex;
 pub use shirt_size::ShirtSize;
 pub use subject_type::SubjectType;
 pub use submission_status::SubmissionStatus;
+pub use trade_offer_decline_reason::TradeOfferDeclineReason;
 pub use user_role::UserRole;
@@ -0,0 +1,28 @@
+use serde::{Deserialize, Serialize};
+use sqlx::Type as SqlxType;
+use std::fmt::{Display, Formatter};
+use utoipa::ToSchema;
+
+/// Why a trade offer was declined, when it wasn't declined by a student through
+/// `PUT /v1/subjects/electives/trade-offers/{id}`.
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize, SqlxType, ToSchema)]
+#[serde(rename_all = "snake_case")]
+#[sqlx(type_name = "trade_offer_decline_reason", rename_all = "snake_case")]
+pub enum TradeOfferDeclineReason {
+    /// Taken back by the sending student.
+    Cancelled,
+    /// Left pending past its `expires_at`.
+    Expired,
+    /// Left pending when the enrollment period of either student closed.
+    EnrollmentClosed,
+}
+
+impl Display for TradeOfferDeclineReason {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TradeOfferDeclineReason::Cancelled => write!(f, "cancelled"),
+            TradeOfferDeclineReason::Expired => write!(f, "expired"),
+            TradeOfferDeclineReason::EnrollmentClosed => write!(f, "enrollment_closed"),
+        }
+    }
+}
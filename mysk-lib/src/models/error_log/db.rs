@@ -0,0 +1,112 @@
+use crate::{
+    common::{requests::FilterConfig, response::ErrorType},
+    models::{
+        error_log::request::{queryable::QueryableErrorLog, sortable::SortableErrorLog},
+        traits::QueryRelation,
+    },
+    prelude::*,
+    query::Queryable as _,
+};
+use chrono::{DateTime, Utc};
+use mysk_lib_macros::GetById;
+use serde::{Deserialize, Serialize};
+use sqlx::{FromRow, PgPool, Postgres, QueryBuilder};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+/// An error returned to a client, as recorded in `api_logging.error_logs`.
+#[derive(Clone, Debug, Deserialize, FromRow, GetById, Serialize, ToSchema)]
+#[from_query(
+    query = "\
+        SELECT id, created_at, request_id, code, error_type, detail, source, method, path, \
+        api_key_id, user_id FROM api_logging.error_logs\
+    ",
+    count_query = "SELECT COUNT(id) FROM api_logging.error_logs"
+)]
+pub struct DbErrorLog {
+    pub id: Uuid,
+    pub created_at: DateTime<Utc>,
+    pub request_id: Uuid,
+    pub code: i64,
+    pub error_type: String,
+    pub detail: String,
+    pub source: String,
+    pub method: String,
+    pub path: String,
+    pub api_key_id: Option<Uuid>,
+    pub user_id: Option<Uuid>,
+}
+
+impl DbErrorLog {
+    pub fn new(
+        error: ErrorType,
+        method: String,
+        path: String,
+        api_key_id: Option<Uuid>,
+        user_id: Option<Uuid>,
+    ) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            created_at: Utc::now(),
+            request_id: error.id,
+            code: error.code,
+            error_type: error.error_type,
+            detail: error.detail,
+            source: error.source,
+            method,
+            path,
+            api_key_id,
+            user_id,
+        }
+    }
+
+    pub async fn insert_many(pool: &PgPool, error_logs: &[Self]) -> Result<()> {
+        if error_logs.is_empty() {
+            return Ok(());
+        }
+
+        let mut qb = QueryBuilder::new(
+            "\
+            INSERT INTO api_logging.error_logs (\
+                id, created_at, request_id, code, error_type, detail, source, method, path, \
+                api_key_id, user_id\
+            ) \
+            ",
+        );
+        qb.push_values(error_logs, |mut row, error_log| {
+            row.push_bind(error_log.id)
+                .push_bind(error_log.created_at)
+                .push_bind(error_log.request_id)
+                .push_bind(error_log.code)
+                .push_bind(&error_log.error_type)
+                .push_bind(&error_log.detail)
+                .push_bind(&error_log.source)
+                .push_bind(&error_log.method)
+                .push_bind(&error_log.path)
+                .push_bind(error_log.api_key_id)
+                .push_bind(error_log.user_id);
+        })
+        .build()
+        .execute(pool)
+        .await?;
+
+        Ok(())
+    }
+}
+
+impl QueryRelation for DbErrorLog {
+    type Q = QueryableErrorLog;
+    type S = SortableErrorLog;
+
+    fn build_shared_query(
+        query_builder: &mut QueryBuilder<'_, Postgres>,
+        filter: Option<FilterConfig<Self::Q>>,
+    ) {
+        if let Some(filter) = filter
+            && let Some(data) = filter.data
+        {
+            data.to_where_clause()
+                .append_into_query_builder(query_builder);
+        }
+    }
+}
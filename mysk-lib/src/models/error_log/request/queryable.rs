@@ -0,0 +1,63 @@
+use crate::{
+    models::error_log::db::DbErrorLog,
+    query::{QueryParam, Queryable, SqlWhereClause},
+};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+#[derive(Clone, Debug, Serialize, Deserialize, ToSchema)]
+pub struct QueryableErrorLog {
+    pub request_id: Option<Uuid>,
+    pub code: Option<i64>,
+    pub error_type: Option<String>,
+    pub path: Option<String>,
+    pub api_key_id: Option<Uuid>,
+    pub user_id: Option<Uuid>,
+}
+
+impl Queryable for QueryableErrorLog {
+    type Relation = DbErrorLog;
+
+    fn to_where_clause<'sql>(self) -> SqlWhereClause<'sql> {
+        let mut wc = SqlWhereClause::new();
+        wc.push_if_some(self.request_id, |mut f, request_id| {
+            f.push_sql("request_id = ")
+                .push_param(QueryParam::Uuid(request_id));
+
+            f
+        })
+        .push_if_some(self.code, |mut f, code| {
+            f.push_sql("code = ").push_param(QueryParam::Int(code));
+
+            f
+        })
+        .push_if_some(self.error_type, |mut f, error_type| {
+            f.push_sql("error_type = ")
+                .push_param(QueryParam::String(error_type));
+
+            f
+        })
+        .push_if_some(self.path, |mut f, path| {
+            f.push_sql("path ILIKE ('%' || ")
+                .push_param(QueryParam::String(path))
+                .push_sql(" || '%')");
+
+            f
+        })
+        .push_if_some(self.api_key_id, |mut f, api_key_id| {
+            f.push_sql("api_key_id = ")
+                .push_param(QueryParam::Uuid(api_key_id));
+
+            f
+        })
+        .push_if_some(self.user_id, |mut f, user_id| {
+            f.push_sql("user_id = ")
+                .push_param(QueryParam::Uuid(user_id));
+
+            f
+        });
+
+        wc
+    }
+}
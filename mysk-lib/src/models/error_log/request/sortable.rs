@@ -0,0 +1,22 @@
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+use utoipa::ToSchema;
+
+#[derive(Clone, Debug, Serialize, Deserialize, Default, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum SortableErrorLog {
+    #[default]
+    CreatedAt,
+    Code,
+    ErrorType,
+}
+
+impl Display for SortableErrorLog {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SortableErrorLog::CreatedAt => write!(f, "created_at"),
+            SortableErrorLog::Code => write!(f, "code"),
+            SortableErrorLog::ErrorType => write!(f, "error_type"),
+        }
+    }
+}
@@ -1,3 +1,4 @@
+pub mod audit_log;
 pub mod certificate;
 pub mod cheer_practice_attendance;
 pub mod cheer_practice_period;
@@ -6,8 +7,11 @@ pub mod club;
 pub mod club_request;
 pub mod contact;
 pub mod elective_subject;
+pub mod elective_trade_cycle;
 pub mod elective_trade_offer;
+pub mod elective_waitlist;
 pub mod enums;
+pub mod error_log;
 pub mod model;
 pub mod online_teaching_reports;
 pub mod organization;
@@ -18,3 +22,5 @@ pub mod subject_group;
 pub mod teacher;
 pub mod traits;
 pub mod user;
+pub mod webhook_delivery;
+pub mod webhook_endpoint;
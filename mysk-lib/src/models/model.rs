@@ -9,7 +9,11 @@ use crate::{
 };
 use serde::{Deserialize, Serialize, Serializer};
 use sqlx::PgPool;
-use std::{marker::PhantomData, sync::Arc};
+use std::{borrow::Cow, marker::PhantomData, sync::Arc};
+use utoipa::{
+    PartialSchema, ToSchema,
+    openapi::{OneOfBuilder, RefOr, Schema, schema::RefBuilder},
+};
 
 /// Data model used for every user-facing API response. A data model for a relation can exist as one
 /// of four variants.
@@ -208,3 +212,62 @@ where
         }
     }
 }
+
+// A model is documented as one of its fetch variants, named after the relation, e.g. `Student` for
+// `IdOnlyStudent`, `CompactStudent`, and so on.
+impl<R, Io, Co, Df, Dt> PartialSchema for Model<R, Io, Co, Df, Dt>
+where
+    Io: ToSchema,
+    Co: ToSchema,
+    Df: ToSchema,
+    Dt: ToSchema,
+{
+    fn schema() -> RefOr<Schema> {
+        let mut variants = Vec::new();
+        for name in [Io::name(), Co::name(), Df::name(), Dt::name()] {
+            // Some relations reuse a variant for several fetch levels
+            if !variants.contains(&name) {
+                variants.push(name);
+            }
+        }
+
+        variants
+            .into_iter()
+            .fold(OneOfBuilder::new(), |one_of, name| {
+                one_of.item(RefBuilder::new().ref_location_from_schema_name(name))
+            })
+            .into()
+    }
+}
+
+impl<R, Io, Co, Df, Dt> ToSchema for Model<R, Io, Co, Df, Dt>
+where
+    Io: ToSchema,
+    Co: ToSchema,
+    Df: ToSchema,
+    Dt: ToSchema,
+{
+    fn name() -> Cow<'static, str> {
+        match Io::name() {
+            Cow::Borrowed(name) => Cow::Borrowed(name.trim_start_matches("IdOnly")),
+            Cow::Owned(name) => Cow::Owned(name.trim_start_matches("IdOnly").to_string()),
+        }
+    }
+
+    fn schemas(schemas: &mut Vec<(String, RefOr<Schema>)>) {
+        // Models refer to each other in cycles (a student has a classroom which has students), so
+        // the variants of each model are only expanded once
+        if schemas.iter().any(|(name, _)| *name == Io::name()) {
+            return;
+        }
+
+        schemas.push((Io::name().into_owned(), Io::schema()));
+        Io::schemas(schemas);
+        schemas.push((Co::name().into_owned(), Co::schema()));
+        Co::schemas(schemas);
+        schemas.push((Df::name().into_owned(), Df::schema()));
+        Df::schemas(schemas);
+        schemas.push((Dt::name().into_owned(), Dt::schema()));
+        Dt::schemas(schemas);
+    }
+}
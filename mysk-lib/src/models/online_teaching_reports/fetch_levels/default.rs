@@ -10,9 +10,10 @@ use crate::{
 use chrono::NaiveDate;
 use serde::{Deserialize, Serialize};
 use sqlx::PgPool;
+use utoipa::ToSchema;
 use uuid::Uuid;
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct DefaultOnlineTeachingReports {
     pub id: Uuid,
     pub subject: Option<Subject>,
@@ -45,11 +46,11 @@ impl FetchVariant for DefaultOnlineTeachingReports {
             )
             .await?;
 
-        let subject = if relation.subject_id.is_some() {
+        let subject = if let Some(subject_id) = relation.subject_id {
             Some(
                 Subject::get_by_id(
                     pool,
-                    relation.subject_id.unwrap(),
+                    subject_id,
                     descendant_fetch_level,
                     FetchLevel::IdOnly,
                     authorizer,
@@ -59,11 +60,11 @@ impl FetchVariant for DefaultOnlineTeachingReports {
         } else {
             None
         };
-        let classroom = if relation.classroom_id.is_some() {
+        let classroom = if let Some(classroom_id) = relation.classroom_id {
             Some(
                 Classroom::get_by_id(
                     pool,
-                    relation.classroom_id.unwrap(),
+                    classroom_id,
                     descendant_fetch_level,
                     FetchLevel::IdOnly,
                     authorizer,
@@ -4,9 +4,10 @@ use crate::{
 };
 use chrono::NaiveDate;
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 use uuid::Uuid;
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct QueryableOnlineTeachingReports {
     pub ids: Option<Vec<Uuid>>,
     pub dates: Option<Vec<NaiveDate>>,
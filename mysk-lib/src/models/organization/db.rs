@@ -5,7 +5,7 @@ use sqlx::FromRow;
 use uuid::Uuid;
 
 #[derive(Clone, Debug, Deserialize, FromRow, GetById)]
-#[from_query(query = "SELECT * FROM organizations")]
+#[from_query(cache = 600, query = "SELECT * FROM organizations")]
 pub struct DbOrganization {
     pub id: Uuid,
     pub created_at: Option<DateTime<Utc>>,
@@ -6,11 +6,12 @@ use crate::{
 use chrono::{DateTime, Utc};
 use serde::Deserialize;
 use sqlx::PgConnection;
+use utoipa::ToSchema;
 use uuid::Uuid;
 
 pub mod db;
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, ToSchema)]
 pub struct Organization {
     pub id: Uuid,
     pub created_at: Option<DateTime<Utc>>,
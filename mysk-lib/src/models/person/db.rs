@@ -17,7 +17,7 @@ use uuid::Uuid;
         FROM people",
     count_query = "SELECT COUNT(distinct id) FROM people"
 )]
-#[from_query(relation = "people")]
+#[from_query(relation = "people", cache = 300, cache_capacity = 4096)]
 pub struct DbPerson {
     pub id: Uuid,
     pub created_at: Option<DateTime<Utc>>,
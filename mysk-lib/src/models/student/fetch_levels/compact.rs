@@ -1,9 +1,10 @@
 use crate::models::student::db::DbStudent;
 use mysk_lib_macros::impl_fetch_variant_from;
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 use uuid::Uuid;
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct CompactStudent {
     pub id: Uuid,
     pub student_id: Option<String>,
@@ -2,10 +2,13 @@ use crate::{
     models::student::db::DbStudent,
     query::{QueryParam, Queryable, SqlWhereClause},
 };
+use async_graphql::InputObject;
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 use uuid::Uuid;
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema, InputObject)]
+#[graphql(name = "StudentFilter")]
 pub struct QueryableStudent {
     pub ids: Option<Vec<Uuid>>,
     pub student_ids: Option<Vec<String>>,
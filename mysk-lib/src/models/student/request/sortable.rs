@@ -1,19 +1,17 @@
+use async_graphql::Enum;
 use serde::{Deserialize, Serialize};
 use std::fmt::{Display, Formatter};
+use utoipa::ToSchema;
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default, ToSchema, Enum)]
+#[graphql(name = "StudentSortColumn")]
 #[serde(rename_all = "snake_case")]
 pub enum SortableStudent {
+    #[default]
     Id,
     StudentId,
 }
 
-impl Default for SortableStudent {
-    fn default() -> Self {
-        Self::Id
-    }
-}
-
 impl Display for SortableStudent {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -9,12 +9,15 @@ use sqlx::{FromRow, PgConnection, query};
 use uuid::Uuid;
 
 #[derive(Clone, Debug, Deserialize, FromRow, GetById)]
-#[from_query(query = "
-    SELECT
-        id, created_at, name_th, name_en, code_th, code_en, short_name_th, short_name_en, type,
-        credit, description_th, description_en, semester, subject_group_id, syllabus
-    FROM subjects
-")]
+#[from_query(
+    query = "
+        SELECT
+            id, created_at, name_th, name_en, code_th, code_en, short_name_th, short_name_en, type,
+            credit, description_th, description_en, semester, subject_group_id, syllabus
+        FROM subjects
+    ",
+    cache = 600
+)]
 pub struct DbSubject {
     pub id: Uuid,
     pub created_at: Option<DateTime<Utc>>,
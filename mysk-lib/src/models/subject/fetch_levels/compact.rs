@@ -1,9 +1,10 @@
 use crate::{common::string::MultiLangString, models::subject::db::DbSubject};
 use mysk_lib_macros::impl_fetch_variant_from;
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 use uuid::Uuid;
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct CompactSubject {
     pub id: Uuid,
     pub name: MultiLangString,
@@ -12,9 +12,10 @@ use crate::{
 };
 use serde::{Deserialize, Serialize};
 use sqlx::PgPool;
+use utoipa::ToSchema;
 use uuid::Uuid;
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct DetailedSubject {
     pub id: Uuid,
     pub name: MultiLangString,
@@ -45,6 +46,12 @@ impl FetchVariant for DetailedSubject {
             .authorize_subject(&relation, &mut conn, ActionType::ReadDetailed)
             .await?;
 
+        let teacher_ids = DbSubject::get_subject_teachers(&mut conn, relation.id, None).await?;
+        let co_teacher_ids =
+            DbSubject::get_subject_co_teachers(&mut conn, relation.id, None).await?;
+        let classroom_ids = DbSubject::get_subject_classrooms(&mut conn, relation.id, None).await?;
+        drop(conn);
+
         let subject_group = SubjectGroup::get_by_id(
             pool,
             relation.subject_group_id,
@@ -54,12 +61,6 @@ impl FetchVariant for DetailedSubject {
         )
         .await?;
 
-        let teacher_ids = DbSubject::get_subject_teachers(&mut conn, relation.id, None).await?;
-        let co_teacher_ids =
-            DbSubject::get_subject_co_teachers(&mut conn, relation.id, None).await?;
-        let classroom_ids = DbSubject::get_subject_classrooms(&mut conn, relation.id, None).await?;
-        drop(conn);
-
         let description = match (relation.description_th, relation.description_en) {
             (Some(description_th), Some(description_en)) => Some(FlexibleMultiLangString {
                 th: Some(description_th),
@@ -6,6 +6,7 @@ use sqlx::FromRow;
 #[derive(Debug, Clone, Deserialize, FromRow, GetById)]
 #[from_query(
     id = "i64",
+    cache = 3600,
     query = "SELECT id, created_at, name_th, name_en FROM subject_groups"
 )]
 pub struct DbSubjectGroup {
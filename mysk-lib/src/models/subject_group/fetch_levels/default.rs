@@ -2,8 +2,9 @@ use crate::{common::string::MultiLangString, models::subject_group::db::DbSubjec
 use chrono::{DateTime, Utc};
 use mysk_lib_macros::impl_fetch_variant_from;
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct DefaultSubjectGroup {
     pub id: i64,
     pub created_at: Option<DateTime<Utc>>,
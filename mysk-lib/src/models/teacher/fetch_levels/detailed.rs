@@ -9,9 +9,10 @@ use crate::{
 };
 use serde::{Deserialize, Serialize};
 use sqlx::PgPool;
+use utoipa::ToSchema;
 use uuid::Uuid;
 
-#[derive(Clone, Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
 pub struct DetailedTeacher {
     pub id: Uuid,
     pub teacher_id: Option<String>,
@@ -41,15 +42,6 @@ impl FetchVariant for DetailedTeacher {
         let classroom_id = DbTeacher::get_teacher_advisor_at(&mut conn, relation.id, None).await?;
         let subject_ids = DbTeacher::get_subject_in_charge(&mut conn, relation.id, None).await?;
 
-        let subject_group = SubjectGroup::get_by_id(
-            pool,
-            relation.subject_group_id,
-            descendant_fetch_level,
-            FetchLevel::IdOnly,
-            authorizer,
-        )
-        .await?;
-
         let user = match relation.user_id {
             Some(user_id) => Some(User::get_by_id(&mut conn, user_id, None).await?),
             None => None,
@@ -61,6 +53,15 @@ impl FetchVariant for DetailedTeacher {
         };
         drop(conn);
 
+        let subject_group = SubjectGroup::get_by_id(
+            pool,
+            relation.subject_group_id,
+            descendant_fetch_level,
+            FetchLevel::IdOnly,
+            authorizer,
+        )
+        .await?;
+
         Ok(Self {
             id: relation.id,
             teacher_id: relation.teacher_id,
@@ -2,10 +2,13 @@ use crate::{
     models::teacher::db::DbTeacher,
     query::{QueryParam, Queryable, SqlWhereClause},
 };
+use async_graphql::InputObject;
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 use uuid::Uuid;
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema, InputObject)]
+#[graphql(name = "TeacherFilter")]
 pub struct QueryableTeacher {
     pub ids: Option<Vec<Uuid>>,
     pub subject_group_ids: Option<Vec<i64>>,
@@ -35,7 +35,15 @@ pub trait GetById: for<'r> FromRow<'r, PgRow> + Send + Sized + 'static {
     fn get_by_ids(
         pool: &PgPool,
         ids: &[Self::Id],
-    ) -> impl Future<Output = Result<Vec<Self>, SqlxError>>;
+    ) -> impl Future<Output = Result<Vec<Self>, SqlxError>> + Send;
+
+    /// Evicts rows from the relation's cache. This is a no-op unless the relation is derived with
+    /// `#[from_query(cache = ...)]`, in which case it must be called after modifying the rows.
+    fn evict_cached(ids: &[Self::Id]) -> impl Future<Output = ()> + Send {
+        let _ = ids;
+
+        async {}
+    }
 }
 
 /// A fetch variant is a data model that can be derived from a base relation.
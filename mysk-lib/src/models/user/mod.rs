@@ -5,11 +5,12 @@ use crate::{
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use sqlx::{PgConnection, query_scalar};
+use utoipa::ToSchema;
 use uuid::Uuid;
 
 pub mod db;
 
-#[derive(Clone, Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
 #[serde(untagged)]
 pub enum UserMeta {
     Student { student_id: Uuid },
@@ -17,7 +18,7 @@ pub enum UserMeta {
     Organization { organization_id: Uuid },
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
 pub struct User {
     pub id: Uuid,
     pub created_at: Option<DateTime<Utc>>,
@@ -27,6 +28,9 @@ pub struct User {
     pub is_admin: bool,
     pub onboarded: bool,
     pub permissions: Vec<String>,
+    /// The admin acting as this user, see [`TokenClaims::imp`](crate::auth::oauth::TokenClaims).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub impersonator_id: Option<Uuid>,
 }
 
 impl User {
@@ -91,6 +95,7 @@ impl User {
             is_admin: user.is_admin,
             onboarded: user.onboarded,
             permissions,
+            impersonator_id: None,
         })
     }
 
@@ -0,0 +1,58 @@
+use crate::{
+    common::requests::FilterConfig,
+    models::{
+        traits::QueryRelation,
+        webhook_delivery::request::{
+            queryable::QueryableWebhookDelivery, sortable::SortableWebhookDelivery,
+        },
+    },
+    query::Queryable as _,
+    webhooks::WebhookEvent,
+};
+use chrono::{DateTime, Utc};
+use mysk_lib_macros::GetById;
+use serde::{Deserialize, Serialize};
+use sqlx::{FromRow, Postgres, QueryBuilder};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+/// An attempt at delivering an event to a webhook endpoint, as recorded in
+/// `api_logging.webhook_deliveries`.
+#[derive(Clone, Debug, Deserialize, FromRow, GetById, Serialize, ToSchema)]
+#[from_query(
+    query = "\
+        SELECT id, created_at, outbox_id, endpoint_id, event, attempt, status_code, error, \
+        duration_ms FROM api_logging.webhook_deliveries\
+    ",
+    count_query = "SELECT COUNT(id) FROM api_logging.webhook_deliveries"
+)]
+pub struct DbWebhookDelivery {
+    pub id: Uuid,
+    pub created_at: DateTime<Utc>,
+    /// The event being delivered, which keeps its ID across attempts.
+    pub outbox_id: Uuid,
+    pub endpoint_id: Uuid,
+    pub event: WebhookEvent,
+    pub attempt: i32,
+    /// The status the endpoint responded with, if it could be reached.
+    pub status_code: Option<i32>,
+    pub error: Option<String>,
+    pub duration_ms: i64,
+}
+
+impl QueryRelation for DbWebhookDelivery {
+    type Q = QueryableWebhookDelivery;
+    type S = SortableWebhookDelivery;
+
+    fn build_shared_query(
+        query_builder: &mut QueryBuilder<'_, Postgres>,
+        filter: Option<FilterConfig<Self::Q>>,
+    ) {
+        if let Some(filter) = filter
+            && let Some(data) = filter.data
+        {
+            data.to_where_clause()
+                .append_into_query_builder(query_builder);
+        }
+    }
+}
@@ -0,0 +1,2 @@
+pub mod queryable;
+pub mod sortable;
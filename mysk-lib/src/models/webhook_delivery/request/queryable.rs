@@ -0,0 +1,51 @@
+use crate::{
+    models::webhook_delivery::db::DbWebhookDelivery,
+    query::{QueryParam, Queryable, SqlWhereClause},
+    webhooks::WebhookEvent,
+};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+#[derive(Clone, Debug, Serialize, Deserialize, ToSchema)]
+pub struct QueryableWebhookDelivery {
+    pub endpoint_id: Option<Uuid>,
+    pub outbox_id: Option<Uuid>,
+    pub event: Option<WebhookEvent>,
+    /// Only failed attempts if `true`, only successful ones if `false`.
+    pub failed: Option<bool>,
+}
+
+impl Queryable for QueryableWebhookDelivery {
+    type Relation = DbWebhookDelivery;
+
+    fn to_where_clause<'sql>(self) -> SqlWhereClause<'sql> {
+        let mut wc = SqlWhereClause::new();
+        wc.push_if_some(self.endpoint_id, |mut f, endpoint_id| {
+            f.push_sql("endpoint_id = ")
+                .push_param(QueryParam::Uuid(endpoint_id));
+
+            f
+        })
+        .push_if_some(self.outbox_id, |mut f, outbox_id| {
+            f.push_sql("outbox_id = ")
+                .push_param(QueryParam::Uuid(outbox_id));
+
+            f
+        })
+        .push_if_some(self.event.map(|event| event.to_string()), |mut f, event| {
+            f.push_sql("event::TEXT = ")
+                .push_param(QueryParam::String(event));
+
+            f
+        })
+        .push_if_some(self.failed, |mut f, failed| {
+            f.push_sql("(error IS NOT NULL) = ")
+                .push_param(QueryParam::Bool(failed));
+
+            f
+        });
+
+        wc
+    }
+}
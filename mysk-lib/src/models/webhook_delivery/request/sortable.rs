@@ -0,0 +1,20 @@
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+use utoipa::ToSchema;
+
+#[derive(Clone, Debug, Serialize, Deserialize, Default, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum SortableWebhookDelivery {
+    #[default]
+    CreatedAt,
+    Attempt,
+}
+
+impl Display for SortableWebhookDelivery {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SortableWebhookDelivery::CreatedAt => write!(f, "created_at"),
+            SortableWebhookDelivery::Attempt => write!(f, "attempt"),
+        }
+    }
+}
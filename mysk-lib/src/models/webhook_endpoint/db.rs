@@ -0,0 +1,105 @@
+use crate::{models::traits::GetById as _, prelude::*, webhooks::WebhookEvent};
+use chrono::{DateTime, Utc};
+use mysk_lib_macros::GetById;
+use rand::{TryRngCore as _, rngs::OsRng};
+use serde::{Deserialize, Serialize};
+use sqlx::{FromRow, PgConnection, PgPool, query, query_as};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+/// An endpoint registered to receive webhooks, from `webhook_endpoints`. Its secret is never read
+/// back after the endpoint is created.
+#[derive(Clone, Debug, Deserialize, FromRow, GetById, Serialize, ToSchema)]
+#[from_query(
+    query = "SELECT id, created_at, url, events, description, is_active FROM webhook_endpoints",
+    count_query = "SELECT COUNT(id) FROM webhook_endpoints"
+)]
+pub struct DbWebhookEndpoint {
+    pub id: Uuid,
+    pub created_at: DateTime<Utc>,
+    pub url: String,
+    /// The events sent to the endpoint.
+    pub events: Vec<WebhookEvent>,
+    pub description: Option<String>,
+    pub is_active: bool,
+}
+
+impl DbWebhookEndpoint {
+    /// Registers an endpoint, returning it along with the secret its payloads are signed with.
+    pub async fn create(
+        conn: &mut PgConnection,
+        url: &str,
+        events: &[WebhookEvent],
+        description: Option<&str>,
+    ) -> Result<(Self, String)> {
+        let mut secret = [0u8; 32];
+        OsRng.try_fill_bytes(&mut secret).map_err(|err| {
+            Error::InternalServerError(
+                format!("Failed to generate a webhook secret: {err}"),
+                "DbWebhookEndpoint::create".to_string(),
+            )
+        })?;
+        let secret = format!("whsec_{}", bs58::encode(secret).into_string());
+
+        let endpoint = query_as::<_, Self>(
+            "\
+            INSERT INTO webhook_endpoints (url, secret, events, description) \
+            VALUES ($1, $2, $3, $4) \
+            RETURNING id, created_at, url, events, description, is_active\
+            ",
+        )
+        .bind(url)
+        .bind(&secret)
+        .bind(events)
+        .bind(description)
+        .fetch_one(conn)
+        .await?;
+
+        Ok((endpoint, secret))
+    }
+
+    pub async fn get_all(pool: &PgPool) -> Result<Vec<Self>> {
+        Ok(
+            query_as::<_, Self>(&format!("{} ORDER BY created_at", Self::BASE_QUERY))
+                .fetch_all(pool)
+                .await?,
+        )
+    }
+
+    /// Stops or resumes sending events to the endpoint. Events raised while it is inactive are
+    /// never sent.
+    pub async fn set_active(conn: &mut PgConnection, id: Uuid, is_active: bool) -> Result<()> {
+        let res = query!(
+            "UPDATE webhook_endpoints SET is_active = $1 WHERE id = $2",
+            is_active,
+            id,
+        )
+        .execute(conn)
+        .await?;
+
+        if res.rows_affected() == 0 {
+            return Err(Error::EntityNotFound(
+                "Webhook endpoint not found".to_string(),
+                format!("/admin/webhooks/{id}"),
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Removes the endpoint along with its pending events and delivery logs.
+    pub async fn delete(conn: &mut PgConnection, id: Uuid) -> Result<()> {
+        let res = query!("DELETE FROM webhook_endpoints WHERE id = $1", id)
+            .execute(conn)
+            .await?;
+
+        if res.rows_affected() == 0 {
+            return Err(Error::EntityNotFound(
+                "Webhook endpoint not found".to_string(),
+                format!("/admin/webhooks/{id}"),
+            ));
+        }
+
+        Ok(())
+    }
+}
@@ -1,3 +1,3 @@
-pub use crate::error::Error;
+pub use crate::error::{Error, ErrorCode};
 
 pub type Result<T, E = Error> = core::result::Result<T, E>;
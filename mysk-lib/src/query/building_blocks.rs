@@ -5,6 +5,7 @@ use crate::{
 use chrono::NaiveDate;
 use serde::Deserialize;
 use sqlx::{Postgres, QueryBuilder};
+use utoipa::ToSchema;
 use uuid::Uuid;
 
 // Additional bounds to enforce strong typing.
@@ -81,7 +82,7 @@ pub trait Queryable {
     fn to_where_clause<'sql>(self) -> SqlWhereClause<'sql>;
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, ToSchema)]
 pub struct QueryablePlaceholder;
 
 impl Queryable for QueryablePlaceholder {
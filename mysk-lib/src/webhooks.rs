@@ -0,0 +1,57 @@
+//! Outbound webhooks for domain events.
+//!
+//! Routes [`enqueue`] an event inside the transaction of the mutation it describes, which writes one
+//! row to `webhook_outbox` for every active endpoint subscribed to the event. The rows are only
+//! seen by the dispatcher once the mutation commits, and vanish along with it if it rolls back.
+
+use crate::prelude::*;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use sqlx::{PgConnection, Type as SqlxType, query};
+use std::fmt::{Display, Formatter};
+use utoipa::ToSchema;
+
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Eq, Serialize, SqlxType, ToSchema)]
+#[serde(rename_all = "snake_case")]
+#[sqlx(type_name = "webhook_event", rename_all = "snake_case")]
+pub enum WebhookEvent {
+    /// A student enrolled in an elective session, or switched to another one.
+    ElectiveEnrolled,
+    /// A student dropped their elective session during the enrollment period.
+    ElectiveWithdrawn,
+    /// A student approved a trade offer, swapping elective sessions with its sender.
+    ElectiveTradeOfferApproved,
+    /// A student asked to join a club.
+    ClubRequestCreated,
+    /// A student answered their certificate ceremony invitation.
+    RsvpChanged,
+}
+
+impl Display for WebhookEvent {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            WebhookEvent::ElectiveEnrolled => write!(f, "elective_enrolled"),
+            WebhookEvent::ElectiveWithdrawn => write!(f, "elective_withdrawn"),
+            WebhookEvent::ElectiveTradeOfferApproved => write!(f, "elective_trade_offer_approved"),
+            WebhookEvent::ClubRequestCreated => write!(f, "club_request_created"),
+            WebhookEvent::RsvpChanged => write!(f, "rsvp_changed"),
+        }
+    }
+}
+
+/// Queues the event for delivery to every active endpoint subscribed to it. Must be called inside
+/// the transaction making the change, so the event is sent if and only if the change is committed.
+pub async fn enqueue(conn: &mut PgConnection, event: WebhookEvent, payload: Value) -> Result<()> {
+    query!(
+        "\
+        INSERT INTO webhook_outbox (endpoint_id, event, payload) \
+        SELECT id, $1, $2 FROM webhook_endpoints WHERE is_active AND $1 = ANY(events)\
+        ",
+        event as WebhookEvent,
+        payload,
+    )
+    .execute(conn)
+    .await?;
+
+    Ok(())
+}